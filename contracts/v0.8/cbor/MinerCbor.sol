@@ -112,14 +112,14 @@ library MinerCBOR {
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
         if (tmp.length > 0) {
-            ret.active.term.quota = tmp.deserializeBytesBigInt();
+            ret.active.term.quota = tmp.deserializeBigInt();
         } else {
             ret.active.term.quota = CommonTypes.BigInt(new bytes(0), false);
         }
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
         if (tmp.length > 0) {
-            ret.active.term.used_quota = tmp.deserializeBytesBigInt();
+            ret.active.term.used_quota = tmp.deserializeBigInt();
         } else {
             ret.active.term.used_quota = CommonTypes.BigInt(new bytes(0), false);
         }
@@ -136,7 +136,7 @@ library MinerCBOR {
 
             (tmp, byteIdx) = rawResp.readBytes(byteIdx);
             if (tmp.length > 0) {
-                ret.proposed.new_quota = tmp.deserializeBytesBigInt();
+                ret.proposed.new_quota = tmp.deserializeBigInt();
             } else {
                 ret.proposed.new_quota = CommonTypes.BigInt(new bytes(0), false);
             }
@@ -176,7 +176,7 @@ library MinerCBOR {
             (epoch, byteIdx) = rawResp.readChainEpoch(byteIdx);
             (tmp, byteIdx) = rawResp.readBytes(byteIdx);
 
-            amount = tmp.deserializeBytesBigInt();
+            amount = tmp.deserializeBigInt();
             vesting_funds[i] = MinerTypes.VestingFunds(epoch, amount);
         }
     }
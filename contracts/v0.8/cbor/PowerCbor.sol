@@ -102,7 +102,7 @@ library PowerCBOR {
         bytes memory tmp;
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
         if (tmp.length > 0) {
-            ret.raw_byte_power = tmp.deserializeBytesBigInt();
+            ret.raw_byte_power = tmp.deserializeBigInt();
         } else {
             ret.raw_byte_power = CommonTypes.BigInt(new bytes(0), false);
         }
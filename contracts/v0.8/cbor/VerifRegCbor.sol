@@ -91,8 +91,8 @@ library VerifRegCBOR {
 
         for (uint i = 0; i < len; i++) {
             (ilen, byteIdx) = rawResp.readFixedArray(byteIdx);
-            if (!(len == 2)) {
-                revert Errors.InvalidArrayLength(2, len);
+            if (!(ilen == 2)) {
+                revert Errors.InvalidArrayLength(2, ilen);
             }
 
             (ret.batch_info.fail_codes[i].idx, byteIdx) = rawResp.readUInt32(byteIdx);
@@ -104,8 +104,8 @@ library VerifRegCBOR {
 
         for (uint i = 0; i < len; i++) {
             (ilen, byteIdx) = rawResp.readFixedArray(byteIdx);
-            if (!(len == 8)) {
-                revert Errors.InvalidArrayLength(8, len);
+            if (!(ilen == 8)) {
+                revert Errors.InvalidArrayLength(8, ilen);
             }
 
             (ret.claims[i].provider, byteIdx) = rawResp.readFilActorId(byteIdx);
@@ -197,8 +197,8 @@ library VerifRegCBOR {
 
         for (uint i = 0; i < len; i++) {
             (ilen, byteIdx) = rawResp.readFixedArray(byteIdx);
-            if (!(len == 2)) {
-                revert Errors.InvalidArrayLength(2, len);
+            if (!(ilen == 2)) {
+                revert Errors.InvalidArrayLength(2, ilen);
             }
 
             (ret.results.fail_codes[i].idx, byteIdx) = rawResp.readUInt32(byteIdx);
@@ -207,7 +207,7 @@ library VerifRegCBOR {
 
         bytes memory tmp;
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.datacap_recovered = tmp.deserializeBytesBigInt();
+        ret.datacap_recovered = tmp.deserializeBigInt();
 
         return ret;
     }
@@ -261,8 +261,8 @@ library VerifRegCBOR {
 
         for (uint i = 0; i < len; i++) {
             (ilen, byteIdx) = rawResp.readFixedArray(byteIdx);
-            if (!(len == 2)) {
-                revert Errors.InvalidArrayLength(2, len);
+            if (!(ilen == 2)) {
+                revert Errors.InvalidArrayLength(2, ilen);
             }
 
             (ret.fail_codes[i].idx, byteIdx) = rawResp.readUInt32(byteIdx);
@@ -329,8 +329,8 @@ library VerifRegCBOR {
 
         for (uint i = 0; i < len; i++) {
             (ilen, byteIdx) = rawResp.readFixedArray(byteIdx);
-            if (!(len == 2)) {
-                revert Errors.InvalidArrayLength(2, len);
+            if (!(ilen == 2)) {
+                revert Errors.InvalidArrayLength(2, ilen);
             }
 
             (ret.results.fail_codes[i].idx, byteIdx) = rawResp.readUInt32(byteIdx);
@@ -34,4 +34,24 @@ contract AddressTest {
 
         require(keccak256(result.data) == keccak256(hex"0001"), "'1' actorID is not returning '0001'");
     }
+
+    function fromEthAddress(address addr) public pure returns (CommonTypes.FilAddress memory) {
+        return FilAddresses.fromEthAddress(addr);
+    }
+
+    function toEthAddress(CommonTypes.FilAddress memory addr) public pure returns (address) {
+        return FilAddresses.toEthAddress(addr);
+    }
+
+    function fromActorID(uint64 actorID) public pure returns (CommonTypes.FilAddress memory) {
+        return FilAddresses.fromActorID(actorID);
+    }
+
+    function fromBytes(bytes memory data) public pure returns (CommonTypes.FilAddress memory) {
+        return FilAddresses.fromBytes(data);
+    }
+
+    function validate(CommonTypes.FilAddress memory addr) public pure returns (bool) {
+        return FilAddresses.validate(addr);
+    }
 }
@@ -23,6 +23,7 @@ import "../../vendor/solidity-BigNumber/src/BigNumbers.sol";
 
 import "../types/CommonTypes.sol";
 import "../utils/BigInts.sol";
+import "../cbor/BigIntCbor.sol";
 
 /// @notice This file is meant to serve as a deployable contract of the BigInts lib, as the library by itself is not.
 /// @notice It imports the library and create a callable method for each method in the library
@@ -399,4 +400,28 @@ contract BigIntsTest {
         );
         require(converted.neg == true, "'neg flag should be false'");
     }
+
+    function toUint256(CommonTypes.BigInt memory value) public view returns (uint256, bool) {
+        return value.toUint256();
+    }
+
+    function toInt256(CommonTypes.BigInt memory value) public view returns (int256, bool) {
+        return value.toInt256();
+    }
+
+    function fromUint256(uint256 value) public view returns (CommonTypes.BigInt memory) {
+        return BigInts.fromUint256(value);
+    }
+
+    function fromInt256(int256 value) public view returns (CommonTypes.BigInt memory) {
+        return BigInts.fromInt256(value);
+    }
+
+    function serializeBigInt(CommonTypes.BigInt memory value) public pure returns (bytes memory) {
+        return BigIntCBOR.serializeBigInt(value);
+    }
+
+    function deserializeBigInt(bytes memory raw) public pure returns (CommonTypes.BigInt memory) {
+        return BigIntCBOR.deserializeBigInt(raw);
+    }
 }
@@ -151,4 +151,128 @@ contract CborDecodeTest {
         (num, index) = input.readUInt8(index);
         require(num == 111, "num is not 111");
     }
+
+    function decodeNegativeInteger() public pure {
+        bytes memory input;
+        uint index = 0;
+        int64 value64;
+        int32 value32;
+        int16 value16;
+        int8 value8;
+
+        input = hex"20";
+        (value64, index) = input.readInt64(0);
+        require(value64 == -1, "value is not -1");
+
+        input = hex"1864";
+        (value64, index) = input.readInt64(0);
+        require(value64 == 100, "value is not 100");
+
+        input = hex"3b7fffffffffffffff";
+        (value64, index) = input.readInt64(0);
+        require(value64 == type(int64).min, "value is not int64 min");
+
+        input = hex"3863";
+        (value32, index) = input.readInt32(0);
+        require(value32 == -100, "value is not -100");
+
+        input = hex"397fff";
+        (value16, index) = input.readInt16(0);
+        require(value16 == type(int16).min, "value is not int16 min");
+
+        input = hex"387f";
+        (value8, index) = input.readInt8(0);
+        require(value8 == type(int8).min, "value is not int8 min");
+    }
+
+    function decodeNegativeBigNum() public pure {
+        bytes memory input;
+        uint index = 0;
+        int256 value;
+
+        input = hex"20";
+        (value, index) = input.readInt256(0);
+        require(value == -1, "value is not -1");
+
+        // tag 3 with magnitude 0x0100, that is -1 - 256
+        input = hex"c3420100";
+        (value, index) = input.readInt256(0);
+        require(value == -257, "value is not -257");
+        require(index == 4, "index is not past the bignum");
+    }
+
+    function decodeShortBigNum() public pure {
+        bytes memory input = hex"c2420100";
+        uint index = 0;
+        uint256 value;
+
+        (value, index) = input.readUInt256(0);
+        require(value == 256, "value is not 256");
+        require(index == 4, "index is not past the bignum");
+    }
+
+    function isNullNext(bytes memory cborData) public pure returns (bool) {
+        return cborData.isNullNext(0);
+    }
+
+    function readBool(bytes memory cborData) public pure returns (bool, uint) {
+        return cborData.readBool(0);
+    }
+
+    function readFixedArray(bytes memory cborData) public pure returns (uint, uint) {
+        return cborData.readFixedArray(0);
+    }
+
+    function readString(bytes memory cborData) public pure returns (bytes memory, uint) {
+        (string memory value, uint byteIdx) = cborData.readString(0);
+        return (bytes(value), byteIdx);
+    }
+
+    function readBytes(bytes memory cborData) public pure returns (bytes memory, uint) {
+        return cborData.readBytes(0);
+    }
+
+    function readBytes32(bytes memory cborData) public pure returns (bytes32, uint) {
+        return cborData.readBytes32(0);
+    }
+
+    function readUInt256(bytes memory cborData) public pure returns (uint256, uint) {
+        return cborData.readUInt256(0);
+    }
+
+    function readInt256(bytes memory cborData) public pure returns (int256, uint) {
+        return cborData.readInt256(0);
+    }
+
+    function readUInt64(bytes memory cborData) public pure returns (uint64, uint) {
+        return cborData.readUInt64(0);
+    }
+
+    function readUInt32(bytes memory cborData) public pure returns (uint32, uint) {
+        return cborData.readUInt32(0);
+    }
+
+    function readUInt16(bytes memory cborData) public pure returns (uint16, uint) {
+        return cborData.readUInt16(0);
+    }
+
+    function readUInt8(bytes memory cborData) public pure returns (uint8, uint) {
+        return cborData.readUInt8(0);
+    }
+
+    function readInt64(bytes memory cborData) public pure returns (int64, uint) {
+        return cborData.readInt64(0);
+    }
+
+    function readInt32(bytes memory cborData) public pure returns (int32, uint) {
+        return cborData.readInt32(0);
+    }
+
+    function readInt16(bytes memory cborData) public pure returns (int16, uint) {
+        return cborData.readInt16(0);
+    }
+
+    function readInt8(bytes memory cborData) public pure returns (int8, uint) {
+        return cborData.readInt8(0);
+    }
 }
@@ -19,19 +19,147 @@
 // SPDX-License-Identifier: Apache-2.0
 pragma solidity ^0.8.17;
 
+import "../types/CommonTypes.sol";
+import "../types/DataCapTypes.sol";
+import "../types/MarketTypes.sol";
 import "../types/MinerTypes.sol";
+import "../types/PowerTypes.sol";
+import "../types/VerifRegTypes.sol";
+import "../cbor/DataCapCbor.sol";
+import "../cbor/FilecoinCbor.sol";
+import "../cbor/MarketCbor.sol";
 import "../cbor/MinerCbor.sol";
+import "../cbor/PowerCbor.sol";
+import "../cbor/VerifRegCbor.sol";
 
 /// @notice This file is meant to serve as a deployable contract to test
+/// @dev Inputs are the golden vectors of testing/vectors/cbor_vectors.json, passed in by the integration tests
 /// @author Zondax AG
 contract DeserializeParamsTest {
-    using MinerCBOR for *;
+    function deserializeGetBalanceReturn(bytes memory params) public pure returns (MarketTypes.GetBalanceReturn memory) {
+        return MarketCBOR.deserializeGetBalanceReturn(params);
+    }
+
+    function deserializeGetDealDataCommitmentReturn(bytes memory params) public pure returns (MarketTypes.GetDealDataCommitmentReturn memory) {
+        return MarketCBOR.deserializeGetDealDataCommitmentReturn(params);
+    }
+
+    function deserializeGetDealTermReturn(bytes memory params) public pure returns (MarketTypes.GetDealTermReturn memory) {
+        return MarketCBOR.deserializeGetDealTermReturn(params);
+    }
+
+    function deserializeGetDealActivationReturn(bytes memory params) public pure returns (MarketTypes.GetDealActivationReturn memory) {
+        return MarketCBOR.deserializeGetDealActivationReturn(params);
+    }
 
-    function deserializeGetVestingFundsReturn() public pure {
-        bytes memory params = hex"8181820040";
+    function deserializePublishStorageDealsReturn(bytes memory params) public pure returns (MarketTypes.PublishStorageDealsReturn memory) {
+        return MarketCBOR.deserializePublishStorageDealsReturn(params);
+    }
+
+    function deserializeDealProposal(bytes memory params) public pure returns (MarketTypes.DealProposal memory) {
+        return MarketCBOR.deserializeDealProposal(params);
+    }
+
+    function deserializeMarketDealNotifyParams(bytes memory params) public pure returns (MarketTypes.MarketDealNotifyParams memory) {
+        return MarketCBOR.deserializeMarketDealNotifyParams(params);
+    }
+
+    function deserializeGetOwnerReturn(bytes memory params) public pure returns (MinerTypes.GetOwnerReturn memory) {
+        return MinerCBOR.deserializeGetOwnerReturn(params);
+    }
 
-        MinerTypes.VestingFunds[] memory vesting_funds = params.deserializeGetVestingFundsReturn();
+    function deserializeGetBeneficiaryReturn(bytes memory params) public pure returns (MinerTypes.GetBeneficiaryReturn memory) {
+        return MinerCBOR.deserializeGetBeneficiaryReturn(params);
+    }
+
+    function deserializeGetVestingFundsReturn(bytes memory params) public pure returns (MinerTypes.VestingFunds[] memory) {
+        return MinerCBOR.deserializeGetVestingFundsReturn(params);
+    }
+
+    function deserializeGetMultiaddrsReturn(bytes memory params) public pure returns (CommonTypes.FilAddress[] memory) {
+        return MinerCBOR.deserializeGetMultiaddrsReturn(params);
+    }
+
+    function deserializeCreateMinerReturn(bytes memory params) public pure returns (PowerTypes.CreateMinerReturn memory) {
+        return PowerCBOR.deserializeCreateMinerReturn(params);
+    }
+
+    function deserializeMinerRawPowerReturn(bytes memory params) public pure returns (PowerTypes.MinerRawPowerReturn memory) {
+        return PowerCBOR.deserializeMinerRawPowerReturn(params);
+    }
+
+    function deserializeGetClaimsReturn(bytes memory params) public pure returns (VerifRegTypes.GetClaimsReturn memory) {
+        return VerifRegCBOR.deserializeGetClaimsReturn(params);
+    }
+
+    function deserializeRemoveExpiredAllocationsReturn(bytes memory params) public pure returns (VerifRegTypes.RemoveExpiredAllocationsReturn memory) {
+        return VerifRegCBOR.deserializeRemoveExpiredAllocationsReturn(params);
+    }
+
+    function deserializeRemoveExpiredClaimsReturn(bytes memory params) public pure returns (VerifRegTypes.RemoveExpiredClaimsReturn memory) {
+        return VerifRegCBOR.deserializeRemoveExpiredClaimsReturn(params);
+    }
+
+    function deserializeBatchReturn(bytes memory params) public pure returns (CommonTypes.BatchReturn memory) {
+        return VerifRegCBOR.deserializeBatchReturn(params);
+    }
+
+    function deserializeTransferReturn(bytes memory params) public pure returns (DataCapTypes.TransferReturn memory) {
+        return DataCapCBOR.deserializeTransferReturn(params);
+    }
+
+    function deserializeTransferFromReturn(bytes memory params) public pure returns (DataCapTypes.TransferFromReturn memory) {
+        return DataCapCBOR.deserializeTransferFromReturn(params);
+    }
+
+    function deserializeBurnFromReturn(bytes memory params) public pure returns (DataCapTypes.BurnFromReturn memory) {
+        return DataCapCBOR.deserializeBurnFromReturn(params);
+    }
+
+    function deserializeUniversalReceiverParams(bytes memory params) public pure returns (CommonTypes.UniversalReceiverParams memory) {
+        return FilecoinCBOR.deserializeUniversalReceiverParams(params);
+    }
+
+    function deserializeGetVestingFundsReturnWithAmount() public pure {
+        bytes memory params = hex"8181821864430003e8";
+
+        MinerTypes.VestingFunds[] memory vesting_funds = MinerCBOR.deserializeGetVestingFundsReturn(params);
 
         require(vesting_funds.length == 1, "result length should be 1");
+        require(CommonTypes.ChainEpoch.unwrap(vesting_funds[0].epoch) == 100, "epoch should be 100");
+        require(keccak256(vesting_funds[0].amount.val) == keccak256(hex"03e8"), "amount should be 1000");
+        require(vesting_funds[0].amount.neg == false, "amount should be positive");
+    }
+
+    function deserializeMinerRawPowerReturnWithPower() public pure {
+        bytes memory params = hex"8243000800f5";
+
+        PowerTypes.MinerRawPowerReturn memory ret = PowerCBOR.deserializeMinerRawPowerReturn(params);
+
+        require(keccak256(ret.raw_byte_power.val) == keccak256(hex"0800"), "raw byte power should be 2048");
+        require(ret.raw_byte_power.neg == false, "raw byte power should be positive");
+        require(ret.meets_consensus_minimum == true, "meets_consensus_minimum should be true");
+    }
+
+    function deserializeGetClaimsReturnWithClaim() public pure {
+        bytes memory params = hex"8282018182001081881903e81903e9d82a450001550000190800186418c80a05";
+
+        VerifRegTypes.GetClaimsReturn memory ret = VerifRegCBOR.deserializeGetClaimsReturn(params);
+
+        require(ret.batch_info.fail_codes.length == 1, "fail codes length should be 1");
+        require(ret.batch_info.fail_codes[0].code == 16, "fail code should be 16");
+        require(ret.claims.length == 1, "claims length should be 1");
+        require(CommonTypes.FilActorId.unwrap(ret.claims[0].provider) == 1000, "provider should be 1000");
+        require(CommonTypes.FilActorId.unwrap(ret.claims[0].sector) == 5, "sector should be 5");
+    }
+
+    function deserializeRemoveExpiredAllocationsReturnWithFailure() public pure {
+        bytes memory params = hex"8382010282018182011043000400";
+
+        VerifRegTypes.RemoveExpiredAllocationsReturn memory ret = VerifRegCBOR.deserializeRemoveExpiredAllocationsReturn(params);
+
+        require(ret.considered.length == 2, "considered length should be 2");
+        require(ret.results.fail_codes.length == 1, "fail codes length should be 1");
+        require(keccak256(ret.datacap_recovered.val) == keccak256(hex"0400"), "datacap recovered should be 1024");
     }
 }
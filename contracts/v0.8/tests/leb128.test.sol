@@ -38,4 +38,12 @@ contract Leb128Test {
 
         require(keccak256(result.buf) == keccak256(expected), "'624485' is not returning 'e58e26'");
     }
+
+    function encodeUnsignedLeb128FromUInt64(uint64 value) public pure returns (bytes memory) {
+        return Leb128.encodeUnsignedLeb128FromUInt64(value).buf;
+    }
+
+    function decodeUnsignedLeb128ToUInt64(bytes memory data) public pure returns (uint64, uint) {
+        return Leb128.decodeUnsignedLeb128ToUInt64(data, 0);
+    }
 }
@@ -0,0 +1,114 @@
+/*******************************************************************************
+ *   (c) 2023 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// THIS CODE WAS SECURITY REVIEWED BY KUDELSKI SECURITY, BUT NOT FORMALLY AUDITED
+
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity ^0.8.17;
+
+import "../types/CommonTypes.sol";
+import "../types/DataCapTypes.sol";
+import "../types/MarketTypes.sol";
+import "../types/MinerTypes.sol";
+import "../types/PowerTypes.sol";
+import "../types/VerifRegTypes.sol";
+import "../cbor/DataCapCbor.sol";
+import "../cbor/FilecoinCbor.sol";
+import "../cbor/MarketCbor.sol";
+import "../cbor/MinerCbor.sol";
+import "../cbor/PowerCbor.sol";
+import "../cbor/VerifRegCbor.sol";
+
+/// @notice This file is meant to serve as a deployable contract to test
+/// @dev Every function returns the CBOR the library produces, the integration tests decode it with the builtin actors types
+/// @author Zondax AG
+contract SerializeParamsTest {
+    function serializeWithdrawBalanceParams(MarketTypes.WithdrawBalanceParams memory params) public pure returns (bytes memory) {
+        return MarketCBOR.serializeWithdrawBalanceParams(params);
+    }
+
+    function serializePublishStorageDealsParams(MarketTypes.PublishStorageDealsParams memory params) public pure returns (bytes memory) {
+        return MarketCBOR.serializePublishStorageDealsParams(params);
+    }
+
+    function serializeDealProposal(MarketTypes.DealProposal memory params) public pure returns (bytes memory) {
+        return MarketCBOR.serializeDealProposal(params);
+    }
+
+    function serializeChangeBeneficiaryParams(MinerTypes.ChangeBeneficiaryParams memory params) public pure returns (bytes memory) {
+        return MinerCBOR.serializeChangeBeneficiaryParams(params);
+    }
+
+    function serializeChangeWorkerAddressParams(MinerTypes.ChangeWorkerAddressParams memory params) public pure returns (bytes memory) {
+        return MinerCBOR.serializeChangeWorkerAddressParams(params);
+    }
+
+    function serializeChangeMultiaddrsParams(CommonTypes.FilAddress[] memory params) public pure returns (bytes memory) {
+        return MinerCBOR.serializeChangeMultiaddrsParams(params);
+    }
+
+    function serializeCreateMinerParams(PowerTypes.CreateMinerParams memory params) public pure returns (bytes memory) {
+        return PowerCBOR.serializeCreateMinerParams(params);
+    }
+
+    function serializeGetClaimsParams(VerifRegTypes.GetClaimsParams memory params) public pure returns (bytes memory) {
+        return VerifRegCBOR.serializeGetClaimsParams(params);
+    }
+
+    function serializeAddVerifiedClientParams(VerifRegTypes.AddVerifiedClientParams memory params) public pure returns (bytes memory) {
+        return VerifRegCBOR.serializeAddVerifiedClientParams(params);
+    }
+
+    function serializeRemoveExpiredAllocationsParams(VerifRegTypes.RemoveExpiredAllocationsParams memory params) public pure returns (bytes memory) {
+        return VerifRegCBOR.serializeRemoveExpiredAllocationsParams(params);
+    }
+
+    function serializeExtendClaimTermsParams(VerifRegTypes.ClaimTerm[] memory params) public pure returns (bytes memory) {
+        return VerifRegCBOR.serializeExtendClaimTermsParams(params);
+    }
+
+    function serializeRemoveExpiredClaimsParams(VerifRegTypes.RemoveExpiredClaimsParams memory params) public pure returns (bytes memory) {
+        return VerifRegCBOR.serializeRemoveExpiredClaimsParams(params);
+    }
+
+    function serializeGetAllowanceParams(DataCapTypes.GetAllowanceParams memory params) public pure returns (bytes memory) {
+        return DataCapCBOR.serializeGetAllowanceParams(params);
+    }
+
+    function serializeTransferParams(DataCapTypes.TransferParams memory params) public pure returns (bytes memory) {
+        return DataCapCBOR.serializeTransferParams(params);
+    }
+
+    function serializeTransferFromParams(DataCapTypes.TransferFromParams memory params) public pure returns (bytes memory) {
+        return DataCapCBOR.serializeTransferFromParams(params);
+    }
+
+    function serializeIncreaseAllowanceParams(DataCapTypes.IncreaseAllowanceParams memory params) public pure returns (bytes memory) {
+        return DataCapCBOR.serializeIncreaseAllowanceParams(params);
+    }
+
+    function serializeDecreaseAllowanceParams(DataCapTypes.DecreaseAllowanceParams memory params) public pure returns (bytes memory) {
+        return DataCapCBOR.serializeDecreaseAllowanceParams(params);
+    }
+
+    function serializeBurnFromParams(DataCapTypes.BurnFromParams memory params) public pure returns (bytes memory) {
+        return DataCapCBOR.serializeBurnFromParams(params);
+    }
+
+    function serializeUniversalReceiverParams(CommonTypes.UniversalReceiverParams memory params) public pure returns (bytes memory) {
+        return FilecoinCBOR.serializeUniversalReceiverParams(params);
+    }
+}
@@ -28,6 +28,7 @@ import "../utils/Misc.sol";
 library BigInts {
     uint256 constant MAX_UINT = (2 ** 256) - 1;
     uint256 constant MAX_INT = ((2 ** 256) / 2) - 1;
+    uint256 constant MIN_INT_ABS = (2 ** 256) / 2;
 
     error NegativeValueNotAllowed();
 
@@ -54,14 +55,16 @@ library BigInts {
     /// @return a uint256 value and a flag that indicates whether it was possible to convert the arg. value
     ///         (returns true if the arg. value overflows uint256 type)
     function toUint256(CommonTypes.BigInt memory value) internal view returns (uint256, bool) {
-        if (value.neg) {
-            revert NegativeValueNotAllowed();
-        }
-
         if (value.val.length == 0) value.val = hex"00";
 
         BigNumber memory max = BigNumbers.init(MAX_UINT, false);
-        BigNumber memory bigNumValue = BigNumbers.init(value.val, value.neg);
+        BigNumber memory bigNumValue = BigNumbers.init(value.val, false);
+
+        // A negative zero is still zero
+        if (value.neg && !BigNumbers.isZero(bigNumValue)) {
+            revert NegativeValueNotAllowed();
+        }
+
         if (BigNumbers.gt(bigNumValue, max)) {
             return (0, true);
         }
@@ -80,6 +83,10 @@ library BigInts {
         BigNumber memory max = BigNumbers.init(MAX_INT, false);
         BigNumber memory bigNumValue = BigNumbers.init(value.val, false);
         if (BigNumbers.gt(bigNumValue, max)) {
+            // The magnitude of type(int256).min is one more than MAX_INT
+            if (value.neg && BigNumbers.eq(bigNumValue, BigNumbers.init(MIN_INT_ABS, false))) {
+                return (type(int256).min, false);
+            }
             return (0, true);
         }
 
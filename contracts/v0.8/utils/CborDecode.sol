@@ -83,6 +83,8 @@ library CBORDecoder {
 
         (maj, len, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajArray, "invalid maj (expected MajArray)");
+        // Every item takes at least one byte, bounding the length callers allocate for
+        require(len <= cborData.length - byteIdx, "array longer than remaining data");
 
         return (len, byteIdx);
     }
@@ -97,6 +99,7 @@ library CBORDecoder {
 
         (maj, len, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajTextString, "invalid maj (expected MajTextString)");
+        require(cborData.length >= byteIdx + len, "slicing out of range");
 
         uint max_len = byteIdx + len;
         bytes memory slice = new bytes(len);
@@ -126,6 +129,7 @@ library CBORDecoder {
                 revert Errors.ExpectedMajorByteString();
             }
         }
+        require(cborData.length >= byteIdx + len, "slicing out of range");
 
         uint max_len = byteIdx + len;
         bytes memory slice = new bytes(len);
@@ -148,6 +152,8 @@ library CBORDecoder {
 
         (maj, len, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajByteString, "invalid maj (expected MajByteString)");
+        require(len <= 32, "value exceeds bytes32 length");
+        require(cborData.length >= byteIdx + len, "slicing out of range");
 
         uint max_len = byteIdx + len;
         bytes memory slice = new bytes(32);
@@ -178,9 +184,11 @@ library CBORDecoder {
             (maj, len, byteIdx) = parseCborHeader(cborData, byteIdx);
             require(maj == MajByteString, "invalid maj (expected MajByteString)");
 
+            require(len <= 32, "value exceeds uint256 max limit");
             require(cborData.length >= byteIdx + len, "slicing out of range");
+            // Load the 32 bytes from the magnitude onwards and drop the ones past its end
             assembly {
-                value := mload(add(cborData, add(len, byteIdx)))
+                value := shr(mul(8, sub(32, len)), mload(add(add(cborData, 0x20), byteIdx)))
             }
 
             return (value, byteIdx + len);
@@ -209,15 +217,19 @@ library CBORDecoder {
             (maj, len, byteIdx) = parseCborHeader(cborData, byteIdx);
             require(maj == MajByteString, "invalid maj (expected MajByteString)");
 
+            require(len <= 32, "value exceeds int256 range");
             require(cborData.length >= byteIdx + len, "slicing out of range");
+            // Load the 32 bytes from the magnitude onwards and drop the ones past its end
             assembly {
-                value := mload(add(cborData, add(len, byteIdx)))
+                value := shr(mul(8, sub(32, len)), mload(add(add(cborData, 0x20), byteIdx)))
             }
-
-            return (int256(value), byteIdx + len);
+            byteIdx += len;
         }
 
-        return (int256(value), byteIdx);
+        // Both encode the negative integer -1 - value
+        require(value <= uint256(type(int256).max), "value exceeds int256 range");
+
+        return (-1 - int256(value), byteIdx);
     }
 
     /// @notice attempt to read a uint64 value
@@ -262,6 +274,9 @@ library CBORDecoder {
         (maj, value, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajUnsignedInt, "invalid maj (expected MajUnsignedInt)");
 
+        // Validation to prevent truncation
+        require(value <= type(uint16).max, "value exceeds uint16 max limit");
+
         return (uint16(value), byteIdx);
     }
 
@@ -276,6 +291,9 @@ library CBORDecoder {
         (maj, value, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajUnsignedInt, "invalid maj (expected MajUnsignedInt)");
 
+        // Validation to prevent truncation
+        require(value <= type(uint8).max, "value exceeds uint8 max limit");
+
         return (uint8(value), byteIdx);
     }
 
@@ -290,7 +308,10 @@ library CBORDecoder {
         (maj, value, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajSignedInt || maj == MajUnsignedInt, "invalid maj (expected MajSignedInt or MajUnsignedInt)");
 
-        return (int64(uint64(value)), byteIdx);
+        // Validation to prevent truncation, a negative integer is encoded as -1 - value
+        require(value <= uint64(type(int64).max), "value exceeds int64 range");
+
+        return (maj == MajSignedInt ? -1 - int64(uint64(value)) : int64(uint64(value)), byteIdx);
     }
 
     /// @notice attempt to read a int32 value
@@ -304,7 +325,10 @@ library CBORDecoder {
         (maj, value, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajSignedInt || maj == MajUnsignedInt, "invalid maj (expected MajSignedInt or MajUnsignedInt)");
 
-        return (int32(uint32(value)), byteIdx);
+        // Validation to prevent truncation, a negative integer is encoded as -1 - value
+        require(value <= uint32(type(int32).max), "value exceeds int32 range");
+
+        return (maj == MajSignedInt ? -1 - int32(uint32(value)) : int32(uint32(value)), byteIdx);
     }
 
     /// @notice attempt to read a int16 value
@@ -318,7 +342,10 @@ library CBORDecoder {
         (maj, value, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajSignedInt || maj == MajUnsignedInt, "invalid maj (expected MajSignedInt or MajUnsignedInt)");
 
-        return (int16(uint16(value)), byteIdx);
+        // Validation to prevent truncation, a negative integer is encoded as -1 - value
+        require(value <= uint16(type(int16).max), "value exceeds int16 range");
+
+        return (maj == MajSignedInt ? -1 - int16(uint16(value)) : int16(uint16(value)), byteIdx);
     }
 
     /// @notice attempt to read a int8 value
@@ -332,7 +359,10 @@ library CBORDecoder {
         (maj, value, byteIdx) = parseCborHeader(cborData, byteIdx);
         require(maj == MajSignedInt || maj == MajUnsignedInt, "invalid maj (expected MajSignedInt or MajUnsignedInt)");
 
-        return (int8(uint8(value)), byteIdx);
+        // Validation to prevent truncation, a negative integer is encoded as -1 - value
+        require(value <= uint8(type(int8).max), "value exceeds int8 range");
+
+        return (maj == MajSignedInt ? -1 - int8(uint8(value)) : int8(uint8(value)), byteIdx);
     }
 
     /// @notice slice uint8 from bytes starting at a given index
@@ -81,6 +81,10 @@ library FilAddresses {
     /// @param addr the filecoin address to validate
     /// @return whether the address is valid or not
     function validate(CommonTypes.FilAddress memory addr) internal pure returns (bool) {
+        if (addr.data.length == 0) {
+            return false;
+        }
+
         if (addr.data[0] == CommonTypes.PROTOCOL_ID) {
             return (
                 addr.data.length > CommonTypes.MIN_PROTOCOL_ID_ADDRESS_LENGTH
@@ -90,7 +94,7 @@ library FilAddresses {
             return addr.data.length == CommonTypes.PROTOCOL_SECP256K1_ACTOR_ADDRESS_LENGTH;
         } else if (addr.data[0] == CommonTypes.PROTOCOL_BLS) {
             return addr.data.length == CommonTypes.PROTOCOL_BLS_ADDRESS_LENGTH;
-        } else if (addr.data[0] == CommonTypes.PROTOCOL_DELEGATED && addr.data[1] == CommonTypes.EAM_ID) {
+        } else if (addr.data[0] == CommonTypes.PROTOCOL_DELEGATED && addr.data.length > 1 && addr.data[1] == CommonTypes.EAM_ID) {
             return addr.data.length == CommonTypes.PROTOCOL_DELEGATED_EAM_ADDRESS_LENGTH;
         }
 
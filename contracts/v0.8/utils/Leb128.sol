@@ -25,6 +25,8 @@ import "@ensdomains/buffer/contracts/Buffer.sol";
 library Leb128 {
     using Buffer for Buffer.buffer;
 
+    error InvalidLeb128();
+
     /// @notice encode a unsigned integer 64bits into bytes
     /// @param value the actor ID to encode
     /// @return result return the value in bytes
@@ -39,4 +41,30 @@ library Leb128 {
             result.appendUint8(uint8(byte_ | 0x80));
         }
     }
+
+    /// @notice decode a unsigned integer 64bits from its leb128 bytes
+    /// @dev reverts if the bytes end before the last group or if the value does not fit in 64 bits
+    /// @param data the bytes to read from
+    /// @param byteIdx index of the first byte of the value
+    /// @return value the decoded value
+    /// @return the index of the byte following the value
+    function decodeUnsignedLeb128ToUInt64(bytes memory data, uint byteIdx) internal pure returns (uint64 value, uint) {
+        for (uint shift = 0; shift < 64; shift += 7) {
+            if (byteIdx >= data.length) {
+                revert InvalidLeb128();
+            }
+            uint8 byte_ = uint8(data[byteIdx]);
+            byteIdx++;
+
+            // The tenth group only holds the most significant bit
+            if (shift == 63 && byte_ > 1) {
+                revert InvalidLeb128();
+            }
+            value |= uint64(byte_ & 0x7f) << shift;
+            if ((byte_ & 0x80) == 0) {
+                return (value, byteIdx);
+            }
+        }
+        revert InvalidLeb128();
+    }
 }
@@ -37,5 +37,10 @@ const test1 = async (testName: string) => {
 
     //note: no additional checks performed (reverts on error)
     await addressSC.eth.contract.actorid_conversion()
+
+    //note: an empty address is invalid, a lone delegated protocol byte falls back to the length rule
+    expect(await addressSC.eth.contract.validate({ data: "0x" })).to.eq(false)
+    expect(await addressSC.eth.contract.validate({ data: "0x04" })).to.eq(true)
+    expect(await addressSC.eth.contract.validate({ data: "0x00e807" })).to.eq(true)
     await utils.defaultTxDelay()
 }
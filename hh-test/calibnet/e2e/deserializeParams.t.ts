@@ -35,8 +35,17 @@ const test1 = async (testName: string) => {
 
     const deserializeParamsSC = await utils.deployContract(deployer, "DeserializeParamsTest")
 
+    const vestingFunds = await deserializeParamsSC.eth.contract.deserializeGetVestingFundsReturn(utils.cborVector("GetVestingFundsReturn/default"))
+    expect(vestingFunds.length).to.eq(1)
+
     //note: additional checks performed inside contracts (all revert on error)
-    await deserializeParamsSC.eth.contract.deserializeGetVestingFundsReturn()
+    await deserializeParamsSC.eth.contract.deserializeGetVestingFundsReturnWithAmount()
+
+    await deserializeParamsSC.eth.contract.deserializeMinerRawPowerReturnWithPower()
+
+    await deserializeParamsSC.eth.contract.deserializeGetClaimsReturnWithClaim()
+
+    await deserializeParamsSC.eth.contract.deserializeRemoveExpiredAllocationsReturnWithFailure()
 
     await utils.defaultTxDelay()
 }
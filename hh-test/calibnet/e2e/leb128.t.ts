@@ -19,6 +19,11 @@ describe("Leb128 Test", () => {
         DBG_TESTS[currentTestName] = false
     })
 
+    it("Test 2: Decoding", async () => {
+        await test2(currentTestName)
+        DBG_TESTS[currentTestName] = false
+    })
+
     afterEach(() => {
         if (DBG_TESTS[currentTestName]) {
             utils.printDbgLog(currentTestName)
@@ -31,19 +36,35 @@ const test1 = async (testName: string) => {
 
     const { deployer } = await utils.performGeneralSetupOnCalibnet()
 
-    dbg(`Deploying contracts... (Leb128Generated[1..15]Test)`)
+    dbg(`Deploying contracts... (Leb128Test)`)
+    const leb128_SC = await utils.deployContract(deployer, "Leb128Test")
 
-    const startingNonce = await deployer.eth.signer.getNonce()
+    //note: additional checks performed inside the contract (reverts on error)
+    await leb128_SC.eth.contract.unsiged_integer_leb128_encoding()
 
-    const leb128_SC = []
-    for (let i = 1; i < 15; ++i) {
-        const cName = `Leb128Generated${i}Test`
-        const sc = await utils.deployContract(deployer, cName)
-        leb128_SC.push(sc)
-    }
+    const encoded = await leb128_SC.eth.contract.encodeUnsignedLeb128FromUInt64(624485)
+    expect(encoded).to.eq("0xe58e26")
+}
 
-    for (const l128_SC of leb128_SC) {
-        //note: additional checks performed inside contracts (all revert on error)
-        await l128_SC.eth.contract.unsiged_integer_leb128_encoding_generated()
+const test2 = async (testName: string) => {
+    const dbg = utils.initDbg(testName)
+    const { deployer } = await utils.performGeneralSetupOnCalibnet()
+
+    dbg(`Deploying contracts... (Leb128Test)`)
+    const leb128_SC = await utils.deployContract(deployer, "Leb128Test")
+
+    const [value, byteIdx] = await leb128_SC.eth.contract.decodeUnsignedLeb128ToUInt64("0xe58e26")
+    expect(value).to.eq(BigInt(624485))
+    expect(byteIdx).to.eq(BigInt(3))
+
+    //note: truncated and over 64 bits inputs revert with InvalidLeb128
+    for (const input of ["0xe58e", "0xffffffffffffffffff7f"]) {
+        let reverted = false
+        try {
+            await leb128_SC.eth.contract.decodeUnsignedLeb128ToUInt64(input)
+        } catch {
+            reverted = true
+        }
+        expect(reverted).to.eq(true)
     }
 }
@@ -46,4 +46,18 @@ const test1 = async (testName: string) => {
     await bigIntSC.eth.contract.from_int256_positive()
 
     await bigIntSC.eth.contract.from_int256_negative()
+
+    //note: a negative zero is zero
+    const [unsigned, unsignedOverflow] = await bigIntSC.eth.contract.toUint256({ val: "0x", neg: true })
+    expect(unsigned).to.eq(BigInt(0))
+    expect(unsignedOverflow).to.eq(false)
+
+    //note: the magnitude of int256 min is one more than int256 max
+    const minMagnitude = "0x80" + "00".repeat(31)
+    const [signed, signedOverflow] = await bigIntSC.eth.contract.toInt256({ val: minMagnitude, neg: true })
+    expect(signed).to.eq(-(BigInt(2) ** BigInt(255)))
+    expect(signedOverflow).to.eq(false)
+
+    const [, overflow] = await bigIntSC.eth.contract.toInt256({ val: minMagnitude, neg: false })
+    expect(overflow).to.eq(true)
 }
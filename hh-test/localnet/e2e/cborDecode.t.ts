@@ -52,5 +52,36 @@ const test1 = async (testName: string) => {
 
     await cborDecode.eth.contract.decodeArrayU8()
 
+    await cborDecode.eth.contract.decodeNegativeInteger()
+
+    await cborDecode.eth.contract.decodeNegativeBigNum()
+
+    const [value] = await cborDecode.eth.contract.readInt64("0x3903e7")
+    expect(value).to.eq(BigInt(-1000))
+
+    await cborDecode.eth.contract.decodeShortBigNum()
+
+    //note: integers outside the target type and lengths past the end of the input revert
+    const rejected = [
+        { helper: "readInt8", input: "0x1880" },
+        { helper: "readInt8", input: "0x3880" },
+        { helper: "readUInt8", input: "0x190100" },
+        { helper: "readUInt16", input: "0x1a00010000" },
+        { helper: "readBytes", input: "0x5affffffff" },
+        { helper: "readString", input: "0x7affffffff" },
+        { helper: "readBytes32", input: "0x582100" },
+        { helper: "readFixedArray", input: "0x9affffffff" },
+        { helper: "readUInt256", input: "0xc2582100" },
+    ]
+    for (const { helper, input } of rejected) {
+        let reverted = false
+        try {
+            await cborDecode.eth.contract[helper](input)
+        } catch {
+            reverted = true
+        }
+        expect(reverted, `${helper}(${input})`).to.eq(true)
+    }
+
     await utils.defaultTxDelay()
 }
@@ -61,6 +61,17 @@ export const utf8Encode = (payload: string) => {
     return utf8EncodeText.encode(payload)
 }
 
+export const cborVector = (name: string) => {
+    //golden vectors generated from the builtin-actors types (testing/vectors/cbor_vectors.json)
+    const file = JSON.parse(readFileSync(`${__dirname}/../testing/vectors/cbor_vectors.json`, "utf-8"))
+    const vector = file.vectors.find((v) => v.name == name)
+    if (vector === undefined) {
+        throw new Error(`no CBOR vector named ${name}`)
+    }
+
+    return "0x" + vector.cbor
+}
+
 export const lotus = {
     setControlAddress: (filAddress: string) => {
         return execSync(`${PREFIX_CMD}lotus-miner actor control set --really-do-it ${filAddress}"`).toString()
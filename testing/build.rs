@@ -0,0 +1,217 @@
+//! Generates `bindings.rs` in `OUT_DIR` from the test contract ABIs emitted by
+//! `make build_tests`, so that tests stop compiling when a Solidity signature changes.
+//!
+//! Every ABI goes into a single `sol!` block: the structs they use are declared once at the
+//! top, deduplicated by name, and each test contract is an interface using them. A struct
+//! declared with different fields by two ABIs fails the build.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+const BUILD_DIR_ENV: &str = "FIL_SOLIDITY_BUILD_DIR";
+const DEFAULT_BUILD_DIR: &str = "../build/v0.8";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={}", BUILD_DIR_ENV);
+
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let build_dir = match std::env::var(BUILD_DIR_ENV) {
+        Ok(dir) => PathBuf::from(dir),
+        Err(_) => manifest_dir.join(DEFAULT_BUILD_DIR),
+    };
+    let tests_dir = build_dir.join("tests");
+    println!("cargo:rerun-if-changed={}", tests_dir.display());
+
+    let mut structs = Structs::default();
+    let mut interfaces = String::new();
+    for abi_path in test_contract_abis(&tests_dir) {
+        println!("cargo:rerun-if-changed={}", abi_path.display());
+
+        let name = abi_path.file_stem().unwrap().to_string_lossy().into_owned();
+        let abi: Value = serde_json::from_str(&std::fs::read_to_string(&abi_path).unwrap())
+            .unwrap_or_else(|err| panic!("parsing {}: {}", abi_path.display(), err));
+
+        write_interface(&mut interfaces, &mut structs, &name, &abi);
+    }
+
+    // The library itself uses the generated structs, an empty `sol!` block would only fail
+    // later with unresolved types
+    if interfaces.is_empty() {
+        panic!(
+            "no test contract ABI in {}, run `make build_tests` first to generate the bindings",
+            tests_dir.display()
+        );
+    }
+
+    let mut bindings = String::from("alloy_sol_types::sol! {\n");
+    structs.write(&mut bindings);
+    bindings.push_str(&interfaces);
+    bindings.push_str("}\n");
+
+    let out_path = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("bindings.rs");
+    std::fs::write(out_path, bindings).unwrap();
+}
+
+// solc writes the ABI of every linked library next to the test contracts, only the
+// contracts themselves are `*Test`.
+fn test_contract_abis(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut abis: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().map_or(false, |ext| ext == "abi"))
+        .filter(|path| {
+            path.file_stem()
+                .map_or(false, |stem| stem.to_string_lossy().ends_with("Test"))
+        })
+        .collect();
+    abis.sort();
+
+    abis
+}
+
+/// Structs found in the ABIs, by name, with their fields as Solidity declarations.
+#[derive(Default)]
+struct Structs(BTreeMap<String, Vec<String>>);
+
+impl Structs {
+    fn insert(&mut self, name: &str, fields: Vec<String>) {
+        match self.0.get(name) {
+            Some(known) if *known != fields => panic!(
+                "struct {} is declared as {{ {} }} and {{ {} }} by the test contract ABIs",
+                name,
+                known.join("; "),
+                fields.join("; ")
+            ),
+            Some(_) => {}
+            None => {
+                self.0.insert(name.to_string(), fields);
+            }
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        for (name, fields) in &self.0 {
+            writeln!(out, "    struct {} {{", name).unwrap();
+            for field in fields {
+                writeln!(out, "        {};", field).unwrap();
+            }
+            writeln!(out, "    }}\n").unwrap();
+        }
+    }
+}
+
+fn write_interface(out: &mut String, structs: &mut Structs, name: &str, abi: &Value) {
+    writeln!(out, "    interface {} {{", name).unwrap();
+
+    for item in abi.as_array().unwrap() {
+        if item["type"] != "function" {
+            continue;
+        }
+
+        let inputs = params(structs, &item["inputs"]);
+        let outputs = params(structs, &item["outputs"]);
+        let mutability = match item["stateMutability"].as_str() {
+            Some("view") => " view",
+            Some("pure") => " pure",
+            Some("payable") => " payable",
+            _ => "",
+        };
+
+        write!(
+            out,
+            "        function {}({}) external{}",
+            item["name"].as_str().unwrap(),
+            inputs,
+            mutability
+        )
+        .unwrap();
+        if !outputs.is_empty() {
+            write!(out, " returns ({})", outputs).unwrap();
+        }
+        writeln!(out, ";").unwrap();
+    }
+
+    writeln!(out, "    }}\n").unwrap();
+}
+
+fn params(structs: &mut Structs, params: &Value) -> String {
+    let Some(params) = params.as_array() else {
+        return String::new();
+    };
+
+    params
+        .iter()
+        .map(|param| {
+            let ty = param_type(structs, param);
+            let location = match is_reference_type(&ty) {
+                true => " memory",
+                false => "",
+            };
+            match param["name"].as_str() {
+                Some(name) if !name.is_empty() => format!("{}{} {}", ty, location, name),
+                _ => format!("{}{}", ty, location),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Solidity type of an ABI parameter, registering the structs it uses. User defined value
+/// types are already their underlying type in the ABI.
+fn param_type(structs: &mut Structs, param: &Value) -> String {
+    let ty = param["type"].as_str().unwrap();
+    let Some(array_suffix) = ty.strip_prefix("tuple") else {
+        return ty.to_string();
+    };
+
+    let fields: Vec<String> = param["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|component| {
+            format!(
+                "{} {}",
+                param_type(structs, component),
+                component["name"].as_str().unwrap()
+            )
+        })
+        .collect();
+
+    // e.g. "struct MarketTypes.ClientDealProposal[]"
+    let struct_name = param["internalType"]
+        .as_str()
+        .and_then(|internal_type| internal_type.strip_prefix("struct "))
+        .map(|qualified| {
+            let name = qualified.rsplit('.').next().unwrap();
+            name.split('[').next().unwrap().to_string()
+        });
+
+    match struct_name {
+        Some(name) => {
+            structs.insert(&name, fields);
+            format!("{}{}", name, array_suffix)
+        }
+        None => {
+            let types: Vec<&str> = fields
+                .iter()
+                .map(|field| field.rsplit_once(' ').unwrap().0)
+                .collect();
+            format!("({}){}", types.join(", "), array_suffix)
+        }
+    }
+}
+
+fn is_reference_type(ty: &str) -> bool {
+    ty.ends_with(']')
+        || ty == "bytes"
+        || ty == "string"
+        || ty.starts_with('(')
+        || ty.chars().next().map_or(false, char::is_uppercase)
+}
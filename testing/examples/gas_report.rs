@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+// Aggregates the gas_results_*.json the integration suites write into one report.
+//
+//   cargo run --example gas_report [results dir] [output dir]
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let results_dir = PathBuf::from(args.next().unwrap_or_else(|| ".".to_string()));
+    let out_dir = PathBuf::from(args.next().unwrap_or_else(|| "gas_report".to_string()));
+
+    let paths = testing::report::write_report(&results_dir, &out_dir).unwrap();
+    for path in paths {
+        println!("Wrote {}", path.display());
+    }
+}
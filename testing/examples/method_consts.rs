@@ -0,0 +1,21 @@
+// Writes the *MethodNum constants of contracts/v0.8/types/*.sol and testing/src/consts.rs
+// from testing::methods::REGISTRY.
+//
+//   cargo run --example method_consts           regenerates the files
+//   cargo run --example method_consts -- --check fails if they are out of date
+fn main() {
+    let check = std::env::args().any(|arg| arg == "--check");
+
+    if check {
+        if let Err(err) = testing::method_consts::check() {
+            eprintln!("{:#}", err);
+            std::process::exit(1);
+        }
+        println!("Method constants are up to date");
+        return;
+    }
+
+    for path in testing::method_consts::generate().unwrap() {
+        println!("Updated {}", path.display());
+    }
+}
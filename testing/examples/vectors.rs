@@ -0,0 +1,21 @@
+// Writes testing/vectors/cbor_vectors.json, the CBOR golden vectors of the builtin-actors
+// types, from testing::vectors.
+//
+//   cargo run --example vectors           regenerates the fixture
+//   cargo run --example vectors -- --check fails if it is out of date
+fn main() {
+    let check = std::env::args().any(|arg| arg == "--check");
+
+    if check {
+        if let Err(err) = testing::vectors::check() {
+            eprintln!("{:#}", err);
+            std::process::exit(1);
+        }
+        println!("Vectors are up to date");
+        return;
+    }
+
+    if testing::vectors::generate().unwrap() {
+        println!("Updated {}", testing::vectors::default_path().display());
+    }
+}
@@ -1,6 +0,0 @@
-use alloy_sol_types::{sol, SolType};
-
-sol!{
-
-    function actorid_conversion() public pure {}
-}
\ No newline at end of file
@@ -1,16 +0,0 @@
-use alloy_sol_types::{sol, SolType};
-
-sol!{
-
-    function to_uint256() public view {}
-
-    function to_int256_positive() public view {}
-
-    function to_int256_negative() public view {}
-
-    function from_uint256() public view {}
-
-    function from_int256_positive() public view {}
-
-    function from_int256_negative() public view {}
-}
\ No newline at end of file
@@ -1,20 +0,0 @@
-use alloy_sol_types::{sol, SolType};
-
-sol!{
-
-    function decodeFixedArray() public pure {}
-
-    function decodeFalse() public pure {}
-
-    function decodeTrue() public pure {}
-
-    function decodeNull() public pure {}
-
-    function decodeInteger() public pure {}
-
-    function decodeString() public pure {}
-
-    function decodeStringWithWeirdChar() public pure {}
-
-    function decodeArrayU8() public pure {}
-}
\ No newline at end of file
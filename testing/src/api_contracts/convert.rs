@@ -0,0 +1,390 @@
+//! Conversions between the Solidity types of `crate::bindings` and the fvm_shared, cid and
+//! builtin-actors types they mirror.
+//!
+//! Values go to Solidity with `From`, and come back with `TryFrom` where the bytes may not
+//! parse, e.g. `TokenAmount::from_atto(100).into()` or `Address::try_from(ret._0.owner)?`.
+
+use anyhow::{Context, Result};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::{BigInt as FilBigInt, Sign};
+use fvm_shared::clock::ChainEpoch as FilChainEpoch;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::piece::PaddedPieceSize;
+
+use crate::bindings::{
+    ActiveBeneficiary, BatchReturn, BeneficiaryTerm, BigInt, Cid, Claim, ClaimTerm, DealLabel,
+    DealProposal, FailCode, FilAddress, PendingBeneficiaryChange, VestingFunds,
+};
+
+/// Big-endian magnitude and sign of `value`, zero having an empty magnitude as the Solidity
+/// libraries write it.
+pub fn bigint_parts(value: &FilBigInt) -> (Vec<u8>, bool) {
+    match value.to_bytes_be() {
+        (Sign::NoSign, _) => (vec![], false),
+        (sign, magnitude) => (magnitude, sign == Sign::Minus),
+    }
+}
+
+pub fn bigint_from_parts(val: &[u8], neg: bool) -> FilBigInt {
+    let sign = if neg { Sign::Minus } else { Sign::Plus };
+    FilBigInt::from_bytes_be(sign, val)
+}
+
+fn cid_from_bytes(data: &[u8]) -> Result<cid::Cid> {
+    cid::Cid::try_from(data).with_context(|| format!("invalid CID {}", hex::encode(data)))
+}
+
+/// CID of a byte string read with `CBORDecoder.readBytes`, which keeps the multibase 0x00
+/// prefix of the tag 42 payload.
+pub fn cid_from_link_bytes(data: &[u8]) -> Result<cid::Cid> {
+    match data {
+        [0x00, cid @ ..] => cid_from_bytes(cid),
+        _ => anyhow::bail!("CID link {} lacks the 0x00 prefix", hex::encode(data)),
+    }
+}
+
+impl From<&FilBigInt> for BigInt {
+    fn from(value: &FilBigInt) -> Self {
+        let (val, neg) = bigint_parts(value);
+        Self { val, neg }
+    }
+}
+
+impl From<FilBigInt> for BigInt {
+    fn from(value: FilBigInt) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&TokenAmount> for BigInt {
+    fn from(value: &TokenAmount) -> Self {
+        Self::from(value.atto())
+    }
+}
+
+impl From<TokenAmount> for BigInt {
+    fn from(value: TokenAmount) -> Self {
+        Self::from(value.atto())
+    }
+}
+
+impl From<&BigInt> for FilBigInt {
+    fn from(value: &BigInt) -> Self {
+        bigint_from_parts(&value.val, value.neg)
+    }
+}
+
+impl From<BigInt> for FilBigInt {
+    fn from(value: BigInt) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&BigInt> for TokenAmount {
+    fn from(value: &BigInt) -> Self {
+        Self::from_atto(FilBigInt::from(value))
+    }
+}
+
+impl From<BigInt> for TokenAmount {
+    fn from(value: BigInt) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&Address> for FilAddress {
+    fn from(value: &Address) -> Self {
+        Self {
+            data: value.to_bytes(),
+        }
+    }
+}
+
+impl From<Address> for FilAddress {
+    fn from(value: Address) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl TryFrom<&FilAddress> for Address {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &FilAddress) -> Result<Self> {
+        Address::from_bytes(&value.data)
+            .with_context(|| format!("invalid address {}", hex::encode(&value.data)))
+    }
+}
+
+impl TryFrom<FilAddress> for Address {
+    type Error = anyhow::Error;
+
+    fn try_from(value: FilAddress) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl From<&cid::Cid> for Cid {
+    fn from(value: &cid::Cid) -> Self {
+        Self {
+            data: value.to_bytes(),
+        }
+    }
+}
+
+impl From<cid::Cid> for Cid {
+    fn from(value: cid::Cid) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl TryFrom<&Cid> for cid::Cid {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Cid) -> Result<Self> {
+        cid_from_bytes(&value.data)
+    }
+}
+
+impl TryFrom<Cid> for cid::Cid {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Cid) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl From<&fil_actors_runtime::BatchReturn> for BatchReturn {
+    fn from(value: &fil_actors_runtime::BatchReturn) -> Self {
+        Self {
+            success_count: value.success_count,
+            fail_codes: value
+                .fail_codes
+                .iter()
+                .map(|fail| FailCode {
+                    idx: fail.idx,
+                    code: fail.code.value(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&BatchReturn> for fil_actors_runtime::BatchReturn {
+    fn from(value: &BatchReturn) -> Self {
+        Self {
+            success_count: value.success_count,
+            fail_codes: value
+                .fail_codes
+                .iter()
+                .map(|fail| fil_actors_runtime::FailCode {
+                    idx: fail.idx,
+                    code: ExitCode::new(fail.code),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&fil_actor_market::Label> for DealLabel {
+    fn from(value: &fil_actor_market::Label) -> Self {
+        match value {
+            fil_actor_market::Label::String(label) => Self {
+                data: label.as_bytes().to_vec(),
+                isString: true,
+            },
+            fil_actor_market::Label::Bytes(label) => Self {
+                data: label.clone(),
+                isString: false,
+            },
+        }
+    }
+}
+
+impl TryFrom<&DealLabel> for fil_actor_market::Label {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &DealLabel) -> Result<Self> {
+        if !value.isString {
+            return Ok(Self::Bytes(value.data.clone()));
+        }
+
+        let label = String::from_utf8(value.data.clone()).context("string label is not UTF-8")?;
+        Ok(Self::String(label))
+    }
+}
+
+impl From<&fil_actor_market::DealProposal> for DealProposal {
+    fn from(value: &fil_actor_market::DealProposal) -> Self {
+        Self {
+            piece_cid: (&value.piece_cid).into(),
+            piece_size: value.piece_size.0,
+            verified_deal: value.verified_deal,
+            client: (&value.client).into(),
+            provider: (&value.provider).into(),
+            label: (&value.label).into(),
+            start_epoch: value.start_epoch,
+            end_epoch: value.end_epoch,
+            storage_price_per_epoch: (&value.storage_price_per_epoch).into(),
+            provider_collateral: (&value.provider_collateral).into(),
+            client_collateral: (&value.client_collateral).into(),
+        }
+    }
+}
+
+impl TryFrom<&DealProposal> for fil_actor_market::DealProposal {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &DealProposal) -> Result<Self> {
+        Ok(Self {
+            piece_cid: (&value.piece_cid).try_into()?,
+            piece_size: PaddedPieceSize(value.piece_size),
+            verified_deal: value.verified_deal,
+            client: (&value.client).try_into()?,
+            provider: (&value.provider).try_into()?,
+            label: (&value.label).try_into()?,
+            start_epoch: value.start_epoch,
+            end_epoch: value.end_epoch,
+            storage_price_per_epoch: (&value.storage_price_per_epoch).into(),
+            provider_collateral: (&value.provider_collateral).into(),
+            client_collateral: (&value.client_collateral).into(),
+        })
+    }
+}
+
+impl From<&(FilChainEpoch, TokenAmount)> for VestingFunds {
+    fn from((epoch, amount): &(FilChainEpoch, TokenAmount)) -> Self {
+        Self {
+            epoch: *epoch,
+            amount: amount.into(),
+        }
+    }
+}
+
+impl From<&VestingFunds> for (FilChainEpoch, TokenAmount) {
+    fn from(value: &VestingFunds) -> Self {
+        (value.epoch, (&value.amount).into())
+    }
+}
+
+impl From<&fil_actor_miner::BeneficiaryTerm> for BeneficiaryTerm {
+    fn from(value: &fil_actor_miner::BeneficiaryTerm) -> Self {
+        Self {
+            quota: (&value.quota).into(),
+            used_quota: (&value.used_quota).into(),
+            expiration: value.expiration,
+        }
+    }
+}
+
+impl From<&BeneficiaryTerm> for fil_actor_miner::BeneficiaryTerm {
+    fn from(value: &BeneficiaryTerm) -> Self {
+        Self {
+            quota: (&value.quota).into(),
+            used_quota: (&value.used_quota).into(),
+            expiration: value.expiration,
+        }
+    }
+}
+
+impl From<&fil_actor_miner::ActiveBeneficiary> for ActiveBeneficiary {
+    fn from(value: &fil_actor_miner::ActiveBeneficiary) -> Self {
+        Self {
+            beneficiary: (&value.beneficiary).into(),
+            term: (&value.term).into(),
+        }
+    }
+}
+
+impl TryFrom<&ActiveBeneficiary> for fil_actor_miner::ActiveBeneficiary {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &ActiveBeneficiary) -> Result<Self> {
+        Ok(Self {
+            beneficiary: (&value.beneficiary).try_into()?,
+            term: (&value.term).into(),
+        })
+    }
+}
+
+impl From<&fil_actor_miner::PendingBeneficiaryChange> for PendingBeneficiaryChange {
+    fn from(value: &fil_actor_miner::PendingBeneficiaryChange) -> Self {
+        Self {
+            new_beneficiary: (&value.new_beneficiary).into(),
+            new_quota: (&value.new_quota).into(),
+            new_expiration: value.new_expiration,
+            approved_by_beneficiary: value.approved_by_beneficiary,
+            approved_by_nominee: value.approved_by_nominee,
+        }
+    }
+}
+
+impl TryFrom<&PendingBeneficiaryChange> for fil_actor_miner::PendingBeneficiaryChange {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &PendingBeneficiaryChange) -> Result<Self> {
+        Ok(Self {
+            new_beneficiary: (&value.new_beneficiary).try_into()?,
+            new_quota: (&value.new_quota).into(),
+            new_expiration: value.new_expiration,
+            approved_by_beneficiary: value.approved_by_beneficiary,
+            approved_by_nominee: value.approved_by_nominee,
+        })
+    }
+}
+
+impl From<&fil_actor_verifreg::ClaimTerm> for ClaimTerm {
+    fn from(value: &fil_actor_verifreg::ClaimTerm) -> Self {
+        Self {
+            provider: value.provider,
+            claim_id: value.claim_id,
+            term_max: value.term_max,
+        }
+    }
+}
+
+impl From<&ClaimTerm> for fil_actor_verifreg::ClaimTerm {
+    fn from(value: &ClaimTerm) -> Self {
+        Self {
+            provider: value.provider,
+            claim_id: value.claim_id,
+            term_max: value.term_max,
+        }
+    }
+}
+
+/// The piece CID is held as read by `CBORDecoder.readBytes`, with its multibase 0x00 prefix.
+impl From<&fil_actor_verifreg::Claim> for Claim {
+    fn from(value: &fil_actor_verifreg::Claim) -> Self {
+        let mut data = vec![0x00];
+        data.extend(value.data.to_bytes());
+
+        Self {
+            provider: value.provider,
+            client: value.client,
+            data,
+            size: value.size.0,
+            term_min: value.term_min,
+            term_max: value.term_max,
+            term_start: value.term_start,
+            sector: value.sector,
+        }
+    }
+}
+
+impl TryFrom<&Claim> for fil_actor_verifreg::Claim {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Claim) -> Result<Self> {
+        Ok(Self {
+            provider: value.provider,
+            client: value.client,
+            data: cid_from_link_bytes(&value.data)?,
+            size: PaddedPieceSize(value.size),
+            term_min: value.term_min,
+            term_max: value.term_max,
+            term_start: value.term_start,
+            sector: value.sector,
+        })
+    }
+}
@@ -1,18 +1,7 @@
+use anyhow::{bail, ensure, Context, Result};
 use cbor_data::{CborBuilder, Encoder};
 
-pub mod account_test;
-pub mod address_test;
-pub mod bigints_test;
-pub mod cbor_decode_test;
-pub mod datacap_test;
-pub mod deserialize_params_test;
-pub mod market_cbor_test;
-pub mod market_test;
-pub mod miner_test;
-pub mod power_test;
-pub mod precompiles_test;
-pub mod send_test;
-pub mod verifreg_test;
+pub mod convert;
 
 pub fn cbor_encode(abi_encoded_call: Vec<u8>) -> String {
   let full_str = hex::encode(CborBuilder::default().encode_array(|builder| {
@@ -20,4 +9,33 @@ pub fn cbor_encode(abi_encoded_call: Vec<u8>) -> String {
   }));
   let encoded = full_str[2..].to_string(); 
   return encoded;
-}
\ No newline at end of file
+}
+
+/// Inverse of `cbor_encode`: strips the CBOR byte string header wrapping the ABI encoded
+/// data returned by `InvokeContract`.
+pub fn cbor_decode(return_data: &[u8]) -> Result<Vec<u8>> {
+  let (&header, rest) = return_data.split_first().context("empty return data")?;
+  if header >> 5 != 2 {
+    bail!("expected a CBOR byte string, got major type {}", header >> 5);
+  }
+
+  let (len, data) = match header & 0x1f {
+    n @ 0..=23 => (n as usize, rest),
+    n @ 24..=27 => {
+      let size = 1 << (n - 24);
+      ensure!(rest.len() >= size, "truncated CBOR byte string length");
+      let len = rest[..size].iter().fold(0_usize, |len, byte| len << 8 | *byte as usize);
+      (len, &rest[size..])
+    }
+    n => bail!("unsupported CBOR byte string length encoding {}", n),
+  };
+
+  ensure!(
+    data.len() == len,
+    "CBOR byte string of {} bytes holds {} bytes",
+    len,
+    data.len()
+  );
+
+  Ok(data.to_vec())
+}
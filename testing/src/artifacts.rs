@@ -0,0 +1,267 @@
+use alloy_json_abi::JsonAbi;
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+/// Overrides the directory solc writes the artifacts to, `build/v0.8` by default.
+pub const BUILD_DIR_ENV: &str = "FIL_SOLIDITY_BUILD_DIR";
+
+const DEFAULT_BUILD_DIR: &str = "../build/v0.8";
+const SOURCES_DIR: &str = "../contracts/v0.8";
+
+static ARTIFACTS: OnceLock<Artifacts> = OnceLock::new();
+
+/// Compiled contract, as emitted by `make build`.
+#[derive(Clone, Debug)]
+pub struct Artifact {
+    pub name: String,
+    pub bin_path: PathBuf,
+    pub bytecode: Vec<u8>,
+    pub abi: JsonAbi,
+}
+
+/// Index of the `.bin`/`.abi` pairs under the build directory, keyed by contract name.
+///
+/// solc writes every library a contract links next to it, so the same name can appear in
+/// several directories. Such names are looked up by their path relative to the build
+/// directory instead, e.g. `tests/MarketAPI`.
+pub struct Artifacts {
+    build_dir: PathBuf,
+    by_name: HashMap<String, Vec<PathBuf>>,
+    // Contract name to the Solidity file declaring it, to tell where it is declared
+    sources: HashMap<String, PathBuf>,
+    // Most recently modified source, any artifact older than it may be stale since
+    // contracts import each other
+    newest_source: Option<(PathBuf, SystemTime)>,
+}
+
+/// Artifacts of the build directory, indexed once per process.
+pub fn artifacts() -> &'static Artifacts {
+    ARTIFACTS.get_or_init(|| Artifacts::from_env().unwrap())
+}
+
+/// Directory solc writes the artifacts to, `BUILD_DIR_ENV` or `build/v0.8`.
+pub fn build_dir() -> PathBuf {
+    match std::env::var(BUILD_DIR_ENV) {
+        Ok(dir) => PathBuf::from(dir),
+        Err(_) => Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_BUILD_DIR),
+    }
+}
+
+impl Artifacts {
+    pub fn from_env() -> Result<Self> {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        Self::index(&build_dir(), &manifest_dir.join(SOURCES_DIR))
+    }
+
+    /// Indexes every `.bin` under `build_dir`, and every contract, library and interface
+    /// declared in the `.sol` files under `sources_dir`.
+    pub fn index(build_dir: &Path, sources_dir: &Path) -> Result<Self> {
+        if !build_dir.is_dir() {
+            bail!(
+                "build directory {} not found, run `make build` first",
+                build_dir.display()
+            );
+        }
+
+        let mut by_name: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        for path in files_with_extension(build_dir, "bin")? {
+            let name = file_stem(&path);
+            by_name.entry(name).or_default().push(path);
+        }
+
+        let mut sources = HashMap::new();
+        let mut newest_source: Option<(PathBuf, SystemTime)> = None;
+        if sources_dir.is_dir() {
+            for path in files_with_extension(sources_dir, "sol")? {
+                let source = std::fs::read_to_string(&path)
+                    .with_context(|| format!("reading {}", path.display()))?;
+                for name in declared_names(&source) {
+                    sources.insert(name, path.clone());
+                }
+
+                let changed = modified(&path)?;
+                if newest_source.as_ref().map_or(true, |(_, newest)| changed > *newest) {
+                    newest_source = Some((path, changed));
+                }
+            }
+        }
+
+        Ok(Artifacts {
+            build_dir: build_dir.to_path_buf(),
+            by_name,
+            sources,
+            newest_source,
+        })
+    }
+
+    pub fn build_dir(&self) -> &Path {
+        &self.build_dir
+    }
+
+    /// Every indexed artifact, as the name to pass to `load`.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .by_name
+            .iter()
+            .flat_map(|(name, paths)| match paths.len() {
+                1 => vec![name.clone()],
+                _ => paths.iter().map(|path| self.relative_name(path)).collect(),
+            })
+            .collect();
+        names.sort();
+
+        names
+    }
+
+    /// Path of the `.bin` for `name`, either a contract name or a path relative to the
+    /// build directory without extension.
+    pub fn bin_path(&self, name: &str) -> Result<PathBuf> {
+        if name.contains('/') {
+            let path = self.build_dir.join(format!("{}.bin", name));
+            if !path.is_file() {
+                bail!("no artifact {} in {}", name, self.build_dir.display());
+            }
+            return Ok(path);
+        }
+
+        match self.by_name.get(name).map(Vec::as_slice) {
+            None | Some([]) => Err(anyhow!(
+                "no artifact named {} in {}, is it listed in the Makefile build targets?",
+                name,
+                self.build_dir.display()
+            )),
+            Some([path]) => Ok(path.clone()),
+            Some(paths) => {
+                let candidates: Vec<String> = paths.iter().map(|path| self.relative_name(path)).collect();
+                Err(anyhow!(
+                    "artifact name {} is ambiguous, use one of {}",
+                    name,
+                    candidates.join(", ")
+                ))
+            }
+        }
+    }
+
+    /// Loads the bytecode and ABI of `name`, failing if either is missing, empty, or
+    /// older than any Solidity file under the sources directory.
+    pub fn load(&self, name: &str) -> Result<Artifact> {
+        let bin_path = self.bin_path(name)?;
+        let contract_name = file_stem(&bin_path);
+        let abi_path = bin_path.with_extension("abi");
+
+        if !abi_path.is_file() {
+            bail!(
+                "{} has no ABI next to it, build it with --abi",
+                bin_path.display()
+            );
+        }
+
+        self.check_fresh(&contract_name, &bin_path)?;
+
+        let bin = std::fs::read_to_string(&bin_path)
+            .with_context(|| format!("reading {}", bin_path.display()))?;
+        let bytecode = hex::decode(bin.trim())
+            .with_context(|| format!("{} is not hex encoded bytecode", bin_path.display()))?;
+        if bytecode.is_empty() {
+            bail!(
+                "{} is empty, {} is abstract or an interface",
+                bin_path.display(),
+                contract_name
+            );
+        }
+
+        let abi_json = std::fs::read_to_string(&abi_path)
+            .with_context(|| format!("reading {}", abi_path.display()))?;
+        let abi: JsonAbi = serde_json::from_str(&abi_json)
+            .with_context(|| format!("{} is not a valid JSON ABI", abi_path.display()))?;
+
+        Ok(Artifact {
+            name: contract_name,
+            bin_path,
+            bytecode,
+            abi,
+        })
+    }
+
+    // The contract may import any other source, so the artifact is compared with the
+    // newest of them rather than with the file declaring the contract only.
+    fn check_fresh(&self, contract_name: &str, bin_path: &Path) -> Result<()> {
+        let Some((newest, changed)) = &self.newest_source else {
+            return Ok(());
+        };
+
+        let built = modified(bin_path)?;
+        if *changed > built {
+            let declared_in = self
+                .sources
+                .get(contract_name)
+                .map(|source| format!(" (declared in {})", source.display()))
+                .unwrap_or_default();
+            bail!(
+                "{}{} is stale, {} changed since it was built, run `make build`",
+                bin_path.display(),
+                declared_in,
+                newest.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn relative_name(&self, bin_path: &Path) -> String {
+        let relative = bin_path.strip_prefix(&self.build_dir).unwrap_or(bin_path);
+        relative.with_extension("").to_string_lossy().into_owned()
+    }
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().unwrap().to_string_lossy().into_owned()
+}
+
+fn modified(path: &Path) -> Result<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .with_context(|| format!("reading the modification time of {}", path.display()))
+}
+
+fn files_with_extension(dir: &Path, extension: &str) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(files_with_extension(&path, extension)?);
+        } else if path.extension().map_or(false, |ext| ext == extension) {
+            files.push(path);
+        }
+    }
+
+    Ok(files)
+}
+
+// Names of the contracts, libraries and interfaces declared at the start of a line.
+fn declared_names(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            let mut keyword = words.next()?;
+            if keyword == "abstract" {
+                keyword = words.next()?;
+            }
+            if !matches!(keyword, "contract" | "library" | "interface") {
+                return None;
+            }
+
+            let name: String = words
+                .next()?
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            (!name.is_empty()).then_some(name)
+        })
+        .collect()
+}
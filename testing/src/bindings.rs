@@ -0,0 +1,7 @@
+//! Typed bindings generated by `build.rs` from the test contract ABIs, one module per
+//! contract, e.g. `bindings::MarketApiTest::get_balanceCall`. The structs are shared by every
+//! contract, e.g. `bindings::BigInt`, see `api_contracts::convert` for their conversions.
+//!
+//! A contract only has bindings once `make build_tests` emitted its ABI.
+
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use cid::Cid;
+use fvm_ipld_blockstore::Blockstore;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Immutable set of blocks that can be shared between tests running on different threads.
+pub type FrozenBlocks = Arc<HashMap<Cid, Vec<u8>>>;
+
+/// Copy-on-write blockstore: reads fall through to frozen base layers, writes only ever
+/// land in a local overlay. Forking a cached base state is then just an `Arc` clone.
+///
+/// Clones share the same overlay, like handles to the same store.
+#[derive(Clone, Default)]
+pub struct CowBlockstore {
+    base: Vec<FrozenBlocks>,
+    overlay: Rc<RefCell<HashMap<Cid, Vec<u8>>>>,
+}
+
+impl CowBlockstore {
+    pub fn new(base: FrozenBlocks) -> Self {
+        CowBlockstore {
+            base: vec![base],
+            overlay: Default::default(),
+        }
+    }
+
+    /// Copies the overlay into a new frozen layer and returns every layer, so that a new
+    /// store can be forked from the current content with `CowBlockstore::from_layers`.
+    pub fn freeze(&self) -> Vec<FrozenBlocks> {
+        let mut layers = self.base.clone();
+        let overlay = self.overlay.borrow();
+        if !overlay.is_empty() {
+            layers.push(Arc::new(overlay.clone()));
+        }
+
+        layers
+    }
+
+    pub fn from_layers(base: Vec<FrozenBlocks>) -> Self {
+        CowBlockstore {
+            base,
+            overlay: Default::default(),
+        }
+    }
+}
+
+impl Blockstore for CowBlockstore {
+    fn get(&self, k: &Cid) -> Result<Option<Vec<u8>>> {
+        if let Some(block) = self.overlay.borrow().get(k) {
+            return Ok(Some(block.clone()));
+        }
+
+        Ok(self
+            .base
+            .iter()
+            .rev()
+            .find_map(|layer| layer.get(k).cloned()))
+    }
+
+    fn put_keyed(&self, k: &Cid, block: &[u8]) -> Result<()> {
+        self.overlay.borrow_mut().insert(*k, block.to_vec());
+        Ok(())
+    }
+
+    fn has(&self, k: &Cid) -> Result<bool> {
+        Ok(self.overlay.borrow().contains_key(k) || self.base.iter().any(|layer| layer.contains_key(k)))
+    }
+}
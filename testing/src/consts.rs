@@ -0,0 +1,103 @@
+// Generated from testing::methods::REGISTRY by
+// `cargo run --example method_consts`, do not edit.
+
+//! FRC-42 method numbers of the builtin actors.
+
+pub mod account {
+    use fvm_shared::MethodNum;
+
+    pub const AUTHENTICATE_MESSAGE: MethodNum = 2643134072;
+}
+
+pub mod common {
+    use fvm_shared::MethodNum;
+
+    pub const RECEIVE: MethodNum = 3726118371;
+}
+
+pub mod market {
+    use fvm_shared::MethodNum;
+
+    pub const ADD_BALANCE: MethodNum = 822473126;
+    pub const WITHDRAW_BALANCE: MethodNum = 2280458852;
+    pub const GET_BALANCE: MethodNum = 726108461;
+    pub const GET_DEAL_DATA_COMMITMENT: MethodNum = 1157985802;
+    pub const GET_DEAL_CLIENT: MethodNum = 128053329;
+    pub const GET_DEAL_PROVIDER: MethodNum = 935081690;
+    pub const GET_DEAL_LABEL: MethodNum = 46363526;
+    pub const GET_DEAL_TERM: MethodNum = 163777312;
+    pub const GET_DEAL_TOTAL_PRICE: MethodNum = 4287162428;
+    pub const GET_DEAL_CLIENT_COLLATERAL: MethodNum = 200567895;
+    pub const GET_DEAL_PROVIDER_COLLATERAL: MethodNum = 2986712137;
+    pub const GET_DEAL_VERIFIED: MethodNum = 2627389465;
+    pub const GET_DEAL_ACTIVATION: MethodNum = 2567238399;
+    pub const PUBLISH_STORAGE_DEALS: MethodNum = 2236929350;
+}
+
+pub mod miner {
+    use fvm_shared::MethodNum;
+
+    pub const GET_OWNER: MethodNum = 3275365574;
+    pub const CHANGE_OWNER_ADDRESS: MethodNum = 1010589339;
+    pub const IS_CONTROLLING_ADDRESS: MethodNum = 348244887;
+    pub const GET_SECTOR_SIZE: MethodNum = 3858292296;
+    pub const GET_AVAILABLE_BALANCE: MethodNum = 4026106874;
+    pub const GET_VESTING_FUNDS: MethodNum = 1726876304;
+    pub const CHANGE_BENEFICIARY: MethodNum = 1570634796;
+    pub const GET_BENEFICIARY: MethodNum = 4158972569;
+    pub const CHANGE_WORKER_ADDRESS: MethodNum = 3302309124;
+    pub const CHANGE_PEER_ID: MethodNum = 1236548004;
+    pub const CHANGE_MULTIADDRS: MethodNum = 1063480576;
+    pub const REPAY_DEBT: MethodNum = 3665352697;
+    pub const CONFIRM_CHANGE_WORKER_ADDRESS: MethodNum = 2354970453;
+    pub const GET_PEER_ID: MethodNum = 2812875329;
+    pub const GET_MULTIADDRS: MethodNum = 1332909407;
+    pub const WITHDRAW_BALANCE: MethodNum = 2280458852;
+}
+
+pub mod power {
+    use fvm_shared::MethodNum;
+
+    pub const CREATE_MINER: MethodNum = 1173380165;
+    pub const MINER_COUNT: MethodNum = 1987646258;
+    pub const MINER_CONSENSUS_COUNT: MethodNum = 196739875;
+    pub const NETWORK_RAW_POWER: MethodNum = 931722534;
+    pub const MINER_RAW_POWER: MethodNum = 3753401894;
+}
+
+pub mod verifreg {
+    use fvm_shared::MethodNum;
+
+    pub const GET_CLAIMS: MethodNum = 2199871187;
+    pub const ADD_VERIFIED_CLIENT: MethodNum = 3916220144;
+    pub const REMOVE_EXPIRED_ALLOCATIONS: MethodNum = 2421068268;
+    pub const EXTEND_CLAIM_TERMS: MethodNum = 1752273514;
+    pub const REMOVE_EXPIRED_CLAIMS: MethodNum = 2873373899;
+}
+
+pub mod datacap {
+    use fvm_shared::MethodNum;
+
+    pub const NAME: MethodNum = 48890204;
+    pub const SYMBOL: MethodNum = 2061153854;
+    pub const TOTAL_SUPPLY: MethodNum = 114981429;
+    pub const BALANCE: MethodNum = 3261979605;
+    pub const TRANSFER: MethodNum = 80475954;
+    pub const TRANSFER_FROM: MethodNum = 3621052141;
+    pub const INCREASE_ALLOWANCE: MethodNum = 1777121560;
+    pub const DECREASE_ALLOWANCE: MethodNum = 1529376545;
+    pub const REVOKE_ALLOWANCE: MethodNum = 2765635761;
+    pub const BURN: MethodNum = 1434719642;
+    pub const BURN_FROM: MethodNum = 2979674018;
+    pub const ALLOWANCE: MethodNum = 4205072950;
+    pub const GRANULARITY: MethodNum = 3936767397;
+    pub const MINT: MethodNum = 116935346;
+    pub const DESTROY: MethodNum = 2624896501;
+}
+
+pub mod evm {
+    use fvm_shared::MethodNum;
+
+    pub const GET_BYTECODE: MethodNum = 3;
+    pub const INVOKE_CONTRACT: MethodNum = 3844450837;
+}
@@ -0,0 +1,370 @@
+use alloy_sol_types::SolCall;
+use cid::Cid;
+use fil_actor_eam::Return;
+use fil_actor_evm::Method as EvmMethods;
+use fil_actors_runtime::EAM_ACTOR_ADDR;
+use fvm::executor::{ApplyKind, ApplyRet, Executor};
+use fvm::machine::{Machine, Manifest};
+use fvm::state_tree::StateTree;
+use fvm::trace::ExecutionTrace;
+use fvm_integration_tests::dummy::DummyExterns;
+use fvm_integration_tests::tester::{Account, IntegrationExecutor, Tester};
+use fvm_ipld_encoding::{strict_bytes, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::message::Message;
+use fvm_shared::{ActorID, MethodNum};
+use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
+
+use crate::blockstore::{CowBlockstore, FrozenBlocks};
+use crate::genesis::GenesisConfig;
+use crate::revert::Revert;
+use crate::setup::{self, TesterConfig};
+use crate::trace::{self, ActorInfo};
+use crate::api_contracts;
+use crate::artifacts;
+
+pub const DEFAULT_GAS_LIMIT: u64 = 1000000000;
+
+// EAM `CreateExternal` method
+const CREATE_EXTERNAL_METHOD_NUM: MethodNum = 4;
+
+#[derive(SerdeSerialize, SerdeDeserialize)]
+#[serde(transparent)]
+pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+
+/// A contract deployed through the EAM actor.
+#[derive(Clone, Debug)]
+pub struct ContractHandle {
+    pub actor_id: ActorID,
+    pub robust_address: Option<Address>,
+    pub eth_address: [u8; 20],
+}
+
+impl ContractHandle {
+    pub fn address(&self) -> Address {
+        Address::new_id(self.actor_id)
+    }
+}
+
+/// Frozen state of a `TestEnv`. Any number of tests, on any thread, can fork from it
+/// with `TestEnv::fork` to share one expensive fixture.
+#[derive(Clone)]
+pub struct Snapshot {
+    pub root: Cid,
+    blocks: Vec<FrozenBlocks>,
+    config: TesterConfig,
+    sender: Account,
+}
+
+/// Owns the tester and its executor, and fills in the sequence of every explicit
+/// message from the sender's actor state, so tests never number messages by hand.
+///
+/// The state tree can be modified (accounts, placeholders, custom actors) until the
+/// first message is sent, which is when the machine gets instantiated.
+pub struct TestEnv {
+    pub tester: Tester<CowBlockstore, DummyExterns>,
+    pub manifest: Manifest,
+    pub config: TesterConfig,
+    /// Handle on the store shared with the state tree, or the machine once instantiated.
+    pub blockstore: CowBlockstore,
+    /// Default sender for `deploy` and `invoke`, always the first account created (ID 100).
+    pub sender: Account,
+}
+
+impl TestEnv {
+    pub fn new() -> Self {
+        Self::with_config(TesterConfig::from_env())
+    }
+
+    pub fn with_genesis(genesis: GenesisConfig) -> Self {
+        Self::with_config(TesterConfig::from_env().with_genesis(genesis))
+    }
+
+    pub fn with_config(config: TesterConfig) -> Self {
+        let (mut tester, manifest) = setup::setup_tester(&config);
+        let blockstore = tester.state_tree.as_ref().unwrap().store().clone();
+
+        let [sender]: [Account; 1] = tester.create_accounts().unwrap();
+
+        TestEnv {
+            tester,
+            manifest,
+            config,
+            blockstore,
+            sender,
+        }
+    }
+
+    /// New environment, with its own machine, starting from the snapshot state.
+    pub fn fork(snapshot: &Snapshot) -> Self {
+        let blockstore = CowBlockstore::from_layers(snapshot.blocks.clone());
+        let (tester, manifest) =
+            setup::setup_tester_from_root(&snapshot.config, blockstore.clone(), &snapshot.root);
+
+        TestEnv {
+            tester,
+            manifest,
+            config: snapshot.config.clone(),
+            blockstore,
+            sender: snapshot.sender,
+        }
+    }
+
+    /// Flushes the state tree to its root and freezes the blocks written so far. The
+    /// environment stays usable, further changes are not part of the snapshot.
+    pub fn snapshot(&mut self) -> Snapshot {
+        let root = match self.tester.executor.as_mut() {
+            Some(executor) => executor.flush().unwrap(),
+            None => self.tester.state_tree.as_mut().unwrap().flush().unwrap(),
+        };
+
+        Snapshot {
+            root,
+            blocks: self.blockstore.freeze(),
+            config: self.config.clone(),
+            sender: self.sender,
+        }
+    }
+
+    pub fn create_accounts<const N: usize>(&mut self) -> [Account; N] {
+        self.assert_not_instantiated();
+        self.tester.create_accounts().unwrap()
+    }
+
+    pub fn create_placeholder(&mut self, address: &Address, balance: TokenAmount) {
+        self.assert_not_instantiated();
+        self.tester.create_placeholder(address, balance).unwrap()
+    }
+
+    /// State tree before the machine is instantiated, to install actors by hand.
+    pub fn state_tree_mut(&mut self) -> &mut StateTree<CowBlockstore> {
+        self.assert_not_instantiated();
+        self.tester.state_tree.as_mut().unwrap()
+    }
+
+    /// Instantiates the machine on first use.
+    pub fn executor(&mut self) -> &mut IntegrationExecutor<CowBlockstore, DummyExterns> {
+        if self.tester.executor.is_none() {
+            self.tester.instantiate_machine(DummyExterns).unwrap();
+        }
+
+        self.tester.executor.as_mut().unwrap()
+    }
+
+    /// Next sequence expected from `from`, read from its actor state.
+    pub fn next_sequence(&mut self, from: &Address) -> u64 {
+        let state_tree = self.executor().state_tree();
+        let actor_id = state_tree
+            .lookup_id(from)
+            .unwrap()
+            .unwrap_or_else(|| panic!("sender {} not found in the state tree", from));
+
+        state_tree
+            .get_actor(actor_id)
+            .unwrap()
+            .unwrap_or_else(|| panic!("no actor for sender {}", from))
+            .sequence
+    }
+
+    /// Applies an explicit message, overriding its sequence with the sender's next one.
+    pub fn send(&mut self, message: Message) -> ApplyRet {
+        let sequence = self.next_sequence(&message.from);
+        let message = Message {
+            sequence,
+            ..message
+        };
+
+        self.executor()
+            .execute_message(message, ApplyKind::Explicit, 100)
+            .unwrap()
+    }
+
+    /// Applies an implicit message, e.g. from the system actor. Sequence is not checked.
+    pub fn send_implicit(&mut self, message: Message) -> ApplyRet {
+        self.executor()
+            .execute_message(message, ApplyKind::Implicit, 100)
+            .unwrap()
+    }
+
+    pub fn call(
+        &mut self,
+        from: Address,
+        to: Address,
+        method_num: MethodNum,
+        params: RawBytes,
+        value: TokenAmount,
+    ) -> ApplyRet {
+        self.send(Message {
+            from,
+            to,
+            gas_limit: DEFAULT_GAS_LIMIT,
+            method_num,
+            params,
+            value,
+            ..Message::default()
+        })
+    }
+
+    pub fn deploy(&mut self, path: &str) -> ContractHandle {
+        let from = self.sender.1;
+        self.deploy_from(from, path)
+    }
+
+    /// Deploys the hex encoded bytecode at `path` through the EAM actor, panicking if it fails.
+    pub fn deploy_from(&mut self, from: Address, path: &str) -> ContractHandle {
+        let evm_bin = setup::load_evm(path);
+        self.deploy_bytecode(from, evm_bin, path)
+    }
+
+    pub fn deploy_contract(&mut self, name: &str) -> ContractHandle {
+        let from = self.sender.1;
+        self.deploy_contract_from(from, name)
+    }
+
+    /// Deploys the artifact `name` from the build directory, see `Artifacts::load`.
+    pub fn deploy_contract_from(&mut self, from: Address, name: &str) -> ContractHandle {
+        let artifact = artifacts::artifacts()
+            .load(name)
+            .unwrap_or_else(|err| panic!("loading artifact {}: {:#}", name, err));
+        self.deploy_bytecode(from, artifact.bytecode, name)
+    }
+
+    fn deploy_bytecode(&mut self, from: Address, bytecode: Vec<u8>, label: &str) -> ContractHandle {
+        let params = RawBytes::serialize(CreateExternalParams(bytecode)).unwrap();
+
+        let res = self.call(
+            from,
+            EAM_ACTOR_ADDR,
+            CREATE_EXTERNAL_METHOD_NUM,
+            params,
+            TokenAmount::default(),
+        );
+
+        assert_eq!(
+            res.msg_receipt.exit_code.value(),
+            0,
+            "deploying {} on {} failed: {:?}",
+            label,
+            self.config.label(),
+            res.failure_info
+        );
+
+        let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+
+        ContractHandle {
+            actor_id: exec_return.actor_id,
+            robust_address: exec_return.robust_address,
+            eth_address: exec_return.eth_address.0,
+        }
+    }
+
+    pub fn invoke<C: SolCall>(&mut self, handle: &ContractHandle, call: &C) -> ApplyRet {
+        let from = self.sender.1;
+        self.invoke_with_value(from, handle, call, TokenAmount::default())
+    }
+
+    pub fn invoke_from<C: SolCall>(
+        &mut self,
+        from: Address,
+        handle: &ContractHandle,
+        call: &C,
+    ) -> ApplyRet {
+        self.invoke_with_value(from, handle, call, TokenAmount::default())
+    }
+
+    /// Calls `InvokeContract` on the contract with the ABI encoded call wrapped as CBOR bytes.
+    pub fn invoke_with_value<C: SolCall>(
+        &mut self,
+        from: Address,
+        handle: &ContractHandle,
+        call: &C,
+        value: TokenAmount,
+    ) -> ApplyRet {
+        let message = invoke_message(from, handle, call, value);
+        self.send(message)
+    }
+
+    /// Name and builtin type of the actor at `address` in the current state.
+    pub fn actor_info(&mut self, address: &Address) -> ActorInfo {
+        self.executor();
+        let state_tree = self.tester.executor.as_ref().unwrap().state_tree();
+        let actor_id = state_tree.lookup_id(address).unwrap();
+        let actor_type = actor_id
+            .and_then(|actor_id| state_tree.get_actor(actor_id).unwrap())
+            .map(|actor| self.manifest.id_by_code(&actor.code))
+            // 0 is not a builtin actor type
+            .filter(|actor_type| *actor_type != 0);
+
+        ActorInfo {
+            name: trace::actor_name(actor_id, actor_type),
+            actor_type,
+        }
+    }
+
+    /// Call tree of an applied message, with the actors named after the current state.
+    pub fn render_trace(&mut self, exec_trace: &ExecutionTrace) -> String {
+        let frames = trace::call_tree(exec_trace);
+        trace::render(&frames, |address| self.actor_info(address))
+    }
+
+    /// Invokes `call`, asserting it succeeds, and decodes its return value.
+    pub fn invoke_decoded<C: SolCall>(&mut self, handle: &ContractHandle, call: &C) -> C::Return {
+        let res = self.invoke(handle, call);
+        decode_return::<C>(&res)
+    }
+
+    fn assert_not_instantiated(&self) {
+        assert!(
+            self.tester.executor.is_none(),
+            "the state tree cannot be modified once the machine is instantiated"
+        );
+    }
+}
+
+impl Default for TestEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `InvokeContract` message of `call`, with the default gas limit and no sequence yet.
+pub fn invoke_message<C: SolCall>(
+    from: Address,
+    handle: &ContractHandle,
+    call: &C,
+    value: TokenAmount,
+) -> Message {
+    let cbor_encoded = api_contracts::cbor_encode(call.abi_encode());
+
+    Message {
+        from,
+        to: handle.address(),
+        gas_limit: DEFAULT_GAS_LIMIT,
+        method_num: EvmMethods::InvokeContract as u64,
+        params: RawBytes::new(hex::decode(cbor_encoded).unwrap()),
+        value,
+        ..Message::default()
+    }
+}
+
+/// Decodes the return value of a successful `invoke` of `C`, panicking with the exit code
+/// and failure info if the call failed.
+pub fn decode_return<C: SolCall>(res: &ApplyRet) -> C::Return {
+    if let Some(revert) = Revert::from_apply_ret(res) {
+        panic!("{} reverted: {}", C::SIGNATURE, revert);
+    }
+    assert_eq!(
+        res.msg_receipt.exit_code.value(),
+        0,
+        "{} failed: {:?}",
+        C::SIGNATURE,
+        res.failure_info
+    );
+
+    let data = api_contracts::cbor_decode(res.msg_receipt.return_data.bytes())
+        .unwrap_or_else(|err| panic!("return data of {}: {:#}", C::SIGNATURE, err));
+
+    C::abi_decode_returns(&data, true)
+        .unwrap_or_else(|err| panic!("decoding the return of {}: {}", C::SIGNATURE, err))
+}
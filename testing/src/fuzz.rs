@@ -0,0 +1,204 @@
+//! Property-based differential tests of the Solidity CBOR libraries: strategies for the
+//! Filecoin values they handle, and a runner sharing one deployed test contract between cases.
+
+use alloy_sol_types::SolCall;
+use cid::Cid;
+use fvm_shared::address::Address;
+use fvm_shared::bigint::BigInt;
+use fvm_shared::clock::ChainEpoch;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use multihash::{Code, MultihashDigest};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use std::cell::RefCell;
+
+use crate::api_contracts;
+use crate::env::{ContractHandle, TestEnv};
+use crate::revert::Revert;
+
+/// Number of cases per property, 64 by default. Every case is a contract call, so this is
+/// kept well below the proptest default.
+pub const FUZZ_CASES_ENV: &str = "FIL_FUZZ_CASES";
+
+const DEFAULT_CASES: u32 = 64;
+
+pub fn config() -> Config {
+    let cases = std::env::var(FUZZ_CASES_ENV)
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+
+    Config {
+        cases,
+        ..Config::default()
+    }
+}
+
+/// A test contract deployed once and called by every case of the properties run on it.
+pub struct ContractFuzzer {
+    env: RefCell<TestEnv>,
+    contract: ContractHandle,
+}
+
+impl ContractFuzzer {
+    pub fn new(contract_name: &str) -> Self {
+        let mut env = TestEnv::new();
+        let contract = env.deploy_contract(contract_name);
+
+        Self {
+            env: RefCell::new(env),
+            contract,
+        }
+    }
+
+    /// Checks `test` against values of `strategy`, panicking with the shrunk failing value.
+    pub fn run<S: Strategy>(
+        &self,
+        property: &str,
+        strategy: S,
+        test: impl Fn(&mut Caller, S::Value) -> Result<(), TestCaseError>,
+    ) {
+        match self.try_run(property, strategy, test) {
+            Ok(()) => {}
+            Err(TestError::Fail(reason, value)) => {
+                panic!(
+                    "{} failed: {}\nminimal failing input: {:#?}",
+                    property, reason, value
+                )
+            }
+            Err(TestError::Abort(reason)) => panic!("{} aborted: {}", property, reason),
+        }
+    }
+
+    /// Like `run`, but returns the shrunk failing value instead of panicking, for tests that
+    /// keep it or call the contract with it again.
+    pub fn try_run<S: Strategy>(
+        &self,
+        property: &str,
+        strategy: S,
+        test: impl Fn(&mut Caller, S::Value) -> Result<(), TestCaseError>,
+    ) -> Result<(), TestError<S::Value>> {
+        println!("Fuzzing `{}`", property);
+
+        let mut runner = TestRunner::new(config());
+        runner.run(&strategy, |value| {
+            let mut env = self.env.borrow_mut();
+            let mut caller = Caller {
+                env: &mut *env,
+                contract: &self.contract,
+            };
+            test(&mut caller, value)
+        })
+    }
+
+    /// Runs `f` on the shared environment and contract, outside of any property.
+    pub fn with_caller<T>(&self, f: impl FnOnce(&mut Caller) -> T) -> T {
+        let mut env = self.env.borrow_mut();
+        let mut caller = Caller {
+            env: &mut *env,
+            contract: &self.contract,
+        };
+        f(&mut caller)
+    }
+}
+
+/// The contract under test, as seen by one case.
+pub struct Caller<'a> {
+    pub env: &'a mut TestEnv,
+    pub contract: &'a ContractHandle,
+}
+
+impl Caller<'_> {
+    /// Invokes `call` and decodes its return value, failing the case if the call reverts.
+    pub fn call<C: SolCall>(&mut self, call: &C) -> Result<C::Return, TestCaseError> {
+        let res = self.env.invoke(self.contract, call);
+        if let Some(revert) = Revert::from_apply_ret(&res) {
+            return Err(TestCaseError::fail(format!(
+                "{} reverted: {}",
+                C::SIGNATURE,
+                revert
+            )));
+        }
+        if !res.msg_receipt.exit_code.is_success() {
+            return Err(TestCaseError::fail(format!(
+                "{} failed: exit {} {:?}",
+                C::SIGNATURE,
+                res.msg_receipt.exit_code.value(),
+                res.failure_info
+            )));
+        }
+
+        let data = api_contracts::cbor_decode(res.msg_receipt.return_data.bytes())
+            .map_err(|err| TestCaseError::fail(format!("{:#}", err)))?;
+        C::abi_decode_returns(&data, true).map_err(|err| TestCaseError::fail(err.to_string()))
+    }
+}
+
+/// Value of a Solidity `CommonTypes.BigInt`.
+pub fn sol_bigint(val: &[u8], neg: bool) -> BigInt {
+    api_contracts::convert::bigint_from_parts(val, neg)
+}
+
+/// CID bytes as read by `CBORDecoder.readBytes`, which keeps the multibase 0x00 prefix of
+/// the tag 42 byte string.
+pub fn cid_link_bytes(cid: &Cid) -> Vec<u8> {
+    let mut bytes = vec![0x00];
+    bytes.extend(cid.to_bytes());
+    bytes
+}
+
+pub fn bytes(max_len: usize) -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..=max_len)
+}
+
+pub fn id_address() -> impl Strategy<Value = Address> {
+    any::<u64>().prop_map(Address::new_id)
+}
+
+/// Addresses of every protocol. Delegated addresses of the EAM have 20 bytes subaddresses, as
+/// `FilAddresses.validate` requires.
+pub fn address() -> impl Strategy<Value = Address> {
+    prop_oneof![
+        id_address(),
+        vec(any::<u8>(), 65).prop_map(|key| Address::new_secp256k1(&key).unwrap()),
+        bytes(64).prop_map(|data| Address::new_actor(&data)),
+        vec(any::<u8>(), 48).prop_map(|key| Address::new_bls(&key).unwrap()),
+        vec(any::<u8>(), 20).prop_map(|eth| Address::new_delegated(10, &eth).unwrap()),
+        (
+            any::<u64>().prop_filter("EAM namespace", |ns| *ns != 10),
+            bytes(54)
+        )
+            .prop_map(|(ns, sub)| Address::new_delegated(ns, &sub).unwrap()),
+    ]
+}
+
+/// Signed integers of up to 256 bits.
+pub fn bigint() -> impl Strategy<Value = BigInt> {
+    (any::<bool>(), bytes(32)).prop_map(|(neg, magnitude)| sol_bigint(&magnitude, neg))
+}
+
+pub fn token_amount() -> impl Strategy<Value = TokenAmount> {
+    prop_oneof![
+        any::<u64>().prop_map(TokenAmount::from_atto),
+        bytes(32).prop_map(|magnitude| TokenAmount::from_atto(sol_bigint(&magnitude, false))),
+    ]
+}
+
+pub fn chain_epoch() -> impl Strategy<Value = ChainEpoch> {
+    any::<i64>()
+}
+
+/// CIDs of raw, DAG-CBOR and unsealed commitment blocks.
+pub fn cid() -> impl Strategy<Value = Cid> {
+    (
+        prop_oneof![Just(0x55_u64), Just(0x71), Just(0xf101)],
+        any::<[u8; 32]>(),
+    )
+        .prop_map(|(codec, seed)| Cid::new_v1(codec, Code::Blake2b256.digest(&seed)))
+}
+
+pub fn exit_code() -> impl Strategy<Value = ExitCode> {
+    any::<u32>().prop_map(ExitCode::new)
+}
@@ -0,0 +1,380 @@
+use anyhow::{bail, Context, Result};
+use fvm::gas::Gas;
+use fvm::trace::{ExecutionEvent, ExecutionTrace};
+use prettytable::Table;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::GasResult;
+
+/// Gas charges of one name at one call depth.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GasEntry {
+    pub count: u64,
+    pub compute_gas: Gas,
+    pub other_gas: Gas,
+}
+
+impl GasEntry {
+    pub fn total(&self) -> Gas {
+        self.compute_gas + self.other_gas
+    }
+}
+
+/// Gas charges of a message grouped by charge name (`wasm_exec`, `OnBlockOpen`, ...) and
+/// call depth: 0 for the message itself, 1 for the called actor (e.g. the EVM actor running
+/// the contract), 2 for the actors it calls, and so on.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GasReport {
+    pub entries: BTreeMap<(u32, String), GasEntry>,
+}
+
+impl GasReport {
+    pub fn from_trace(exec_trace: &ExecutionTrace) -> Self {
+        let mut report = GasReport::default();
+        let mut depth = 0_u32;
+
+        for event in exec_trace {
+            match event {
+                ExecutionEvent::GasCharge(charge) => {
+                    let entry = report
+                        .entries
+                        .entry((depth, charge.name.to_string()))
+                        .or_default();
+                    entry.count += 1;
+                    entry.compute_gas += charge.compute_gas;
+                    entry.other_gas += charge.other_gas;
+                }
+                ExecutionEvent::Call { .. } => depth += 1,
+                ExecutionEvent::CallReturn(..) | ExecutionEvent::CallError(..) => {
+                    depth = depth.saturating_sub(1)
+                }
+                _ => {}
+            }
+        }
+
+        report
+    }
+
+    /// Gas of the message and of the called actor, without the actors it calls. This is the
+    /// number `parse_gas` always reported.
+    pub fn total(&self) -> i64 {
+        self.total_up_to_depth(1).round_down() as i64
+    }
+
+    pub fn total_up_to_depth(&self, max_depth: u32) -> Gas {
+        self.entries
+            .iter()
+            .filter(|((depth, _), _)| *depth <= max_depth)
+            .fold(Gas::new(0), |total, (_, entry)| total + entry.total())
+    }
+
+    pub fn total_at_depth(&self, depth: u32) -> Gas {
+        self.entries
+            .iter()
+            .filter(|((d, _), _)| *d == depth)
+            .fold(Gas::new(0), |total, (_, entry)| total + entry.total())
+    }
+
+    /// Charges of `name` summed over every depth.
+    pub fn by_name(&self, name: &str) -> GasEntry {
+        self.entries
+            .iter()
+            .filter(|((_, n), _)| n == name)
+            .fold(GasEntry::default(), |sum, (_, entry)| GasEntry {
+                count: sum.count + entry.count,
+                compute_gas: sum.compute_gas + entry.compute_gas,
+                other_gas: sum.other_gas + entry.other_gas,
+            })
+    }
+
+    pub fn table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Depth", "Charge", "Count", "Compute", "Other", "Total"]);
+        for ((depth, name), entry) in &self.entries {
+            table.add_row(row![
+                depth,
+                name,
+                entry.count,
+                entry.compute_gas.round_down(),
+                entry.other_gas.round_down(),
+                entry.total().round_down()
+            ]);
+        }
+
+        table
+    }
+}
+
+/// Writes the breakdown of every function to `gas_breakdown_{actor_name}.csv`, next to the
+/// totals `save_gas_table` writes.
+pub fn save_gas_reports(reports: &[(String, GasReport)], actor_name: &str) {
+    let mut table = Table::new();
+    table.add_row(row!["Function", "Depth", "Charge", "Count", "Compute", "Other"]);
+    for (description, report) in reports {
+        for ((depth, name), entry) in &report.entries {
+            table.add_row(row![
+                description,
+                depth,
+                name,
+                entry.count,
+                entry.compute_gas.round_down(),
+                entry.other_gas.round_down()
+            ]);
+        }
+    }
+
+    let file_name = format!("gas_breakdown_{}.csv", actor_name);
+    let out = std::fs::File::create(&file_name)
+        .unwrap_or_else(|why| panic!("couldn't create {}: {}", file_name, why));
+
+    table.to_csv(out).expect("table cannot be written to csv");
+}
+
+/// Directory of the committed baselines, `gas_baselines` in the crate by default.
+pub const GAS_BASELINE_DIR_ENV: &str = "FIL_GAS_BASELINE_DIR";
+/// Increase allowed over the baseline, in percent, for functions without their own threshold.
+pub const GAS_THRESHOLD_ENV: &str = "FIL_GAS_THRESHOLD";
+/// When true (`1`, `true`, `yes` or `on`), the baselines are overwritten with the new results
+/// instead of checked.
+pub const UPDATE_GAS_BASELINE_ENV: &str = "FIL_UPDATE_GAS_BASELINE";
+
+const DEFAULT_BASELINE_DIR: &str = "gas_baselines";
+const DEFAULT_THRESHOLD_PERCENT: f64 = 5.0;
+
+/// Committed gas of every function of a suite, one `Function,Gas[,Threshold]` CSV per suite.
+/// The optional threshold, in percent, overrides the global one for that function.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GasBaseline {
+    pub entries: Vec<(String, i64, Option<f64>)>,
+}
+
+impl GasBaseline {
+    pub fn path(actor_name: &str) -> PathBuf {
+        let dir = match std::env::var(GAS_BASELINE_DIR_ENV) {
+            Ok(dir) => PathBuf::from(dir),
+            Err(_) => Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_BASELINE_DIR),
+        };
+
+        dir.join(format!("{}.csv", actor_name))
+    }
+
+    /// `None` if there is no baseline yet.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let mut entries = vec![];
+        // First line is the header
+        for (i, line) in content.lines().enumerate().skip(1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let parse_error = || format!("{}:{}: expected Function,Gas[,Threshold]", path.display(), i + 1);
+            let (function, gas, threshold) = match fields.as_slice() {
+                [function, gas] => (function, gas, None),
+                [function, gas, threshold] if threshold.is_empty() => (function, gas, None),
+                [function, gas, threshold] => (function, gas, Some(threshold.parse::<f64>().with_context(parse_error)?)),
+                _ => bail!(parse_error()),
+            };
+
+            entries.push((function.to_string(), gas.parse::<i64>().with_context(parse_error)?, threshold));
+        }
+
+        Ok(Some(GasBaseline { entries }))
+    }
+
+    /// Baseline with the new results, keeping the per-function thresholds of `self`.
+    pub fn updated(&self, gas_result: &GasResult) -> Self {
+        let entries = gas_result
+            .iter()
+            .map(|(function, gas)| (function.clone(), *gas, self.threshold(function)))
+            .collect();
+
+        GasBaseline { entries }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut content = "Function,Gas,Threshold\n".to_string();
+        for (function, gas, threshold) in &self.entries {
+            let threshold = threshold.map(|t| t.to_string()).unwrap_or_default();
+            content.push_str(&format!("{},{},{}\n", function, gas, threshold));
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, content).with_context(|| format!("writing {}", path.display()))
+    }
+
+    fn gas(&self, function: &str) -> Option<i64> {
+        self.entries.iter().find(|(f, _, _)| f == function).map(|(_, gas, _)| *gas)
+    }
+
+    fn threshold(&self, function: &str) -> Option<f64> {
+        self.entries.iter().find(|(f, _, _)| f == function).and_then(|(_, _, threshold)| *threshold)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GasDiffRow {
+    pub function: String,
+    /// `None` for a function new since the baseline.
+    pub baseline: Option<i64>,
+    /// `None` for a function no longer called.
+    pub current: Option<i64>,
+    /// Increase allowed, in percent.
+    pub threshold: f64,
+}
+
+impl GasDiffRow {
+    pub fn delta(&self) -> Option<i64> {
+        Some(self.current? - self.baseline?)
+    }
+
+    pub fn percent(&self) -> Option<f64> {
+        let baseline = self.baseline?;
+        if baseline == 0 {
+            return None;
+        }
+
+        Some(self.delta()? as f64 * 100.0 / baseline as f64)
+    }
+
+    pub fn exceeds_threshold(&self) -> bool {
+        self.percent().map_or(false, |percent| percent > self.threshold)
+    }
+}
+
+/// New gas results compared to a baseline, function by function.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GasDiff {
+    pub rows: Vec<GasDiffRow>,
+}
+
+impl GasDiff {
+    pub fn compare(baseline: &GasBaseline, gas_result: &GasResult, global_threshold: f64) -> Self {
+        let mut rows: Vec<GasDiffRow> = gas_result
+            .iter()
+            .map(|(function, gas)| GasDiffRow {
+                function: function.clone(),
+                baseline: baseline.gas(function),
+                current: Some(*gas),
+                threshold: baseline.threshold(function).unwrap_or(global_threshold),
+            })
+            .collect();
+
+        for (function, gas, threshold) in &baseline.entries {
+            if !gas_result.iter().any(|(f, _)| f == function) {
+                rows.push(GasDiffRow {
+                    function: function.clone(),
+                    baseline: Some(*gas),
+                    current: None,
+                    threshold: threshold.unwrap_or(global_threshold),
+                });
+            }
+        }
+
+        GasDiff { rows }
+    }
+
+    pub fn regressions(&self) -> Vec<&GasDiffRow> {
+        self.rows.iter().filter(|row| row.exceeds_threshold()).collect()
+    }
+
+    pub fn table(&self) -> Table {
+        let format_gas = |gas: Option<i64>| gas.map_or("-".to_string(), |gas| gas.to_string());
+
+        let mut table = Table::new();
+        table.add_row(row!["Function", "Baseline", "Current", "Delta", "%", "Threshold %", ""]);
+        for row in &self.rows {
+            let delta = match row.delta() {
+                Some(delta) => format!("{:+}", delta),
+                None if row.baseline.is_none() => "new".to_string(),
+                None => "removed".to_string(),
+            };
+            let percent = row.percent().map_or("-".to_string(), |percent| format!("{:+.2}", percent));
+            let status = if row.exceeds_threshold() { "REGRESSION" } else { "" };
+
+            table.add_row(row![
+                row.function,
+                format_gas(row.baseline),
+                format_gas(row.current),
+                delta,
+                percent,
+                row.threshold,
+                status
+            ]);
+        }
+
+        table
+    }
+}
+
+/// Boolean value of an environment variable, `None` if it is neither true nor false.
+pub fn parse_flag(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "" | "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn update_requested() -> bool {
+    match std::env::var(UPDATE_GAS_BASELINE_ENV) {
+        Ok(value) => parse_flag(&value)
+            .unwrap_or_else(|| panic!("{} must be a boolean, got {}", UPDATE_GAS_BASELINE_ENV, value)),
+        Err(_) => false,
+    }
+}
+
+fn global_threshold() -> f64 {
+    match std::env::var(GAS_THRESHOLD_ENV) {
+        Ok(threshold) => threshold
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be a percentage, got {}", GAS_THRESHOLD_ENV, threshold)),
+        Err(_) => DEFAULT_THRESHOLD_PERCENT,
+    }
+}
+
+/// Compares the results of a suite with its committed baseline and panics if a function
+/// costs more than its threshold allows, or if the suite has no baseline. With
+/// `FIL_UPDATE_GAS_BASELINE` true, the baseline is rewritten instead.
+pub fn check_gas_baseline(gas_result: &GasResult, actor_name: &str) {
+    let path = GasBaseline::path(actor_name);
+    let baseline = GasBaseline::load(&path).unwrap();
+
+    if update_requested() {
+        baseline.unwrap_or_default().updated(gas_result).save(&path).unwrap();
+        println!("Gas baseline {} updated", path.display());
+        return;
+    }
+
+    let Some(baseline) = baseline else {
+        panic!(
+            "no gas baseline at {}, run `make update_gas_baselines` or set {}=1 to create it",
+            path.display(),
+            UPDATE_GAS_BASELINE_ENV
+        );
+    };
+
+    let diff = GasDiff::compare(&baseline, gas_result, global_threshold());
+    diff.table().printstd();
+
+    let regressions = diff.regressions();
+    assert!(
+        regressions.is_empty(),
+        "gas regressions in {} over the baseline {}: {}",
+        actor_name,
+        path.display(),
+        regressions
+            .iter()
+            .map(|row| format!("{} {:+.2}%", row.function, row.percent().unwrap()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+}
@@ -0,0 +1,106 @@
+use alloy_sol_types::SolCall;
+use anyhow::{bail, Result};
+use fvm::executor::ApplyRet;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::message::Message;
+use prettytable::Table;
+use std::fmt::Display;
+
+use crate::env::{invoke_message, ContractHandle, Snapshot, TestEnv, DEFAULT_GAS_LIMIT};
+use crate::revert::Revert;
+
+/// Gas a message used with the default gas limit, and the lowest gas limit it succeeds with.
+///
+/// The two differ when the contract forwards gas to other actors: the EVM only forwards
+/// 63/64 of the gas left, so the limit must cover more than what ends up being used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasLimitEstimate {
+    pub gas_used: u64,
+    pub gas_limit: u64,
+}
+
+/// Binary-searches the minimum gas limit the message built by `prepare` succeeds with.
+///
+/// Every attempt runs on a new fork of `snapshot`. `prepare` may send messages of its own
+/// to set the state up, only the message it returns gets its gas limit replaced.
+pub fn min_gas_limit(
+    snapshot: &Snapshot,
+    mut prepare: impl FnMut(&mut TestEnv) -> Message,
+) -> Result<GasLimitEstimate> {
+    let mut attempt = |gas_limit: u64| -> ApplyRet {
+        let mut env = TestEnv::fork(snapshot);
+        let message = prepare(&mut env);
+        env.send(Message { gas_limit, ..message })
+    };
+
+    let res = attempt(DEFAULT_GAS_LIMIT);
+    if !res.msg_receipt.exit_code.is_success() {
+        match Revert::from_apply_ret(&res) {
+            Some(revert) => bail!("the message reverts with the default gas limit: {}", revert),
+            None => bail!(
+                "the message fails with the default gas limit: exit {} {:?}",
+                res.msg_receipt.exit_code.value(),
+                res.failure_info
+            ),
+        }
+    }
+    let gas_used = res.msg_receipt.gas_used;
+
+    // Nothing succeeds below the gas it used, `high` always succeeds
+    let mut low = gas_used.saturating_sub(1);
+    let mut high = DEFAULT_GAS_LIMIT;
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if attempt(mid).msg_receipt.exit_code.is_success() {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Ok(GasLimitEstimate {
+        gas_used,
+        gas_limit: high,
+    })
+}
+
+/// `min_gas_limit` of `call` sent by the default sender to `contract` with `value`, named
+/// after the signature of the call for `gas_limit_table`.
+pub fn call_gas_limit<C: SolCall>(
+    snapshot: &Snapshot,
+    contract: &ContractHandle,
+    call: &C,
+    value: TokenAmount,
+) -> Result<(String, GasLimitEstimate)> {
+    let estimate = min_gas_limit(snapshot, |env| {
+        invoke_message(env.sender.1, contract, call, value.clone())
+    })?;
+
+    Ok((C::SIGNATURE.to_string(), estimate))
+}
+
+/// `min_gas_limit` for every parameter of a range, e.g. number of deals or label length, to
+/// spot calls whose gas grows with the size of their input.
+pub fn min_gas_limits<P: Display>(
+    snapshot: &Snapshot,
+    params: impl IntoIterator<Item = P>,
+    mut prepare: impl FnMut(&mut TestEnv, &P) -> Message,
+) -> Result<Vec<(String, GasLimitEstimate)>> {
+    params
+        .into_iter()
+        .map(|param| {
+            let estimate = min_gas_limit(snapshot, |env| prepare(env, &param))?;
+            Ok((param.to_string(), estimate))
+        })
+        .collect()
+}
+
+pub fn gas_limit_table(parameter: &str, estimates: &[(String, GasLimitEstimate)]) -> Table {
+    let mut table = Table::new();
+    table.add_row(row![parameter, "Gas used", "Gas limit required"]);
+    for (param, estimate) in estimates {
+        table.add_row(row![param, estimate.gas_used, estimate.gas_limit]);
+    }
+
+    table
+}
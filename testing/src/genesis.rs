@@ -0,0 +1,202 @@
+use anyhow::{Context, Result};
+use bls_signatures::Serialize as _;
+use cid::Cid;
+use fil_actors_runtime::runtime::builtins;
+use fil_actors_runtime::{
+    BURNT_FUNDS_ACTOR_ADDR, BURNT_FUNDS_ACTOR_ID, CRON_ACTOR_ID, DATACAP_TOKEN_ACTOR_ID,
+    EAM_ACTOR_ID, INIT_ACTOR_ID, REWARD_ACTOR_ID, STORAGE_MARKET_ACTOR_ADDR,
+    STORAGE_MARKET_ACTOR_ID, STORAGE_POWER_ACTOR_ADDR, STORAGE_POWER_ACTOR_ID, SYSTEM_ACTOR_ID,
+    VERIFIED_REGISTRY_ACTOR_ADDR, VERIFIED_REGISTRY_ACTOR_ID,
+};
+use fvm::machine::Manifest;
+use fvm::state_tree::{ActorState, StateTree};
+use fvm_ipld_blockstore::Blockstore;
+use fvm_ipld_encoding::CborStore;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::sector::StoragePower;
+use fvm_shared::ActorID;
+use multihash::Code;
+use serde::Serialize;
+
+/// Account acting as the default verified registry root key, like f080 on mainnet.
+pub const VERIFREG_ROOT_KEY_ACTOR_ID: ActorID = 80;
+/// Seed of the BLS key of the default root key account, fixed so tests can sign as it.
+pub const VERIFREG_ROOT_KEY_SEED: &str = "8080808080808080808080808080808080808080808080808080808080808080";
+
+pub const NETWORK_NAME: &str = "test";
+
+#[derive(Clone, Debug)]
+pub struct GenesisConfig {
+    /// ID address allowed to add and remove verifiers.
+    pub verifreg_root_key: Address,
+    /// Only address allowed to mint and destroy datacap tokens.
+    pub datacap_governor: Address,
+}
+
+/// BLS private key of the default root key account.
+pub fn verifreg_root_key_private_key() -> bls_signatures::PrivateKey {
+    bls_signatures::PrivateKey::new(hex::decode(VERIFREG_ROOT_KEY_SEED).unwrap())
+}
+
+/// Key address of the default root key account, resolving to `VERIFREG_ROOT_KEY_ACTOR_ID`.
+pub fn verifreg_root_key_address() -> Address {
+    let public_key = verifreg_root_key_private_key().public_key();
+    Address::new_bls(&public_key.as_bytes()).unwrap()
+}
+
+impl Default for GenesisConfig {
+    fn default() -> Self {
+        GenesisConfig {
+            verifreg_root_key: Address::new_id(VERIFREG_ROOT_KEY_ACTOR_ID),
+            datacap_governor: VERIFIED_REGISTRY_ACTOR_ADDR,
+        }
+    }
+}
+
+/// Installs every builtin singleton actor with the same state its constructor would
+/// leave, so tests start from a network where all of them can be called right away.
+///
+/// Must run on a fresh state tree, before any account is created, as the init actor
+/// address map is reset.
+pub fn install_genesis_actors(
+    state_tree: &mut StateTree<impl Blockstore>,
+    manifest: &Manifest,
+    config: &GenesisConfig,
+) -> Result<()> {
+    // The system actor state holds the builtin actors registry, which Tester::new already
+    // wrote from the bundle, so only the code is (re)installed here.
+    let system_state = state_tree
+        .get_actor(SYSTEM_ACTOR_ID)?
+        .context("system actor missing from the state tree")?
+        .state;
+    set_singleton_actor(state_tree, manifest, builtins::Type::System, SYSTEM_ACTOR_ID, system_state)?;
+
+    // The default root key account is only created when it is the configured root key,
+    // otherwise the test installs its own account at the configured ID.
+    let root_key_account = config.verifreg_root_key == Address::new_id(VERIFREG_ROOT_KEY_ACTOR_ID);
+
+    let mut init_state = fil_actor_init::State::new(state_tree.store(), NETWORK_NAME.to_string())
+        .context("init actor state")?;
+    if root_key_account {
+        // Below the first non-singleton ID, so it is mapped by hand rather than allocated
+        let next_id = init_state.next_id;
+        init_state.next_id = VERIFREG_ROOT_KEY_ACTOR_ID;
+        init_state
+            .map_addresses_to_id(state_tree.store(), &verifreg_root_key_address(), None)
+            .context("root key address")?;
+        init_state.next_id = next_id;
+    }
+    put_singleton_actor(state_tree, manifest, builtins::Type::Init, INIT_ACTOR_ID, &init_state)?;
+
+    let reward_state = fil_actor_reward::State::new(StoragePower::default());
+    put_singleton_actor(state_tree, manifest, builtins::Type::Reward, REWARD_ACTOR_ID, &reward_state)?;
+
+    let cron_state = fil_actor_cron::State {
+        entries: vec![
+            fil_actor_cron::Entry {
+                receiver: STORAGE_POWER_ACTOR_ADDR,
+                method_num: fil_actor_power::Method::OnEpochTickEnd as u64,
+            },
+            fil_actor_cron::Entry {
+                receiver: STORAGE_MARKET_ACTOR_ADDR,
+                method_num: fil_actor_market::Method::CronTick as u64,
+            },
+        ],
+    };
+    put_singleton_actor(state_tree, manifest, builtins::Type::Cron, CRON_ACTOR_ID, &cron_state)?;
+
+    let power_state = fil_actor_power::State::new(state_tree.store()).context("power actor state")?;
+    put_singleton_actor(state_tree, manifest, builtins::Type::Power, STORAGE_POWER_ACTOR_ID, &power_state)?;
+
+    let market_state = fil_actor_market::State::new(state_tree.store()).context("market actor state")?;
+    put_singleton_actor(state_tree, manifest, builtins::Type::Market, STORAGE_MARKET_ACTOR_ID, &market_state)?;
+
+    let verifreg_state = fil_actor_verifreg::State::new(state_tree.store(), config.verifreg_root_key)
+        .context("verifreg actor state")?;
+    put_singleton_actor(
+        state_tree,
+        manifest,
+        builtins::Type::VerifiedRegistry,
+        VERIFIED_REGISTRY_ACTOR_ID,
+        &verifreg_state,
+    )?;
+
+    let datacap_state = fil_actor_datacap::State::new(state_tree.store(), config.datacap_governor)
+        .context("datacap actor state")?;
+    put_singleton_actor(
+        state_tree,
+        manifest,
+        builtins::Type::DataCap,
+        DATACAP_TOKEN_ACTOR_ID,
+        &datacap_state,
+    )?;
+
+    put_singleton_actor(state_tree, manifest, builtins::Type::EAM, EAM_ACTOR_ID, &[(); 0])?;
+
+    let burnt_funds_state = fvm::account_actor::State {
+        address: BURNT_FUNDS_ACTOR_ADDR,
+    };
+    put_singleton_actor(
+        state_tree,
+        manifest,
+        builtins::Type::Account,
+        BURNT_FUNDS_ACTOR_ID,
+        &burnt_funds_state,
+    )?;
+
+    if root_key_account {
+        let root_key_state = fvm::account_actor::State {
+            address: verifreg_root_key_address(),
+        };
+        put_singleton_actor(
+            state_tree,
+            manifest,
+            builtins::Type::Account,
+            VERIFREG_ROOT_KEY_ACTOR_ID,
+            &root_key_state,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn put_singleton_actor<S: Serialize>(
+    state_tree: &mut StateTree<impl Blockstore>,
+    manifest: &Manifest,
+    actor_type: builtins::Type,
+    actor_id: ActorID,
+    state: &S,
+) -> Result<()> {
+    let state_cid = state_tree
+        .store()
+        .put_cbor(state, Code::Blake2b256)
+        .with_context(|| format!("{:?} actor state", actor_type))?;
+
+    set_singleton_actor(state_tree, manifest, actor_type, actor_id, state_cid)
+}
+
+fn set_singleton_actor(
+    state_tree: &mut StateTree<impl Blockstore>,
+    manifest: &Manifest,
+    actor_type: builtins::Type,
+    actor_id: ActorID,
+    state: Cid,
+) -> Result<()> {
+    let code = *manifest
+        .code_by_id(actor_type as u32)
+        .with_context(|| format!("{:?} actor missing from the manifest", actor_type))?;
+
+    state_tree.set_actor(
+        actor_id,
+        ActorState {
+            code,
+            state,
+            sequence: 0,
+            balance: TokenAmount::default(),
+            delegated_address: None,
+        },
+    );
+
+    Ok(())
+}
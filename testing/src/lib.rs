@@ -1,15 +1,27 @@
 use fvm::trace::ExecutionTrace;
-use fvm_shared::bigint::Zero;
 use prettytable::Table;
-use fvm::trace::ExecutionEvent;
-use fvm::gas::Gas;
 
 #[macro_use]
 extern crate prettytable;
 
 pub mod api_contracts;
-pub mod helpers;
+pub mod artifacts;
+pub mod bindings;
+pub mod blockstore;
+pub mod consts;
+pub mod env;
+pub mod fuzz;
+pub mod gas;
+pub mod gas_limit;
+pub mod genesis;
+pub mod method_consts;
+pub mod methods;
+pub mod report;
+pub mod revert;
+pub mod schema;
 pub mod setup;
+pub mod trace;
+pub mod vectors;
 
 
 pub type GasResult = Vec<(String, i64)>;
@@ -38,21 +50,5 @@ pub fn save_gas_table(table: &Table, actor_name: &str) {
 }
 
 pub fn parse_gas(exec_trace: ExecutionTrace) -> i64 {
-    let mut depth = -1; // start at -1 because we have the on chain message gas and then the call to our solidity contract
-    let mut gas_usage = Gas::new(0);
-
-    for exec in exec_trace {
-        let gas = match &exec {
-            ExecutionEvent::GasCharge(gc) => gc.total(),
-            ExecutionEvent::Call{..} => { depth += 1; Gas::zero() },
-            ExecutionEvent::CallReturn(..) => { depth -= 1; Gas::zero() },
-            _ => Gas::zero(),
-        };
-
-        if depth <= 0 {
-            gas_usage += gas;
-        }
-    }
-
-    return gas_usage.round_down() as i64;
-}
\ No newline at end of file
+    gas::GasReport::from_trace(&exec_trace).total()
+}
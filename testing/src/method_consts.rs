@@ -0,0 +1,151 @@
+use anyhow::{bail, Context, Result};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::methods::{self, ActorMethods, REGISTRY};
+
+const CONSTS_PATH: &str = "src/consts.rs";
+
+/// Upper snake case constant name of a method, e.g. `GET_PEER_ID` for `GetPeerID`.
+pub fn rust_const_name(method: &str) -> String {
+    let chars: Vec<char> = method.chars().collect();
+    let mut name = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        let word_start = i > 0
+            && c.is_uppercase()
+            && (chars[i - 1].is_lowercase()
+                || chars.get(i + 1).map_or(false, |next| next.is_lowercase()));
+        if word_start {
+            name.push('_');
+        }
+        name.push(c.to_ascii_uppercase());
+    }
+
+    name
+}
+
+/// The `consts` module, one submodule per actor of `registry`.
+pub fn render_rust_consts(registry: &[ActorMethods]) -> String {
+    let mut out = String::new();
+    writeln!(out, "// Generated from testing::methods::REGISTRY by").unwrap();
+    writeln!(out, "// `cargo run --example method_consts`, do not edit.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "//! FRC-42 method numbers of the builtin actors.").unwrap();
+
+    for actor in registry {
+        writeln!(out).unwrap();
+        writeln!(out, "pub mod {} {{", actor.actor).unwrap();
+        writeln!(out, "    use fvm_shared::MethodNum;").unwrap();
+        writeln!(out).unwrap();
+        for (name, num) in actor.methods.iter().chain(actor.rust_only) {
+            writeln!(out, "    pub const {}: MethodNum = {};", rust_const_name(name), num).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    out
+}
+
+fn is_method_num_line(line: &str) -> bool {
+    let code = line.split("//").next().unwrap();
+    code.contains(" constant ") && code.contains("MethodNum") && code.contains('=')
+}
+
+/// `source` with its block of `*MethodNum` constants replaced by the constants of the actors
+/// declared in that file. The block keeps its indentation and integer type.
+pub fn render_types_file(source: &str, file: &str, actors: &[&ActorMethods]) -> Result<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let Some(start) = lines.iter().position(|line| is_method_num_line(line)) else {
+        bail!("{} has no *MethodNum constants to replace", file);
+    };
+    let end = start + lines[start..].iter().take_while(|line| is_method_num_line(line)).count();
+    if lines[end..].iter().any(|line| is_method_num_line(line)) {
+        bail!("the *MethodNum constants of {} are not declared in one block", file);
+    }
+
+    let first = lines[start];
+    let indent = &first[..first.len() - first.trim_start().len()];
+    let int_type = first.split_whitespace().next().unwrap();
+
+    let mut out: Vec<String> = lines[..start].iter().map(|line| line.to_string()).collect();
+    for actor in actors {
+        for (name, num) in actor.methods.iter() {
+            out.push(format!(
+                "{}{} constant {} = {};",
+                indent,
+                int_type,
+                actor.constant_name(name),
+                num
+            ));
+        }
+    }
+    out.extend(lines[end..].iter().map(|line| line.to_string()));
+
+    let mut rendered = out.join("\n");
+    if source.ends_with('\n') {
+        rendered.push('\n');
+    }
+
+    Ok(rendered)
+}
+
+/// Every generated file, as (path, content).
+pub fn generated_files(types_dir: &Path, consts_path: &Path) -> Result<Vec<(PathBuf, String)>> {
+    let mut files = vec![];
+
+    let mut types_files: Vec<&str> = REGISTRY.iter().filter_map(|actor| actor.types_file).collect();
+    types_files.sort();
+    types_files.dedup();
+    for file in types_files {
+        let actors: Vec<&ActorMethods> = REGISTRY
+            .iter()
+            .filter(|actor| actor.types_file == Some(file))
+            .collect();
+
+        let path = types_dir.join(file);
+        let source =
+            std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        files.push((path, render_types_file(&source, file, &actors)?));
+    }
+
+    files.push((consts_path.to_path_buf(), render_rust_consts(REGISTRY)));
+
+    Ok(files)
+}
+
+fn default_consts_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(CONSTS_PATH)
+}
+
+/// Writes the method constants of every `*Types.sol` and the Rust `consts` module, returning
+/// the files that changed.
+pub fn generate() -> Result<Vec<PathBuf>> {
+    let mut changed = vec![];
+    for (path, content) in generated_files(&methods::types_dir(), &default_consts_path())? {
+        if std::fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+            std::fs::write(&path, content).with_context(|| format!("writing {}", path.display()))?;
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Fails if any checked-in file differs from what `generate` would write.
+pub fn check() -> Result<()> {
+    let mut stale = vec![];
+    for (path, content) in generated_files(&methods::types_dir(), &default_consts_path())? {
+        if std::fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+            stale.push(path.display().to_string());
+        }
+    }
+
+    if !stale.is_empty() {
+        bail!(
+            "method constants out of date in {}, run `cargo run --example method_consts`",
+            stale.join(", ")
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,426 @@
+use anyhow::{bail, Context, Result};
+use fil_actor_account::Method as AccountMethod;
+use fil_actor_datacap::Method as DataCapMethod;
+use fil_actor_market::Method as MarketMethod;
+use fil_actor_miner::Method as MinerMethod;
+use fil_actor_power::Method as PowerMethod;
+use fil_actor_verifreg::Method as VerifRegMethod;
+use fil_actors_runtime::runtime::builtins;
+use fvm_shared::MethodNum;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+const TYPES_DIR: &str = "../contracts/v0.8/types";
+
+/// Method names and numbers of an actor.
+pub type Methods = &'static [(&'static str, MethodNum)];
+
+// Names with their FRC-42 hash, computed at compile time. The build fails if a hash is not
+// the number of the actor method it is declared for.
+macro_rules! frc42_methods {
+    ($method:ident: $($variant:ident => $name:literal),* $(,)?) => {
+        &[$({
+            const NUM: MethodNum = frc42_dispatch::method_hash!($name);
+            assert!(
+                NUM == $method::$variant as MethodNum,
+                concat!("FRC-42 hash of ", $name, " is not ", stringify!($method::$variant))
+            );
+            ($name, NUM)
+        }),*]
+    };
+}
+
+pub const MARKET_METHODS: Methods = frc42_methods![
+    MarketMethod:
+    AddBalanceExported => "AddBalance",
+    WithdrawBalanceExported => "WithdrawBalance",
+    GetBalanceExported => "GetBalance",
+    GetDealDataCommitmentExported => "GetDealDataCommitment",
+    GetDealClientExported => "GetDealClient",
+    GetDealProviderExported => "GetDealProvider",
+    GetDealLabelExported => "GetDealLabel",
+    GetDealTermExported => "GetDealTerm",
+    GetDealTotalPriceExported => "GetDealTotalPrice",
+    GetDealClientCollateralExported => "GetDealClientCollateral",
+    GetDealProviderCollateralExported => "GetDealProviderCollateral",
+    GetDealVerifiedExported => "GetDealVerified",
+    GetDealActivationExported => "GetDealActivation",
+    PublishStorageDealsExported => "PublishStorageDeals",
+];
+
+pub const MINER_METHODS: Methods = frc42_methods![
+    MinerMethod:
+    GetOwnerExported => "GetOwner",
+    ChangeOwnerAddressExported => "ChangeOwnerAddress",
+    IsControllingAddressExported => "IsControllingAddress",
+    GetSectorSizeExported => "GetSectorSize",
+    GetAvailableBalanceExported => "GetAvailableBalance",
+    GetVestingFundsExported => "GetVestingFunds",
+    ChangeBeneficiaryExported => "ChangeBeneficiary",
+    GetBeneficiaryExported => "GetBeneficiary",
+    ChangeWorkerAddressExported => "ChangeWorkerAddress",
+    ChangePeerIDExported => "ChangePeerID",
+    ChangeMultiaddrsExported => "ChangeMultiaddrs",
+    RepayDebtExported => "RepayDebt",
+    ConfirmChangeWorkerAddressExported => "ConfirmChangeWorkerAddress",
+    GetPeerIDExported => "GetPeerID",
+    GetMultiaddrsExported => "GetMultiaddrs",
+    WithdrawBalanceExported => "WithdrawBalance",
+];
+
+pub const POWER_METHODS: Methods = frc42_methods![
+    PowerMethod:
+    CreateMinerExported => "CreateMiner",
+    MinerCountExported => "MinerCount",
+    MinerConsensusCountExported => "MinerConsensusCount",
+    NetworkRawPowerExported => "NetworkRawPower",
+    MinerRawPowerExported => "MinerRawPower",
+];
+
+pub const VERIFREG_METHODS: Methods = frc42_methods![
+    VerifRegMethod:
+    GetClaimsExported => "GetClaims",
+    AddVerifiedClientExported => "AddVerifiedClient",
+    RemoveExpiredAllocationsExported => "RemoveExpiredAllocations",
+    ExtendClaimTermsExported => "ExtendClaimTerms",
+    RemoveExpiredClaimsExported => "RemoveExpiredClaims",
+];
+
+pub const DATACAP_METHODS: Methods = frc42_methods![
+    DataCapMethod:
+    NameExported => "Name",
+    SymbolExported => "Symbol",
+    TotalSupplyExported => "TotalSupply",
+    BalanceExported => "Balance",
+    TransferExported => "Transfer",
+    TransferFromExported => "TransferFrom",
+    IncreaseAllowanceExported => "IncreaseAllowance",
+    DecreaseAllowanceExported => "DecreaseAllowance",
+    RevokeAllowanceExported => "RevokeAllowance",
+    BurnExported => "Burn",
+    BurnFromExported => "BurnFrom",
+    AllowanceExported => "Allowance",
+];
+
+/// Exported by the datacap actor but not called by the Solidity library: `Mint` and `Destroy`
+/// are only callable by the governor, the verified registry, and the granularity is fixed.
+pub const DATACAP_RUST_ONLY_METHODS: Methods = frc42_methods![
+    DataCapMethod:
+    GranularityExported => "Granularity",
+    MintExported => "Mint",
+    DestroyExported => "Destroy",
+];
+
+pub const ACCOUNT_METHODS: Methods =
+    frc42_methods![AccountMethod: AuthenticateMessageExported => "AuthenticateMessage"];
+
+/// Exported by every actor that can receive funds.
+pub const COMMON_METHODS: Methods =
+    frc42_methods![VerifRegMethod: UniversalReceiverHook => "Receive"];
+
+pub const EVM_METHODS: Methods = &[
+    ("GetBytecode", fil_actor_evm::Method::GetBytecode as u64),
+    ("InvokeContract", fil_actor_evm::Method::InvokeContract as u64),
+];
+
+pub const EAM_METHODS: Methods = &[("Create", 2), ("Create2", 3), ("CreateExternal", 4)];
+
+pub const INIT_METHODS: Methods = &[("Exec", fil_actor_init::Method::Exec as u64)];
+
+pub const CRON_METHODS: Methods = &[("EpochTick", 2)];
+
+// Method numbers of the builtin actors internal API, not exported through FRC-42
+const MARKET_INTERNAL_METHODS: Methods = &[
+    ("AddBalance", fil_actor_market::Method::AddBalance as u64),
+    ("WithdrawBalance", fil_actor_market::Method::WithdrawBalance as u64),
+    ("PublishStorageDeals", fil_actor_market::Method::PublishStorageDeals as u64),
+    ("CronTick", fil_actor_market::Method::CronTick as u64),
+];
+
+const MINER_INTERNAL_METHODS: Methods = &[(
+    "ChangeWorkerAddress",
+    fil_actor_miner::Method::ChangeWorkerAddress as u64,
+)];
+
+const POWER_INTERNAL_METHODS: Methods = &[
+    ("CreateMiner", fil_actor_power::Method::CreateMiner as u64),
+    ("OnEpochTickEnd", fil_actor_power::Method::OnEpochTickEnd as u64),
+];
+
+// Shared by every actor
+const BUILTIN_METHODS: Methods = &[("Send", 0), ("Constructor", 1)];
+
+/// Methods of the builtin actor type, a `builtins::Type` value.
+pub fn actor_methods(actor_type: u32) -> Vec<Methods> {
+    let tables: &[(builtins::Type, &[Methods])] = &[
+        (builtins::Type::Market, &[MARKET_METHODS, MARKET_INTERNAL_METHODS]),
+        (builtins::Type::Miner, &[MINER_METHODS, MINER_INTERNAL_METHODS]),
+        (builtins::Type::Power, &[POWER_METHODS, POWER_INTERNAL_METHODS]),
+        (builtins::Type::VerifiedRegistry, &[VERIFREG_METHODS]),
+        (builtins::Type::DataCap, &[DATACAP_METHODS, DATACAP_RUST_ONLY_METHODS]),
+        (builtins::Type::Account, &[ACCOUNT_METHODS]),
+        (builtins::Type::EVM, &[EVM_METHODS]),
+        (builtins::Type::EAM, &[EAM_METHODS]),
+        (builtins::Type::Init, &[INIT_METHODS]),
+        (builtins::Type::Cron, &[CRON_METHODS]),
+    ];
+
+    tables
+        .iter()
+        .find(|(t, _)| *t as u32 == actor_type)
+        .map(|(_, methods)| methods.to_vec())
+        .unwrap_or_default()
+}
+
+/// Reverse lookup of a method number, for an actor of unknown type when `actor_type` is
+/// `None`, in which case only the methods every actor shares are known.
+pub fn method_name(actor_type: Option<u32>, method_num: MethodNum) -> Option<&'static str> {
+    let mut tables = actor_type.map(actor_methods).unwrap_or_default();
+    tables.push(COMMON_METHODS);
+    tables.push(BUILTIN_METHODS);
+
+    tables
+        .iter()
+        .flat_map(|methods| methods.iter())
+        .find(|(_, num)| *num == method_num)
+        .map(|(name, _)| *name)
+}
+
+/// Exported methods of one actor, and the Solidity types file declaring their
+/// `<Method>MethodNum` constants.
+pub struct ActorMethods {
+    pub actor: &'static str,
+    /// File under `contracts/v0.8/types`, `None` if the library declares no constants.
+    pub types_file: Option<&'static str>,
+    pub methods: Methods,
+    /// Methods whose constant is not named after them, as (method, constant prefix).
+    pub aliases: &'static [(&'static str, &'static str)],
+    /// Exported methods the Solidity library does not call, only part of the Rust constants.
+    pub rust_only: Methods,
+}
+
+impl ActorMethods {
+    pub fn constant_name(&self, method: &str) -> String {
+        let prefix = self
+            .aliases
+            .iter()
+            .find(|(name, _)| *name == method)
+            .map_or(method, |(_, alias)| *alias);
+
+        format!("{}MethodNum", prefix)
+    }
+
+    /// Constants the types file is expected to declare.
+    pub fn constants(&self) -> Vec<(String, MethodNum)> {
+        self.methods
+            .iter()
+            .map(|(name, num)| (self.constant_name(name), *num))
+            .collect()
+    }
+}
+
+/// Every method the Solidity library calls, per actor.
+pub const REGISTRY: &[ActorMethods] = &[
+    ActorMethods {
+        actor: "account",
+        types_file: Some("AccountTypes.sol"),
+        methods: ACCOUNT_METHODS,
+        aliases: &[],
+        rust_only: &[],
+    },
+    ActorMethods {
+        actor: "common",
+        types_file: Some("CommonTypes.sol"),
+        methods: COMMON_METHODS,
+        aliases: &[("Receive", "UniversalReceiverHook")],
+        rust_only: &[],
+    },
+    ActorMethods {
+        actor: "market",
+        types_file: Some("MarketTypes.sol"),
+        methods: MARKET_METHODS,
+        aliases: &[],
+        rust_only: &[],
+    },
+    ActorMethods {
+        actor: "miner",
+        types_file: Some("MinerTypes.sol"),
+        methods: MINER_METHODS,
+        aliases: &[],
+        rust_only: &[],
+    },
+    ActorMethods {
+        actor: "power",
+        types_file: Some("PowerTypes.sol"),
+        methods: POWER_METHODS,
+        aliases: &[],
+        rust_only: &[],
+    },
+    ActorMethods {
+        actor: "verifreg",
+        types_file: Some("VerifRegTypes.sol"),
+        methods: VERIFREG_METHODS,
+        aliases: &[],
+        rust_only: &[],
+    },
+    ActorMethods {
+        actor: "datacap",
+        types_file: Some("DataCapTypes.sol"),
+        methods: DATACAP_METHODS,
+        aliases: &[("Balance", "BalanceOf")],
+        rust_only: DATACAP_RUST_ONLY_METHODS,
+    },
+    ActorMethods {
+        actor: "evm",
+        types_file: None,
+        methods: EVM_METHODS,
+        aliases: &[],
+        rust_only: &[],
+    },
+];
+
+/// `contracts/v0.8/types`, where the Solidity library declares the method numbers.
+pub fn types_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(TYPES_DIR)
+}
+
+/// The `*MethodNum` constants declared in a Solidity source, in order.
+pub fn parse_method_nums(source: &str) -> Result<Vec<(String, MethodNum)>> {
+    let mut constants = vec![];
+    for line in source.lines() {
+        let code = line.split("//").next().unwrap();
+        let Some((declaration, value)) = code.split_once('=') else {
+            continue;
+        };
+
+        let mut words = declaration.split_whitespace();
+        if !words.any(|word| word == "constant") {
+            continue;
+        }
+        let Some(name) = words.next().filter(|name| name.ends_with("MethodNum")) else {
+            continue;
+        };
+
+        let value = value.trim().trim_end_matches(';').trim();
+        let num = value
+            .parse()
+            .with_context(|| format!("{} = {} is not a decimal method number", name, value))?;
+        constants.push((name.to_string(), num));
+    }
+
+    Ok(constants)
+}
+
+/// Difference between the registry and the constants of a Solidity types file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MethodNumMismatch {
+    WrongValue {
+        file: String,
+        constant: String,
+        registry: MethodNum,
+        solidity: MethodNum,
+    },
+    /// Declared in Solidity, unknown to the registry.
+    NotInRegistry { file: String, constant: String },
+    /// In the registry, not declared in Solidity.
+    NotInSolidity { file: String, constant: String },
+}
+
+impl fmt::Display for MethodNumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MethodNumMismatch::WrongValue {
+                file,
+                constant,
+                registry,
+                solidity,
+            } => write!(
+                f,
+                "{}: {} is {}, the FRC-42 number is {}",
+                file, constant, solidity, registry
+            ),
+            MethodNumMismatch::NotInRegistry { file, constant } => {
+                write!(f, "{}: {} is not in testing::methods::REGISTRY", file, constant)
+            }
+            MethodNumMismatch::NotInSolidity { file, constant } => {
+                write!(f, "{}: {} is missing", file, constant)
+            }
+        }
+    }
+}
+
+/// Compares the `*MethodNum` constants of every `.sol` file in `dir` with `registry`, both ways.
+pub fn check_types_dir(dir: &Path, registry: &[ActorMethods]) -> Result<Vec<MethodNumMismatch>> {
+    let mut files: Vec<String> = std::fs::read_dir(dir)
+        .with_context(|| format!("reading {}", dir.display()))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>>>()?;
+    files.retain(|file| file.ends_with(".sol"));
+    for actor in registry {
+        if let Some(file) = actor.types_file {
+            if !files.iter().any(|f| f == file) {
+                files.push(file.to_string());
+            }
+        }
+    }
+    files.sort();
+
+    let mut mismatches = vec![];
+    for file in files {
+        let path = dir.join(&file);
+        let declared = match path.is_file() {
+            true => {
+                let source = std::fs::read_to_string(&path)
+                    .with_context(|| format!("reading {}", path.display()))?;
+                parse_method_nums(&source).with_context(|| format!("parsing {}", path.display()))?
+            }
+            false => vec![],
+        };
+
+        let expected: Vec<(String, MethodNum)> = registry
+            .iter()
+            .filter(|actor| actor.types_file == Some(file.as_str()))
+            .flat_map(ActorMethods::constants)
+            .collect();
+
+        for (constant, solidity) in &declared {
+            match expected.iter().find(|(name, _)| name == constant) {
+                Some((_, num)) if num != solidity => {
+                    mismatches.push(MethodNumMismatch::WrongValue {
+                        file: file.clone(),
+                        constant: constant.clone(),
+                        registry: *num,
+                        solidity: *solidity,
+                    })
+                }
+                Some(_) => {}
+                None => mismatches.push(MethodNumMismatch::NotInRegistry {
+                    file: file.clone(),
+                    constant: constant.clone(),
+                }),
+            }
+        }
+
+        for (constant, _) in &expected {
+            if !declared.iter().any(|(name, _)| name == constant) {
+                mismatches.push(MethodNumMismatch::NotInSolidity {
+                    file: file.clone(),
+                    constant: constant.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
+
+/// Fails listing every mismatch between `REGISTRY` and `contracts/v0.8/types`.
+pub fn check_types() -> Result<()> {
+    let mismatches = check_types_dir(&types_dir(), REGISTRY)?;
+    if !mismatches.is_empty() {
+        let lines: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+        bail!("method numbers out of sync:\n{}", lines.join("\n"));
+    }
+
+    Ok(())
+}
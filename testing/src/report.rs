@@ -0,0 +1,209 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::artifacts;
+use crate::setup::TesterConfig;
+use crate::GasResult;
+
+/// Overrides the solc version recorded in the reports, read from `../bin/solc` otherwise.
+pub const SOLC_VERSION_ENV: &str = "FIL_SOLC_VERSION";
+/// Overrides the optimizer settings recorded in the reports, read from the flags `make build`
+/// recorded in the build directory otherwise.
+pub const SOLC_OPTIMIZER_ENV: &str = "FIL_SOLC_OPTIMIZER";
+
+const SOLC_PATH: &str = "../bin/solc";
+// Written by `make build` next to the artifacts, the flags every contract is compiled with
+const SOLC_FLAGS_FILE: &str = "solc_flags";
+// What solc optimizes for without `--optimize-runs`
+const DEFAULT_OPTIMIZER_RUNS: &str = "200";
+
+/// Configuration the gas results were measured with, so that results of different
+/// compilers, bundles or network versions are never mixed up.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasRunInfo {
+    pub solc_version: String,
+    pub optimizer: String,
+    pub bundle: String,
+    pub network_version: u32,
+    pub state_tree_version: u64,
+}
+
+impl GasRunInfo {
+    pub fn current(config: &TesterConfig) -> Self {
+        GasRunInfo {
+            solc_version: solc_version(),
+            optimizer: optimizer(),
+            bundle: config.bundle.name(),
+            network_version: config.network_version.into(),
+            state_tree_version: config.state_tree_version as u64,
+        }
+    }
+}
+
+fn solc_version() -> String {
+    if let Ok(version) = std::env::var(SOLC_VERSION_ENV) {
+        return version;
+    }
+
+    let solc = Path::new(env!("CARGO_MANIFEST_DIR")).join(SOLC_PATH);
+    Command::new(solc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| {
+            // "Version: 0.8.18+commit.87f61d96.Linux.g++"
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .find_map(|line| line.strip_prefix("Version: ").map(str::to_string))
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn optimizer() -> String {
+    if let Ok(optimizer) = std::env::var(SOLC_OPTIMIZER_ENV) {
+        return optimizer;
+    }
+
+    std::fs::read_to_string(artifacts::build_dir().join(SOLC_FLAGS_FILE))
+        .map(|flags| describe_optimizer(&flags))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Optimizer settings of the solc command line `flags`, e.g. "enabled, 200 runs".
+pub fn describe_optimizer(flags: &str) -> String {
+    let flags: Vec<&str> = flags.split_whitespace().collect();
+    if !flags.contains(&"--optimize") {
+        return "disabled".to_string();
+    }
+
+    let runs = flags
+        .iter()
+        .position(|flag| *flag == "--optimize-runs")
+        .and_then(|i| flags.get(i + 1))
+        .copied()
+        .unwrap_or(DEFAULT_OPTIMIZER_RUNS);
+    let mut optimizer = format!("enabled, {} runs", runs);
+    if flags.contains(&"--via-ir") {
+        optimizer.push_str(", via IR");
+    }
+
+    optimizer
+}
+
+/// Gas results of one suite, as written next to `gas_results_{suite}.csv`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SuiteGasResults {
+    pub suite: String,
+    pub info: GasRunInfo,
+    pub results: GasResult,
+}
+
+impl SuiteGasResults {
+    pub fn path(dir: &Path, suite: &str) -> PathBuf {
+        dir.join(format!("gas_results_{}.json", suite))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = Self::path(dir, &self.suite);
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, json).with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+/// Writes `gas_results_{suite}.json` in the working directory, next to the CSV
+/// `save_gas_table` writes, with the configuration the suite ran with.
+pub fn save_gas_results(gas_result: &GasResult, suite: &str) {
+    let results = SuiteGasResults {
+        suite: suite.to_string(),
+        info: GasRunInfo::current(&TesterConfig::from_env()),
+        results: gas_result.clone(),
+    };
+
+    results.save(Path::new(".")).unwrap();
+}
+
+/// Every `gas_results_*.json` in `dir`, sorted by suite.
+pub fn collect(dir: &Path) -> Result<Vec<SuiteGasResults>> {
+    let mut suites = vec![];
+    for entry in std::fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap().to_string_lossy();
+        if !(file_name.starts_with("gas_results_") && file_name.ends_with(".json")) {
+            continue;
+        }
+
+        let json = std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        let suite: SuiteGasResults =
+            serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))?;
+        suites.push(suite);
+    }
+    suites.sort_by(|a, b| a.suite.cmp(&b.suite));
+
+    Ok(suites)
+}
+
+pub fn render_markdown(suites: &[SuiteGasResults]) -> String {
+    let mut out = "# Gas report\n".to_string();
+    for suite in suites {
+        let info = &suite.info;
+        out.push_str(&format!(
+            "\n## {}\n\nsolc {} (optimizer {}), {}, nv{}, stv{}\n\n| Function | Gas |\n|---|---:|\n",
+            suite.suite, info.solc_version, info.optimizer, info.bundle, info.network_version, info.state_tree_version
+        ));
+        for (function, gas) in &suite.results {
+            out.push_str(&format!("| {} | {} |\n", function, gas));
+        }
+    }
+
+    out
+}
+
+pub fn render_csv(suites: &[SuiteGasResults]) -> String {
+    let mut out = "Suite,Function,Gas,Solc,Optimizer,Bundle,NetworkVersion,StateTreeVersion\n".to_string();
+    for suite in suites {
+        let info = &suite.info;
+        for (function, gas) in &suite.results {
+            out.push_str(&format!(
+                "{},{},{},{},\"{}\",{},{},{}\n",
+                suite.suite,
+                function,
+                gas,
+                info.solc_version,
+                info.optimizer,
+                info.bundle,
+                info.network_version,
+                info.state_tree_version
+            ));
+        }
+    }
+
+    out
+}
+
+pub fn render_json(suites: &[SuiteGasResults]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(suites)?)
+}
+
+/// Writes `gas_report.md`, `gas_report.json` and `gas_report.csv` to `out_dir` from the
+/// suite results found in `results_dir`.
+pub fn write_report(results_dir: &Path, out_dir: &Path) -> Result<Vec<PathBuf>> {
+    let suites = collect(results_dir)?;
+    std::fs::create_dir_all(out_dir)?;
+
+    let outputs = [
+        ("gas_report.md", render_markdown(&suites)),
+        ("gas_report.json", render_json(&suites)?),
+        ("gas_report.csv", render_csv(&suites)),
+    ];
+
+    let mut paths = vec![];
+    for (file_name, content) in outputs {
+        let path = out_dir.join(file_name);
+        std::fs::write(&path, content).with_context(|| format!("writing {}", path.display()))?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
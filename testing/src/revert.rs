@@ -0,0 +1,220 @@
+use alloy_primitives::{I256, U256};
+use alloy_sol_types::{sol, Panic as SolPanic, Revert as SolRevert, SolError};
+use fvm::executor::ApplyRet;
+use std::fmt;
+
+use crate::api_contracts;
+
+/// Exit code of an `InvokeContract` call that reverted, the revert data is the return data.
+pub const EVM_CONTRACT_REVERTED: u32 = 33;
+
+sol! {
+    // Actor.sol
+    error InvalidAddress(bytes addr);
+    error NotEnoughBalance(uint256 balance, uint256 value);
+    error InvalidActorID(uint64 actorId);
+    error FailToCallActor();
+    error InvalidResponseLength();
+    error InvalidCodec(uint64);
+    error ActorError(int256 errorCode);
+    error ActorNotFound();
+
+    // Errors.sol
+    error InvalidArrayLength(uint256 expected, uint256 actual);
+    error InvalidBooleanType();
+    error ExpectedMajorByteString();
+    error ExpectedNegativeBigNumTag();
+    error ExpectedLowValue27();
+    error NonZeroExitCode(int256 exit_code, string description);
+}
+
+/// Revert data of a contract call, decoded against the errors of the Solidity library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Revert {
+    InvalidAddress(Vec<u8>),
+    NotEnoughBalance { balance: U256, value: U256 },
+    InvalidActorID(u64),
+    FailToCallActor,
+    InvalidResponseLength,
+    InvalidCodec(u64),
+    ActorError(I256),
+    ActorNotFound,
+    InvalidArrayLength { expected: U256, actual: U256 },
+    InvalidBooleanType,
+    ExpectedMajorByteString,
+    ExpectedNegativeBigNumTag,
+    ExpectedLowValue27,
+    NonZeroExitCode { exit_code: I256, description: String },
+    Error(String),
+    Panic(U256),
+    /// Revert without data, e.g. `revert()`.
+    Empty,
+    Unknown(Vec<u8>),
+}
+
+impl Revert {
+    /// Decodes the ABI encoded revert data, as returned inside the CBOR byte string.
+    pub fn decode(data: &[u8]) -> Self {
+        if data.is_empty() {
+            return Revert::Empty;
+        }
+
+        decode_known(data).unwrap_or_else(|| Revert::Unknown(data.to_vec()))
+    }
+
+    /// Revert of a call, `None` if it did not exit with `EVM_CONTRACT_REVERTED`.
+    pub fn from_apply_ret(res: &ApplyRet) -> Option<Self> {
+        if res.msg_receipt.exit_code.value() != EVM_CONTRACT_REVERTED {
+            return None;
+        }
+
+        let data = api_contracts::cbor_decode(res.msg_receipt.return_data.bytes()).ok()?;
+        Some(Revert::decode(&data))
+    }
+}
+
+fn decode_known(data: &[u8]) -> Option<Revert> {
+    let selector: [u8; 4] = data.get(..4)?.try_into().ok()?;
+
+    let revert = match selector {
+        InvalidAddress::SELECTOR => Revert::InvalidAddress(InvalidAddress::abi_decode(data, true).ok()?.addr),
+        NotEnoughBalance::SELECTOR => {
+            let err = NotEnoughBalance::abi_decode(data, true).ok()?;
+            Revert::NotEnoughBalance {
+                balance: err.balance,
+                value: err.value,
+            }
+        }
+        InvalidActorID::SELECTOR => Revert::InvalidActorID(InvalidActorID::abi_decode(data, true).ok()?.actorId),
+        FailToCallActor::SELECTOR => Revert::FailToCallActor,
+        InvalidResponseLength::SELECTOR => Revert::InvalidResponseLength,
+        InvalidCodec::SELECTOR => Revert::InvalidCodec(InvalidCodec::abi_decode(data, true).ok()?._0),
+        ActorError::SELECTOR => Revert::ActorError(ActorError::abi_decode(data, true).ok()?.errorCode),
+        ActorNotFound::SELECTOR => Revert::ActorNotFound,
+        InvalidArrayLength::SELECTOR => {
+            let err = InvalidArrayLength::abi_decode(data, true).ok()?;
+            Revert::InvalidArrayLength {
+                expected: err.expected,
+                actual: err.actual,
+            }
+        }
+        InvalidBooleanType::SELECTOR => Revert::InvalidBooleanType,
+        ExpectedMajorByteString::SELECTOR => Revert::ExpectedMajorByteString,
+        ExpectedNegativeBigNumTag::SELECTOR => Revert::ExpectedNegativeBigNumTag,
+        ExpectedLowValue27::SELECTOR => Revert::ExpectedLowValue27,
+        NonZeroExitCode::SELECTOR => {
+            let err = NonZeroExitCode::abi_decode(data, true).ok()?;
+            Revert::NonZeroExitCode {
+                exit_code: err.exit_code,
+                description: err.description,
+            }
+        }
+        // Emitted by solc itself for `require`/`revert("...")` and failed checks
+        SolRevert::SELECTOR => Revert::Error(SolRevert::abi_decode(data, true).ok()?.reason),
+        SolPanic::SELECTOR => Revert::Panic(SolPanic::abi_decode(data, true).ok()?.code),
+        _ => return None,
+    };
+
+    Some(revert)
+}
+
+/// Name of the exit codes `Errors.sol` and the FVM define, e.g. `USR_NOT_FOUND`.
+pub fn exit_code_name(code: I256) -> Option<&'static str> {
+    let name = match i64::try_from(code).ok()? {
+        1 => "SYS_SENDER_INVALID",
+        2 => "SYS_SENDER_STATE_INVALID",
+        4 => "SYS_ILLEGAL_INSTRUCTION",
+        5 => "SYS_INVALID_RECEIVER",
+        6 => "SYS_INSUFFICIENT_FUNDS",
+        7 => "SYS_OUT_OF_GAS",
+        9 => "SYS_ILLEGAL_EXIT_CODE",
+        10 => "SYS_ASSERTION_FAILED",
+        11 => "SYS_MISSING_RETURN",
+        16 => "USR_ILLEGAL_ARGUMENT",
+        17 => "USR_NOT_FOUND",
+        18 => "USR_FORBIDDEN",
+        19 => "USR_INSUFFICIENT_FUNDS",
+        20 => "USR_ILLEGAL_STATE",
+        21 => "USR_SERIALIZATION",
+        22 => "USR_UNHANDLED_MESSAGE",
+        23 => "USR_UNSPECIFIED",
+        24 => "USR_ASSERTION_FAILED",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+struct ExitCodeDisplay(I256);
+
+impl fmt::Display for ExitCodeDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match exit_code_name(self.0) {
+            Some(name) => write!(f, "{}={}", name, self.0),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl fmt::Display for Revert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Revert::InvalidAddress(addr) => write!(f, "InvalidAddress(0x{})", hex::encode(addr)),
+            Revert::NotEnoughBalance { balance, value } => {
+                write!(f, "NotEnoughBalance(balance={}, value={})", balance, value)
+            }
+            Revert::InvalidActorID(actor_id) => write!(f, "InvalidActorID({})", actor_id),
+            Revert::FailToCallActor => write!(f, "FailToCallActor"),
+            Revert::InvalidResponseLength => write!(f, "InvalidResponseLength"),
+            Revert::InvalidCodec(codec) => write!(f, "InvalidCodec(0x{:x})", codec),
+            Revert::ActorError(code) => write!(f, "ActorError({})", ExitCodeDisplay(*code)),
+            Revert::ActorNotFound => write!(f, "ActorNotFound"),
+            Revert::InvalidArrayLength { expected, actual } => {
+                write!(f, "InvalidArrayLength(expected={}, actual={})", expected, actual)
+            }
+            Revert::InvalidBooleanType => write!(f, "InvalidBooleanType"),
+            Revert::ExpectedMajorByteString => write!(f, "ExpectedMajorByteString"),
+            Revert::ExpectedNegativeBigNumTag => write!(f, "ExpectedNegativeBigNumTag"),
+            Revert::ExpectedLowValue27 => write!(f, "ExpectedLowValue27"),
+            Revert::NonZeroExitCode {
+                exit_code,
+                description,
+            } => {
+                // revertOnError describes known codes by their name, which is already shown
+                if exit_code_name(*exit_code) == Some(description.as_str()) {
+                    write!(f, "NonZeroExitCode({})", ExitCodeDisplay(*exit_code))
+                } else {
+                    write!(f, "NonZeroExitCode({}, {:?})", ExitCodeDisplay(*exit_code), description)
+                }
+            }
+            Revert::Error(message) => write!(f, "Error({:?})", message),
+            Revert::Panic(code) => write!(f, "Panic(0x{:x})", code),
+            Revert::Empty => write!(f, "revert without data"),
+            Revert::Unknown(data) => write!(f, "unknown revert 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+/// Asserts the call reverted with the custom error `E` and returns it, e.g.
+/// `let err = expect_revert::<ActorError>(&res);`.
+pub fn expect_revert<E: SolError>(res: &ApplyRet) -> E {
+    let exit_code = res.msg_receipt.exit_code.value();
+    assert_eq!(
+        exit_code,
+        EVM_CONTRACT_REVERTED,
+        "expected a {} revert, the call exited with {}",
+        E::SIGNATURE,
+        exit_code
+    );
+
+    let data = api_contracts::cbor_decode(res.msg_receipt.return_data.bytes())
+        .unwrap_or_else(|err| panic!("revert data: {:#}", err));
+
+    E::abi_decode(&data, true).unwrap_or_else(|_| {
+        panic!(
+            "expected a {} revert, got {}",
+            E::SIGNATURE,
+            Revert::decode(&data)
+        )
+    })
+}
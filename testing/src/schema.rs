@@ -0,0 +1,1014 @@
+use anyhow::{Context, Result};
+use cid::Cid;
+use fil_actors_runtime::{BatchReturn, FailCode};
+use fvm_ipld_encoding::{BytesDe, BytesSer, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::bigint_ser::BigIntSer;
+use fvm_shared::bigint::BigInt;
+use fvm_shared::crypto::signature::Signature;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::piece::PaddedPieceSize;
+use fvm_shared::sector::RegisteredPoStProof;
+use libipld_core::ipld::Ipld;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::methods;
+
+/// Type of a Solidity struct field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolType {
+    Bytes,
+    String,
+    Bool,
+    Uint,
+    Int,
+    /// Struct, enum or user defined value type, qualified with its library, e.g.
+    /// `CommonTypes.BigInt`.
+    Named(String),
+    Array(Box<SolType>),
+}
+
+/// Structs, enums and value types declared by the Solidity libraries.
+#[derive(Clone, Debug, Default)]
+pub struct SolSchema {
+    /// Fields in declaration order, by qualified struct name.
+    pub structs: BTreeMap<String, Vec<(String, SolType)>>,
+    pub enums: BTreeSet<String>,
+    pub value_types: BTreeMap<String, SolType>,
+}
+
+impl SolSchema {
+    /// Schema of every `.sol` file in `dir`.
+    pub fn from_dir(dir: &Path) -> Result<Self> {
+        let mut schema = SolSchema::default();
+        for entry in std::fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+            let path = entry?.path();
+            if path.extension().map_or(false, |ext| ext == "sol") {
+                let source = std::fs::read_to_string(&path)
+                    .with_context(|| format!("reading {}", path.display()))?;
+                schema.add_source(&source);
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// Adds the declarations of `source`. Line based, as the types files declare one
+    /// field per line.
+    pub fn add_source(&mut self, source: &str) {
+        let mut library = String::new();
+        let mut current: Option<(String, Vec<(String, SolType)>)> = None;
+        let mut in_enum = false;
+
+        for line in source.lines() {
+            let line = line.split("//").next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let words: Vec<&str> = line.split_whitespace().collect();
+
+            if in_enum {
+                in_enum = !line.contains('}');
+                continue;
+            }
+
+            if let Some((name, fields)) = current.as_mut() {
+                if line.starts_with('}') {
+                    self.structs.insert(name.clone(), std::mem::take(fields));
+                    current = None;
+                } else if let [ty, field] = words[..] {
+                    fields.push((field.trim_end_matches(';').to_string(), parse_type(&library, ty)));
+                }
+                continue;
+            }
+
+            match words[..] {
+                ["library", name, ..] => library = name.to_string(),
+                ["struct", name, ..] if line.ends_with('}') => {
+                    self.structs.insert(qualify(&library, name), vec![]);
+                }
+                ["struct", name, ..] => current = Some((qualify(&library, name), vec![])),
+                ["enum", name, ..] => {
+                    self.enums.insert(qualify(&library, name));
+                    in_enum = !line.contains('}');
+                }
+                ["type", name, "is", underlying] => {
+                    let underlying = underlying.trim_end_matches(';');
+                    self.value_types
+                        .insert(qualify(&library, name), parse_type(&library, underlying));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn resolve<'a>(&'a self, ty: &'a SolType) -> &'a SolType {
+        match ty {
+            SolType::Named(name) => self.value_types.get(name).unwrap_or(ty),
+            _ => ty,
+        }
+    }
+}
+
+fn qualify(library: &str, name: &str) -> String {
+    match name.contains('.') {
+        true => name.to_string(),
+        false => format!("{}.{}", library, name),
+    }
+}
+
+fn parse_type(library: &str, ty: &str) -> SolType {
+    if let Some(element) = ty.strip_suffix("[]") {
+        return SolType::Array(Box::new(parse_type(library, element)));
+    }
+
+    match ty {
+        "bytes" => SolType::Bytes,
+        "string" => SolType::String,
+        "bool" => SolType::Bool,
+        _ if ty.starts_with("uint") => SolType::Uint,
+        _ if ty.starts_with("int") => SolType::Int,
+        _ => SolType::Named(qualify(library, ty)),
+    }
+}
+
+/// A Solidity struct and the CBOR tuple of the builtin-actors type it mirrors.
+pub struct Conformance {
+    pub solidity: &'static str,
+    pub rust: &'static str,
+    /// Solidity field names, in the order of the CBOR tuple, with the CBOR of the value
+    /// `sample` has for that field. The values are distinct so that two fields swapped by
+    /// the serializer are caught.
+    pub cbor_fields: Vec<(&'static str, Ipld)>,
+    /// CBOR of a value of the Rust type, with every optional field set and every list
+    /// non empty so that all nested shapes show up.
+    pub sample: Ipld,
+}
+
+impl Conformance {
+    fn field_names(&self) -> Vec<&'static str> {
+        self.cbor_fields.iter().map(|(field, _)| *field).collect()
+    }
+}
+
+/// A field of a `Conformance`, with the CBOR of its sample value.
+pub fn field<T: Serialize>(name: &'static str, value: &T) -> (&'static str, Ipld) {
+    (name, shape(value))
+}
+
+/// Solidity structs with no builtin-actors type to compare with, and why.
+pub const UNCHECKED: &[(&str, &str)] = &[];
+
+/// Solidity structs declared in another order than their CBOR tuple. `check` reports them,
+/// reordering the declarations changes their ABI and is left to a breaking release.
+pub const MISORDERED: &[&str] = &[
+    "DataCapTypes.TransferFromParams",
+    "DataCapTypes.TransferFromReturn",
+    "DataCapTypes.TransferParams",
+    "DataCapTypes.TransferReturn",
+    "MarketTypes.PublishStorageDealsReturn",
+    "PowerTypes.CreateMinerParams",
+    "VerifRegTypes.Claim",
+];
+
+/// A field whose Solidity declaration no longer matches the CBOR of the actors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Drift {
+    /// Struct and field, e.g. `MarketTypes.DealProposal.label`.
+    pub path: String,
+    pub problem: String,
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.problem)
+    }
+}
+
+/// Compares every struct of `schema` with the builtin-actors type it mirrors.
+pub fn check(schema: &SolSchema, conformances: &[Conformance]) -> Vec<Drift> {
+    let checker = Checker {
+        schema,
+        conformances,
+    };
+    let mut drifts = vec![];
+
+    for conformance in conformances {
+        checker.check_serialized_order(conformance, &mut drifts);
+        checker.check_struct(conformance.solidity, &conformance.sample, conformance.solidity, &mut drifts);
+    }
+
+    for name in schema.structs.keys() {
+        let covered = conformances.iter().any(|c| c.solidity == name.as_str())
+            || UNCHECKED.iter().any(|(unchecked, _)| *unchecked == name.as_str())
+            || scalar_kind(name).is_some();
+        if !covered {
+            drifts.push(Drift {
+                path: name.clone(),
+                problem: "not compared with any builtin-actors type".to_string(),
+            });
+        }
+    }
+
+    drifts
+}
+
+/// `check` of `contracts/v0.8/types` against the builtin-actors types the testing crate
+/// builds against.
+pub fn check_types() -> Result<Vec<Drift>> {
+    let schema = SolSchema::from_dir(&methods::types_dir())?;
+    Ok(check(&schema, &conformances()))
+}
+
+// Structs the Solidity libraries encode as a single CBOR value
+fn scalar_kind(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "CommonTypes.BigInt" | "CommonTypes.FilAddress" => Some(&["bytes"]),
+        "CommonTypes.Cid" => Some(&["link"]),
+        "CommonTypes.DealLabel" => Some(&["string", "bytes"]),
+        _ => None,
+    }
+}
+
+fn kind(ipld: &Ipld) -> &'static str {
+    match ipld {
+        Ipld::Null => "null",
+        Ipld::Bool(_) => "bool",
+        Ipld::Integer(_) => "integer",
+        Ipld::Float(_) => "float",
+        Ipld::String(_) => "string",
+        Ipld::Bytes(_) => "bytes",
+        Ipld::List(_) => "list",
+        Ipld::Map(_) => "map",
+        Ipld::Link(_) => "link",
+    }
+}
+
+struct Checker<'a> {
+    schema: &'a SolSchema,
+    conformances: &'a [Conformance],
+}
+
+impl Checker<'_> {
+    fn check_struct(&self, name: &str, ipld: &Ipld, path: &str, drifts: &mut Vec<Drift>) {
+        let mut drift = |path: String, problem: String| drifts.push(Drift { path, problem });
+
+        let Some(fields) = self.schema.structs.get(name) else {
+            drift(path.to_string(), format!("no Solidity struct {}", name));
+            return;
+        };
+        let solidity_order: Vec<&str> = fields.iter().map(|(field, _)| field.as_str()).collect();
+        let order = self
+            .conformances
+            .iter()
+            .find(|c| c.solidity == name)
+            .map_or(solidity_order.clone(), |c| c.field_names());
+
+        for field in &solidity_order {
+            if !order.contains(field) {
+                drift(format!("{}.{}", path, field), "not in the CBOR field order".to_string());
+            }
+        }
+        for field in &order {
+            if !solidity_order.contains(field) {
+                drift(format!("{}.{}", path, field), "no such Solidity field".to_string());
+            }
+        }
+
+        // The fields both sides know are declared in the order of the CBOR tuple
+        let declared: Vec<&str> = solidity_order.iter().copied().filter(|f| order.contains(f)).collect();
+        let encoded: Vec<&str> = order.iter().copied().filter(|f| solidity_order.contains(f)).collect();
+        for (field, expected) in declared.iter().zip(&encoded) {
+            if field != expected {
+                drift(
+                    format!("{}.{}", path, field),
+                    format!("declared where the CBOR tuple has {}", expected),
+                );
+            }
+        }
+
+        let Ipld::List(items) = ipld else {
+            drift(path.to_string(), format!("expected a CBOR tuple, got {}", kind(ipld)));
+            return;
+        };
+        if items.len() != order.len() {
+            drift(
+                path.to_string(),
+                format!("the CBOR tuple has {} fields, {} expected", items.len(), order.len()),
+            );
+        }
+
+        for (field, item) in order.iter().zip(items) {
+            if let Some((_, ty)) = fields.iter().find(|(name, _)| name.as_str() == *field) {
+                self.check_value(ty, item, &format!("{}.{}", path, field), drifts);
+            }
+        }
+        for i in order.len()..items.len() {
+            drifts.push(Drift {
+                path: format!("{}[{}]", path, i),
+                problem: "CBOR field with no Solidity field".to_string(),
+            });
+        }
+    }
+
+    // The sample values of the Rust type end up at the positions of `cbor_fields`
+    fn check_serialized_order(&self, conformance: &Conformance, drifts: &mut Vec<Drift>) {
+        let path = conformance.solidity;
+        let Ipld::List(items) = &conformance.sample else {
+            return;
+        };
+
+        for (i, (field, value)) in conformance.cbor_fields.iter().enumerate() {
+            if let Some((other, _)) = conformance.cbor_fields[..i].iter().find(|(_, v)| v == value) {
+                drifts.push(Drift {
+                    path: format!("{}.{}", path, field),
+                    problem: format!("same sample value as {}, swaps would go unnoticed", other),
+                });
+                continue;
+            }
+
+            if items.get(i) == Some(value) {
+                continue;
+            }
+            let problem = match items.iter().position(|item| item == value) {
+                Some(position) => format!("serialized at position {}, expected {}", position, i),
+                None => "sample value not found in the CBOR tuple".to_string(),
+            };
+            drifts.push(Drift {
+                path: format!("{}.{}", path, field),
+                problem,
+            });
+        }
+    }
+
+    fn check_value(&self, ty: &SolType, ipld: &Ipld, path: &str, drifts: &mut Vec<Drift>) {
+        // `bytes` fields also hold the raw bytes of CIDs, e.g. `Claim.data`
+        let expected: &[&str] = match self.schema.resolve(ty) {
+            SolType::Bytes => &["bytes", "link"],
+            SolType::String => &["string"],
+            SolType::Bool => &["bool"],
+            SolType::Uint | SolType::Int => &["integer"],
+            SolType::Array(element) => {
+                match ipld {
+                    Ipld::List(items) => {
+                        for (i, item) in items.iter().enumerate() {
+                            self.check_value(element, item, &format!("{}[{}]", path, i), drifts);
+                        }
+                    }
+                    _ => drifts.push(Drift {
+                        path: path.to_string(),
+                        problem: format!("expected list, CBOR has {}", kind(ipld)),
+                    }),
+                }
+                return;
+            }
+            SolType::Named(name) if self.schema.enums.contains(name) => &["integer"],
+            SolType::Named(name) => match scalar_kind(name) {
+                Some(kinds) => kinds,
+                None => return self.check_struct(name, ipld, path, drifts),
+            },
+        };
+
+        if !expected.contains(&kind(ipld)) {
+            drifts.push(Drift {
+                path: path.to_string(),
+                problem: format!("expected {}, CBOR has {}", expected.join(" or "), kind(ipld)),
+            });
+        }
+    }
+}
+
+fn shape<T: Serialize>(value: &T) -> Ipld {
+    let cbor = fvm_ipld_encoding::to_vec(value).unwrap();
+    fvm_ipld_encoding::from_slice(&cbor).unwrap()
+}
+
+const SAMPLE_CID: &str = "baga6ea4seaqlkg6mss5qs56jqtajg5ycrhpkj2b66cgdkukf2qjmmzz6ayksuci";
+
+/// Every Solidity struct of `contracts/v0.8/types` paired with the builtin-actors type it mirrors.
+pub fn conformances() -> Vec<Conformance> {
+    let address = Address::new_id;
+    let amount = |atto: u64| TokenAmount::from_atto(atto);
+    let cid = Cid::from_str(SAMPLE_CID).unwrap();
+
+    let authenticate_message = fil_actor_account::types::AuthenticateMessageParams {
+        signature: vec![1; 65],
+        message: vec![2, 3, 4],
+    };
+
+    let fail_code = FailCode {
+        idx: 1,
+        code: ExitCode::USR_NOT_FOUND,
+    };
+    let batch_return = BatchReturn {
+        success_count: 2,
+        fail_codes: vec![fail_code.clone()],
+    };
+    let receiver_params = fvm_actor_utils::receiver::UniversalReceiverParams {
+        type_: 0x85223bdf,
+        payload: RawBytes::new(vec![1, 2, 3]),
+    };
+
+    let withdraw_balance = fil_actor_market::WithdrawBalanceParams {
+        provider_or_client: address(1000),
+        amount: amount(100),
+    };
+    let balance = fil_actor_market::GetBalanceReturn {
+        balance: amount(100),
+        locked: amount(200),
+    };
+    let data_commitment = fil_actor_market::GetDealDataCommitmentReturn {
+        data: cid,
+        size: PaddedPieceSize(2048),
+    };
+    let deal_term = fil_actor_market::GetDealTermReturn {
+        start: 100,
+        duration: 200,
+    };
+    let deal_activation = fil_actor_market::GetDealActivationReturn {
+        activated: 100,
+        terminated: -1,
+    };
+    let deal_proposal = fil_actor_market::DealProposal {
+        piece_cid: cid,
+        piece_size: PaddedPieceSize(2048),
+        verified_deal: false,
+        client: address(1000),
+        provider: address(1001),
+        label: fil_actor_market::Label::String("label".to_string()),
+        start_epoch: 100,
+        end_epoch: 200,
+        storage_price_per_epoch: amount(100),
+        provider_collateral: amount(200),
+        client_collateral: amount(300),
+    };
+    let client_deal_proposal = fil_actor_market::ClientDealProposal {
+        proposal: deal_proposal.clone(),
+        client_signature: Signature::new_bls(vec![1; 96]),
+    };
+    let publish_params = fil_actor_market::PublishStorageDealsParams {
+        deals: vec![client_deal_proposal.clone()],
+    };
+    // BitField serializes as its RLE+ bytes, the empty set is no bytes at all
+    let publish_return: fil_actor_market::PublishStorageDealsReturn =
+        fvm_ipld_encoding::from_slice(&fvm_ipld_encoding::to_vec(&(vec![7_u64], BytesSer(&[]))).unwrap())
+            .unwrap();
+    let notify_deal = fil_actor_market::MarketNotifyDealParams {
+        proposal: fvm_ipld_encoding::to_vec(&deal_proposal).unwrap(),
+        deal_id: 7,
+    };
+
+    let owner = fil_actor_miner::GetOwnerReturn {
+        owner: address(1000),
+        proposed: Some(address(1001)),
+    };
+    let change_beneficiary = fil_actor_miner::ChangeBeneficiaryParams {
+        new_beneficiary: address(1000),
+        new_quota: amount(100),
+        new_expiration: 300,
+    };
+    let beneficiary_term = fil_actor_miner::BeneficiaryTerm {
+        quota: amount(100),
+        used_quota: amount(200),
+        expiration: 300,
+    };
+    let active_beneficiary = fil_actor_miner::ActiveBeneficiary {
+        beneficiary: address(1000),
+        term: beneficiary_term.clone(),
+    };
+    let pending_beneficiary = fil_actor_miner::PendingBeneficiaryChange {
+        new_beneficiary: address(1001),
+        new_quota: amount(100),
+        new_expiration: 300,
+        approved_by_beneficiary: true,
+        approved_by_nominee: false,
+    };
+    let beneficiary = fil_actor_miner::GetBeneficiaryReturn {
+        active: active_beneficiary.clone(),
+        proposed: Some(pending_beneficiary.clone()),
+    };
+    let change_worker = fil_actor_miner::ChangeWorkerAddressParams {
+        new_worker: address(1000),
+        new_control_addresses: vec![address(1001)],
+    };
+    let vesting_funds = fil_actor_miner::GetVestingFundsReturn {
+        vesting_funds: vec![(100, amount(200))],
+    };
+    let (vesting_epoch, vesting_amount) = &vesting_funds.vesting_funds[0];
+
+    let create_miner = fil_actor_power::CreateMinerParams {
+        owner: address(1000),
+        worker: address(1001),
+        window_post_proof_type: RegisteredPoStProof::StackedDRGWindow32GiBV1P1,
+        peer: vec![1, 2, 3],
+        multiaddrs: vec![BytesDe(vec![4, 5, 6])],
+    };
+    let create_miner_return = fil_actor_power::CreateMinerReturn {
+        id_address: address(1000),
+        robust_address: Address::new_actor(b"miner"),
+    };
+    let raw_power = fil_actor_power::MinerRawPowerReturn {
+        raw_byte_power: BigInt::from(1_u64 << 40),
+        meets_consensus_minimum: true,
+    };
+
+    let claim = fil_actor_verifreg::Claim {
+        provider: 1000,
+        client: 1001,
+        data: cid,
+        size: PaddedPieceSize(2048),
+        term_min: 100,
+        term_max: 200,
+        term_start: 50,
+        sector: 1,
+    };
+    let get_claims = fil_actor_verifreg::GetClaimsParams {
+        provider: 1000,
+        claim_ids: vec![1, 2],
+    };
+    let get_claims_return = fil_actor_verifreg::GetClaimsReturn {
+        batch_info: batch_return.clone(),
+        claims: vec![claim.clone()],
+    };
+    let add_verified_client = fil_actor_verifreg::AddVerifiedClientParams {
+        address: address(1000),
+        allowance: amount(100),
+    };
+    let remove_allocations = fil_actor_verifreg::RemoveExpiredAllocationsParams {
+        client: 1001,
+        allocation_ids: vec![1, 2],
+    };
+    let remove_allocations_return = fil_actor_verifreg::RemoveExpiredAllocationsReturn {
+        considered: vec![1, 2],
+        results: batch_return.clone(),
+        datacap_recovered: amount(100),
+    };
+    let remove_claims = fil_actor_verifreg::RemoveExpiredClaimsParams {
+        provider: 1000,
+        claim_ids: vec![1, 2],
+    };
+    let remove_claims_return = fil_actor_verifreg::RemoveExpiredClaimsReturn {
+        considered: vec![1, 2],
+        results: batch_return.clone(),
+    };
+    let claim_term = fil_actor_verifreg::ClaimTerm {
+        provider: 1000,
+        claim_id: 1,
+        term_max: 500,
+    };
+
+    let get_allowance = frc46_token::token::types::GetAllowanceParams {
+        owner: address(1000),
+        operator: address(1001),
+    };
+    let transfer = frc46_token::token::types::TransferParams {
+        to: address(1000),
+        amount: amount(100),
+        operator_data: RawBytes::new(vec![1, 2, 3]),
+    };
+    let transfer_return = frc46_token::token::types::TransferReturn {
+        from_balance: amount(100),
+        to_balance: amount(200),
+        recipient_data: RawBytes::new(vec![1, 2, 3]),
+    };
+    let transfer_from = frc46_token::token::types::TransferFromParams {
+        from: address(1000),
+        to: address(1001),
+        amount: amount(100),
+        operator_data: RawBytes::new(vec![1, 2, 3]),
+    };
+    let transfer_from_return = frc46_token::token::types::TransferFromReturn {
+        from_balance: amount(100),
+        to_balance: amount(200),
+        allowance: amount(300),
+        recipient_data: RawBytes::new(vec![1, 2, 3]),
+    };
+    let increase_allowance = frc46_token::token::types::IncreaseAllowanceParams {
+        operator: address(1000),
+        increase: amount(100),
+    };
+    let decrease_allowance = frc46_token::token::types::DecreaseAllowanceParams {
+        operator: address(1000),
+        decrease: amount(100),
+    };
+    let burn_from = frc46_token::token::types::BurnFromParams {
+        owner: address(1000),
+        amount: amount(100),
+    };
+    let burn_from_return = frc46_token::token::types::BurnFromReturn {
+        balance: amount(100),
+        allowance: amount(200),
+    };
+
+    vec![
+        // AccountTypes
+        Conformance {
+            solidity: "AccountTypes.AuthenticateMessageParams",
+            rust: "fil_actor_account::types::AuthenticateMessageParams",
+            cbor_fields: vec![
+                field("signature", &BytesSer(&authenticate_message.signature)),
+                field("message", &BytesSer(&authenticate_message.message)),
+            ],
+            sample: shape(&authenticate_message),
+        },
+        // CommonTypes
+        Conformance {
+            solidity: "CommonTypes.FailCode",
+            rust: "fil_actors_runtime::FailCode",
+            cbor_fields: vec![field("idx", &fail_code.idx), field("code", &fail_code.code)],
+            sample: shape(&fail_code),
+        },
+        Conformance {
+            solidity: "CommonTypes.BatchReturn",
+            rust: "fil_actors_runtime::BatchReturn",
+            cbor_fields: vec![
+                field("success_count", &batch_return.success_count),
+                field("fail_codes", &batch_return.fail_codes),
+            ],
+            sample: shape(&batch_return),
+        },
+        Conformance {
+            solidity: "CommonTypes.UniversalReceiverParams",
+            rust: "fvm_actor_utils::receiver::UniversalReceiverParams",
+            cbor_fields: vec![
+                field("type_", &receiver_params.type_),
+                field("payload", &receiver_params.payload),
+            ],
+            sample: shape(&receiver_params),
+        },
+        // MarketTypes
+        Conformance {
+            solidity: "MarketTypes.WithdrawBalanceParams",
+            rust: "fil_actor_market::WithdrawBalanceParams",
+            cbor_fields: vec![
+                field("provider_or_client", &withdraw_balance.provider_or_client),
+                field("tokenAmount", &withdraw_balance.amount),
+            ],
+            sample: shape(&withdraw_balance),
+        },
+        Conformance {
+            solidity: "MarketTypes.GetBalanceReturn",
+            rust: "fil_actor_market::GetBalanceReturn",
+            cbor_fields: vec![field("balance", &balance.balance), field("locked", &balance.locked)],
+            sample: shape(&balance),
+        },
+        Conformance {
+            solidity: "MarketTypes.GetDealDataCommitmentReturn",
+            rust: "fil_actor_market::GetDealDataCommitmentReturn",
+            cbor_fields: vec![
+                field("data", &data_commitment.data),
+                field("size", &data_commitment.size),
+            ],
+            sample: shape(&data_commitment),
+        },
+        Conformance {
+            solidity: "MarketTypes.GetDealTermReturn",
+            rust: "fil_actor_market::GetDealTermReturn",
+            cbor_fields: vec![field("start", &deal_term.start), field("duration", &deal_term.duration)],
+            sample: shape(&deal_term),
+        },
+        Conformance {
+            solidity: "MarketTypes.GetDealActivationReturn",
+            rust: "fil_actor_market::GetDealActivationReturn",
+            cbor_fields: vec![
+                field("activated", &deal_activation.activated),
+                field("terminated", &deal_activation.terminated),
+            ],
+            sample: shape(&deal_activation),
+        },
+        Conformance {
+            solidity: "MarketTypes.PublishStorageDealsParams",
+            rust: "fil_actor_market::PublishStorageDealsParams",
+            cbor_fields: vec![field("deals", &publish_params.deals)],
+            sample: shape(&publish_params),
+        },
+        Conformance {
+            solidity: "MarketTypes.PublishStorageDealsReturn",
+            rust: "fil_actor_market::PublishStorageDealsReturn",
+            cbor_fields: vec![
+                field("ids", &publish_return.ids),
+                field("valid_deals", &publish_return.valid_deals),
+            ],
+            sample: shape(&publish_return),
+        },
+        Conformance {
+            solidity: "MarketTypes.DealProposal",
+            rust: "fil_actor_market::DealProposal",
+            cbor_fields: vec![
+                field("piece_cid", &deal_proposal.piece_cid),
+                field("piece_size", &deal_proposal.piece_size),
+                field("verified_deal", &deal_proposal.verified_deal),
+                field("client", &deal_proposal.client),
+                field("provider", &deal_proposal.provider),
+                field("label", &deal_proposal.label),
+                field("start_epoch", &deal_proposal.start_epoch),
+                field("end_epoch", &deal_proposal.end_epoch),
+                field("storage_price_per_epoch", &deal_proposal.storage_price_per_epoch),
+                field("provider_collateral", &deal_proposal.provider_collateral),
+                field("client_collateral", &deal_proposal.client_collateral),
+            ],
+            sample: shape(&deal_proposal),
+        },
+        Conformance {
+            solidity: "MarketTypes.ClientDealProposal",
+            rust: "fil_actor_market::ClientDealProposal",
+            cbor_fields: vec![
+                field("proposal", &client_deal_proposal.proposal),
+                field("client_signature", &client_deal_proposal.client_signature),
+            ],
+            sample: shape(&client_deal_proposal),
+        },
+        Conformance {
+            solidity: "MarketTypes.MarketDealNotifyParams",
+            rust: "fil_actor_market::MarketNotifyDealParams",
+            cbor_fields: vec![
+                field("dealProposal", &BytesSer(&notify_deal.proposal)),
+                field("dealId", &notify_deal.deal_id),
+            ],
+            sample: shape(&notify_deal),
+        },
+        // MinerTypes
+        Conformance {
+            solidity: "MinerTypes.GetOwnerReturn",
+            rust: "fil_actor_miner::GetOwnerReturn",
+            cbor_fields: vec![field("owner", &owner.owner), field("proposed", &owner.proposed)],
+            sample: shape(&owner),
+        },
+        Conformance {
+            solidity: "MinerTypes.ChangeBeneficiaryParams",
+            rust: "fil_actor_miner::ChangeBeneficiaryParams",
+            cbor_fields: vec![
+                field("new_beneficiary", &change_beneficiary.new_beneficiary),
+                field("new_quota", &change_beneficiary.new_quota),
+                field("new_expiration", &change_beneficiary.new_expiration),
+            ],
+            sample: shape(&change_beneficiary),
+        },
+        Conformance {
+            solidity: "MinerTypes.GetBeneficiaryReturn",
+            rust: "fil_actor_miner::GetBeneficiaryReturn",
+            cbor_fields: vec![
+                field("active", &beneficiary.active),
+                field("proposed", &beneficiary.proposed),
+            ],
+            sample: shape(&beneficiary),
+        },
+        Conformance {
+            solidity: "MinerTypes.ChangeWorkerAddressParams",
+            rust: "fil_actor_miner::ChangeWorkerAddressParams",
+            cbor_fields: vec![
+                field("new_worker", &change_worker.new_worker),
+                field("new_control_addresses", &change_worker.new_control_addresses),
+            ],
+            sample: shape(&change_worker),
+        },
+        Conformance {
+            solidity: "MinerTypes.VestingFunds",
+            rust: "fil_actor_miner::GetVestingFundsReturn::vesting_funds",
+            cbor_fields: vec![field("epoch", vesting_epoch), field("amount", vesting_amount)],
+            sample: shape(&vesting_funds.vesting_funds[0]),
+        },
+        Conformance {
+            solidity: "MinerTypes.BeneficiaryTerm",
+            rust: "fil_actor_miner::BeneficiaryTerm",
+            cbor_fields: vec![
+                field("quota", &beneficiary_term.quota),
+                field("used_quota", &beneficiary_term.used_quota),
+                field("expiration", &beneficiary_term.expiration),
+            ],
+            sample: shape(&beneficiary_term),
+        },
+        Conformance {
+            solidity: "MinerTypes.ActiveBeneficiary",
+            rust: "fil_actor_miner::ActiveBeneficiary",
+            cbor_fields: vec![
+                field("beneficiary", &active_beneficiary.beneficiary),
+                field("term", &active_beneficiary.term),
+            ],
+            sample: shape(&active_beneficiary),
+        },
+        Conformance {
+            solidity: "MinerTypes.PendingBeneficiaryChange",
+            rust: "fil_actor_miner::PendingBeneficiaryChange",
+            cbor_fields: vec![
+                field("new_beneficiary", &pending_beneficiary.new_beneficiary),
+                field("new_quota", &pending_beneficiary.new_quota),
+                field("new_expiration", &pending_beneficiary.new_expiration),
+                field("approved_by_beneficiary", &pending_beneficiary.approved_by_beneficiary),
+                field("approved_by_nominee", &pending_beneficiary.approved_by_nominee),
+            ],
+            sample: shape(&pending_beneficiary),
+        },
+        // PowerTypes
+        Conformance {
+            solidity: "PowerTypes.CreateMinerParams",
+            rust: "fil_actor_power::CreateMinerParams",
+            cbor_fields: vec![
+                field("owner", &create_miner.owner),
+                field("worker", &create_miner.worker),
+                field("window_post_proof_type", &create_miner.window_post_proof_type),
+                field("peer", &BytesSer(&create_miner.peer)),
+                field("multiaddrs", &create_miner.multiaddrs),
+            ],
+            sample: shape(&create_miner),
+        },
+        Conformance {
+            solidity: "PowerTypes.CreateMinerReturn",
+            rust: "fil_actor_power::CreateMinerReturn",
+            cbor_fields: vec![
+                field("id_address", &create_miner_return.id_address),
+                field("robust_address", &create_miner_return.robust_address),
+            ],
+            sample: shape(&create_miner_return),
+        },
+        Conformance {
+            solidity: "PowerTypes.MinerRawPowerReturn",
+            rust: "fil_actor_power::MinerRawPowerReturn",
+            cbor_fields: vec![
+                field("raw_byte_power", &BigIntSer(&raw_power.raw_byte_power)),
+                field("meets_consensus_minimum", &raw_power.meets_consensus_minimum),
+            ],
+            sample: shape(&raw_power),
+        },
+        // VerifRegTypes
+        Conformance {
+            solidity: "VerifRegTypes.GetClaimsParams",
+            rust: "fil_actor_verifreg::GetClaimsParams",
+            cbor_fields: vec![
+                field("provider", &get_claims.provider),
+                field("claim_ids", &get_claims.claim_ids),
+            ],
+            sample: shape(&get_claims),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.GetClaimsReturn",
+            rust: "fil_actor_verifreg::GetClaimsReturn",
+            cbor_fields: vec![
+                field("batch_info", &get_claims_return.batch_info),
+                field("claims", &get_claims_return.claims),
+            ],
+            sample: shape(&get_claims_return),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.AddVerifiedClientParams",
+            rust: "fil_actor_verifreg::AddVerifiedClientParams",
+            cbor_fields: vec![
+                field("addr", &add_verified_client.address),
+                field("allowance", &add_verified_client.allowance),
+            ],
+            sample: shape(&add_verified_client),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.RemoveExpiredAllocationsParams",
+            rust: "fil_actor_verifreg::RemoveExpiredAllocationsParams",
+            cbor_fields: vec![
+                field("client", &remove_allocations.client),
+                field("allocation_ids", &remove_allocations.allocation_ids),
+            ],
+            sample: shape(&remove_allocations),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.RemoveExpiredAllocationsReturn",
+            rust: "fil_actor_verifreg::RemoveExpiredAllocationsReturn",
+            cbor_fields: vec![
+                field("considered", &remove_allocations_return.considered),
+                field("results", &remove_allocations_return.results),
+                field("datacap_recovered", &remove_allocations_return.datacap_recovered),
+            ],
+            sample: shape(&remove_allocations_return),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.RemoveExpiredClaimsParams",
+            rust: "fil_actor_verifreg::RemoveExpiredClaimsParams",
+            cbor_fields: vec![
+                field("provider", &remove_claims.provider),
+                field("claim_ids", &remove_claims.claim_ids),
+            ],
+            sample: shape(&remove_claims),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.RemoveExpiredClaimsReturn",
+            rust: "fil_actor_verifreg::RemoveExpiredClaimsReturn",
+            cbor_fields: vec![
+                field("considered", &remove_claims_return.considered),
+                field("results", &remove_claims_return.results),
+            ],
+            sample: shape(&remove_claims_return),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.ClaimTerm",
+            rust: "fil_actor_verifreg::ClaimTerm",
+            cbor_fields: vec![
+                field("provider", &claim_term.provider),
+                field("claim_id", &claim_term.claim_id),
+                field("term_max", &claim_term.term_max),
+            ],
+            sample: shape(&claim_term),
+        },
+        Conformance {
+            solidity: "VerifRegTypes.Claim",
+            rust: "fil_actor_verifreg::Claim",
+            cbor_fields: vec![
+                field("provider", &claim.provider),
+                field("client", &claim.client),
+                field("data", &claim.data),
+                field("size", &claim.size),
+                field("term_min", &claim.term_min),
+                field("term_max", &claim.term_max),
+                field("term_start", &claim.term_start),
+                field("sector", &claim.sector),
+            ],
+            sample: shape(&claim),
+        },
+        // DataCapTypes
+        Conformance {
+            solidity: "DataCapTypes.GetAllowanceParams",
+            rust: "frc46_token::token::types::GetAllowanceParams",
+            cbor_fields: vec![
+                field("owner", &get_allowance.owner),
+                field("operator", &get_allowance.operator),
+            ],
+            sample: shape(&get_allowance),
+        },
+        Conformance {
+            solidity: "DataCapTypes.TransferParams",
+            rust: "frc46_token::token::types::TransferParams",
+            cbor_fields: vec![
+                field("to", &transfer.to),
+                field("amount", &transfer.amount),
+                field("operator_data", &transfer.operator_data),
+            ],
+            sample: shape(&transfer),
+        },
+        Conformance {
+            solidity: "DataCapTypes.TransferReturn",
+            rust: "frc46_token::token::types::TransferReturn",
+            cbor_fields: vec![
+                field("from_balance", &transfer_return.from_balance),
+                field("to_balance", &transfer_return.to_balance),
+                field("recipient_data", &transfer_return.recipient_data),
+            ],
+            sample: shape(&transfer_return),
+        },
+        Conformance {
+            solidity: "DataCapTypes.TransferFromParams",
+            rust: "frc46_token::token::types::TransferFromParams",
+            cbor_fields: vec![
+                field("from", &transfer_from.from),
+                field("to", &transfer_from.to),
+                field("amount", &transfer_from.amount),
+                field("operator_data", &transfer_from.operator_data),
+            ],
+            sample: shape(&transfer_from),
+        },
+        Conformance {
+            solidity: "DataCapTypes.TransferFromReturn",
+            rust: "frc46_token::token::types::TransferFromReturn",
+            cbor_fields: vec![
+                field("from_balance", &transfer_from_return.from_balance),
+                field("to_balance", &transfer_from_return.to_balance),
+                field("allowance", &transfer_from_return.allowance),
+                field("recipient_data", &transfer_from_return.recipient_data),
+            ],
+            sample: shape(&transfer_from_return),
+        },
+        Conformance {
+            solidity: "DataCapTypes.IncreaseAllowanceParams",
+            rust: "frc46_token::token::types::IncreaseAllowanceParams",
+            cbor_fields: vec![
+                field("operator", &increase_allowance.operator),
+                field("increase", &increase_allowance.increase),
+            ],
+            sample: shape(&increase_allowance),
+        },
+        Conformance {
+            solidity: "DataCapTypes.DecreaseAllowanceParams",
+            rust: "frc46_token::token::types::DecreaseAllowanceParams",
+            cbor_fields: vec![
+                field("operator", &decrease_allowance.operator),
+                field("decrease", &decrease_allowance.decrease),
+            ],
+            sample: shape(&decrease_allowance),
+        },
+        Conformance {
+            solidity: "DataCapTypes.BurnFromParams",
+            rust: "frc46_token::token::types::BurnFromParams",
+            cbor_fields: vec![field("owner", &burn_from.owner), field("amount", &burn_from.amount)],
+            sample: shape(&burn_from),
+        },
+        Conformance {
+            solidity: "DataCapTypes.BurnFromReturn",
+            rust: "frc46_token::token::types::BurnFromReturn",
+            cbor_fields: vec![
+                field("balance", &burn_from_return.balance),
+                field("allowance", &burn_from_return.allowance),
+            ],
+            sample: shape(&burn_from_return),
+        },
+    ]
+}
@@ -1,7 +1,7 @@
 use fvm::executor::{ApplyKind, ApplyRet, Executor};
 use cid::Cid;
 use fvm::machine::Manifest;
-use fvm_ipld_blockstore::MemoryBlockstore;
+use fvm::state_tree::StateTree;
 use fvm_integration_tests::tester::Tester;
 use fvm_shared::state::StateTreeVersion;
 use fvm_shared::version::NetworkVersion;
@@ -9,17 +9,199 @@ use fvm_integration_tests::bundle;
 use fvm_ipld_encoding::CborStore;
 use fvm_integration_tests::dummy::DummyExterns;
 use fvm_ipld_encoding::tuple::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
 
-pub fn setup_tester() -> (Tester<MemoryBlockstore, DummyExterns>, Manifest) {
-    let bs = MemoryBlockstore::default();
-    let bundle_root = bundle::import_bundle(&bs, actors_v12::BUNDLE_CAR).unwrap();
+use crate::blockstore::{CowBlockstore, FrozenBlocks};
+use crate::genesis::{self, GenesisConfig};
+
+/// Path to a local actors bundle `.car` to run the suite against instead of the embedded one.
+pub const BUNDLE_PATH_ENV: &str = "FIL_ACTORS_BUNDLE";
+/// Network version used with the bundle, e.g. `21`.
+pub const NETWORK_VERSION_ENV: &str = "FIL_NETWORK_VERSION";
+/// State tree version used with the bundle, e.g. `5`.
+pub const STATE_TREE_VERSION_ENV: &str = "FIL_STATE_TREE_VERSION";
+
+#[derive(Clone, Debug)]
+pub enum BundleSource {
+    Bytes {
+        name: String,
+        car: &'static [u8],
+    },
+    Path(PathBuf),
+}
+
+impl BundleSource {
+    pub fn name(&self) -> String {
+        match self {
+            BundleSource::Bytes { name, .. } => name.clone(),
+            BundleSource::Path(path) => path.display().to_string(),
+        }
+    }
+
+    fn load(&self) -> Vec<u8> {
+        match self {
+            BundleSource::Bytes { car, .. } => car.to_vec(),
+            BundleSource::Path(path) => std::fs::read(path)
+                .unwrap_or_else(|e| panic!("couldn't read actors bundle {}: {}", path.display(), e)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TesterConfig {
+    pub bundle: BundleSource,
+    pub network_version: NetworkVersion,
+    pub state_tree_version: StateTreeVersion,
+    pub genesis: GenesisConfig,
+}
+
+impl Default for TesterConfig {
+    fn default() -> Self {
+        TesterConfig {
+            bundle: BundleSource::Bytes {
+                name: "builtin-actors v12.0.0".to_string(),
+                car: actors_v12::BUNDLE_CAR,
+            },
+            network_version: NetworkVersion::V21,
+            state_tree_version: StateTreeVersion::V5,
+            genesis: GenesisConfig::default(),
+        }
+    }
+}
+
+impl TesterConfig {
+    /// Default configuration, overridden by the `FIL_ACTORS_BUNDLE`, `FIL_NETWORK_VERSION`
+    /// and `FIL_STATE_TREE_VERSION` environment variables. Running the suite once per bundle
+    /// is done by setting them for each run, see `make test_integration_matrix`.
+    pub fn from_env() -> Self {
+        let mut config = TesterConfig::default();
+
+        if let Ok(path) = std::env::var(BUNDLE_PATH_ENV) {
+            config.bundle = BundleSource::Path(PathBuf::from(path));
+        }
+        if let Ok(nv) = std::env::var(NETWORK_VERSION_ENV) {
+            let nv: u32 = nv
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid {}: {}", NETWORK_VERSION_ENV, nv));
+            config.network_version = NetworkVersion::new(nv);
+        }
+        if let Ok(stv) = std::env::var(STATE_TREE_VERSION_ENV) {
+            config.state_tree_version = parse_state_tree_version(&stv);
+        }
+
+        config
+    }
+
+    pub fn with_genesis(self, genesis: GenesisConfig) -> Self {
+        TesterConfig { genesis, ..self }
+    }
+
+    /// Identifies the configuration in test output, e.g. `builtin-actors v12.0.0 (nv21, stv5)`.
+    pub fn label(&self) -> String {
+        format!(
+            "{} (nv{}, stv{})",
+            self.bundle.name(),
+            self.network_version,
+            self.state_tree_version as u64
+        )
+    }
+}
+
+fn parse_state_tree_version(version: &str) -> StateTreeVersion {
+    match version {
+        "0" => StateTreeVersion::V0,
+        "1" => StateTreeVersion::V1,
+        "2" => StateTreeVersion::V2,
+        "3" => StateTreeVersion::V3,
+        "4" => StateTreeVersion::V4,
+        "5" => StateTreeVersion::V5,
+        _ => panic!("invalid {}: {}", STATE_TREE_VERSION_ENV, version),
+    }
+}
+
+/// Imported bundle and flushed genesis state, built once per process for each configuration.
+pub struct BaseState {
+    pub bundle_root: Cid,
+    pub manifest_version: u32,
+    pub manifest_data_cid: Cid,
+    pub genesis_root: Cid,
+    pub blocks: FrozenBlocks,
+}
+
+static BASE_STATES: OnceLock<Mutex<HashMap<String, Arc<BaseState>>>> = OnceLock::new();
+
+/// Returns the cached base state for `config`, importing the bundle and running genesis
+/// the first time it is requested. Tests asking for it concurrently wait for that build.
+pub fn base_state(config: &TesterConfig) -> Arc<BaseState> {
+    let key = format!("{} {:?}", config.label(), config.genesis);
+    let mut base_states = BASE_STATES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    base_states
+        .entry(key)
+        .or_insert_with(|| Arc::new(build_base_state(config)))
+        .clone()
+}
+
+fn build_base_state(config: &TesterConfig) -> BaseState {
+    let bs = CowBlockstore::default();
+    let bundle_root = bundle::import_bundle(&bs, &config.bundle.load())
+        .unwrap_or_else(|e| panic!("couldn't import actors bundle {}: {}", config.label(), e));
 
     let (manifest_version, manifest_data_cid): (u32, Cid) =
         bs.get_cbor(&bundle_root).unwrap().unwrap();
     let manifest = Manifest::load(&bs, &manifest_data_cid, manifest_version).unwrap();
 
-    let tester =
-        Tester::new(NetworkVersion::V21, StateTreeVersion::V5, bundle_root, bs).unwrap();
+    let mut tester: Tester<CowBlockstore, DummyExterns> =
+        Tester::new(config.network_version, config.state_tree_version, bundle_root, bs.clone()).unwrap();
+
+    let state_tree = tester.state_tree.as_mut().unwrap();
+    genesis::install_genesis_actors(state_tree, &manifest, &config.genesis)
+        .unwrap_or_else(|e| panic!("genesis failed on actors bundle {}: {:#}", config.label(), e));
+    let genesis_root = state_tree.flush().unwrap();
+
+    let mut blocks = HashMap::new();
+    for layer in bs.freeze() {
+        blocks.extend(layer.iter().map(|(k, v)| (*k, v.clone())));
+    }
+
+    BaseState {
+        bundle_root,
+        manifest_version,
+        manifest_data_cid,
+        genesis_root,
+        blocks: Arc::new(blocks),
+    }
+}
+
+pub fn setup_tester(config: &TesterConfig) -> (Tester<CowBlockstore, DummyExterns>, Manifest) {
+    // Printed so that the output of a failing test says which bundle it ran against
+    println!("Using actors bundle {}", config.label());
+
+    let base = base_state(config);
+    let bs = CowBlockstore::new(base.blocks.clone());
+
+    setup_tester_from_root(config, bs, &base.genesis_root)
+}
+
+/// Tester whose state tree starts at `root`, which must be reachable from `bs`.
+pub fn setup_tester_from_root(
+    config: &TesterConfig,
+    bs: CowBlockstore,
+    root: &Cid,
+) -> (Tester<CowBlockstore, DummyExterns>, Manifest) {
+    let base = base_state(config);
+    let manifest = Manifest::load(&bs, &base.manifest_data_cid, base.manifest_version).unwrap();
+
+    // Tester::new writes a fresh system/init/EAM state tree, which is replaced right away
+    // by the one at `root`.
+    let mut tester =
+        Tester::new(config.network_version, config.state_tree_version, base.bundle_root, bs.clone()).unwrap();
+    tester.state_tree = Some(StateTree::new_from_root(bs, root).unwrap());
 
     return (tester, manifest)
 }
@@ -33,4 +215,4 @@ pub fn load_evm(path: &str) -> Vec<u8> {
     let evm_hex = std::fs::read(wasm_path).expect("Unable to read file");
 
     hex::decode(evm_hex).unwrap()
-}
\ No newline at end of file
+}
@@ -0,0 +1,204 @@
+use fil_actors_runtime::runtime::builtins;
+use fvm::gas::Gas;
+use fvm::trace::{ExecutionEvent, ExecutionTrace};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::Zero;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::{ActorID, MethodNum};
+use std::fmt::Write;
+
+use crate::methods;
+
+/// One call of the execution trace, with the calls it made.
+#[derive(Clone, Debug)]
+pub struct CallFrame {
+    pub from: ActorID,
+    pub to: Address,
+    pub method: MethodNum,
+    pub params_len: usize,
+    pub value: TokenAmount,
+    /// `None` if the call aborted with a syscall error, or the trace ended first.
+    pub exit_code: Option<ExitCode>,
+    /// Gas charged while this frame and its children ran.
+    pub gas: Gas,
+    pub children: Vec<CallFrame>,
+}
+
+/// Name and `builtins::Type` of an actor, `actor_type` is `None` if it is not a builtin.
+#[derive(Clone, Debug)]
+pub struct ActorInfo {
+    pub name: String,
+    pub actor_type: Option<u32>,
+}
+
+/// Rebuilds the call tree from the flat `Call`/`CallReturn` events of the trace.
+pub fn call_tree(trace: &ExecutionTrace) -> Vec<CallFrame> {
+    let mut roots = vec![];
+    let mut stack: Vec<CallFrame> = vec![];
+
+    for event in trace {
+        match event {
+            ExecutionEvent::Call {
+                from,
+                to,
+                method,
+                params,
+                value,
+                ..
+            } => stack.push(CallFrame {
+                from: *from,
+                to: *to,
+                method: *method,
+                params_len: params.as_ref().map_or(0, |params| params.data.len()),
+                value: value.clone(),
+                exit_code: None,
+                gas: Gas::new(0),
+                children: vec![],
+            }),
+            ExecutionEvent::CallReturn(exit_code, _) => {
+                if let Some(mut frame) = stack.pop() {
+                    frame.exit_code = Some(*exit_code);
+                    close_frame(frame, &mut stack, &mut roots);
+                }
+            }
+            ExecutionEvent::CallError(_) => {
+                if let Some(frame) = stack.pop() {
+                    close_frame(frame, &mut stack, &mut roots);
+                }
+            }
+            ExecutionEvent::GasCharge(charge) => {
+                if let Some(frame) = stack.last_mut() {
+                    frame.gas += charge.total();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Calls that never returned, e.g. when running out of gas
+    while let Some(frame) = stack.pop() {
+        close_frame(frame, &mut stack, &mut roots);
+    }
+
+    roots
+}
+
+fn close_frame(frame: CallFrame, stack: &mut [CallFrame], roots: &mut Vec<CallFrame>) {
+    match stack.last_mut() {
+        Some(parent) => {
+            parent.gas += frame.gas;
+            parent.children.push(frame);
+        }
+        None => roots.push(frame),
+    }
+}
+
+/// Name of the singleton actors, e.g. `StorageMarket`, and of the other builtin actors with
+/// their ID, e.g. `EVM contract #1001`.
+pub fn actor_name(actor_id: Option<ActorID>, actor_type: Option<u32>) -> String {
+    let singleton = match actor_id {
+        Some(0) => Some("System"),
+        Some(1) => Some("Init"),
+        Some(2) => Some("Reward"),
+        Some(3) => Some("Cron"),
+        Some(4) => Some("StoragePower"),
+        Some(5) => Some("StorageMarket"),
+        Some(6) => Some("VerifiedRegistry"),
+        Some(7) => Some("DataCap"),
+        Some(10) => Some("EAM"),
+        Some(99) => Some("BurntFunds"),
+        _ => None,
+    };
+    if let Some(name) = singleton {
+        return name.to_string();
+    }
+
+    let types: &[(builtins::Type, &str)] = &[
+        (builtins::Type::Account, "Account"),
+        (builtins::Type::Miner, "StorageMiner"),
+        (builtins::Type::PaymentChannel, "PaymentChannel"),
+        (builtins::Type::Multisig, "Multisig"),
+        (builtins::Type::Placeholder, "Placeholder"),
+        (builtins::Type::EVM, "EVM contract"),
+        (builtins::Type::EthAccount, "EthAccount"),
+    ];
+    let type_name = actor_type
+        .and_then(|actor_type| types.iter().find(|(t, _)| *t as u32 == actor_type))
+        .map_or("Actor", |(_, name)| *name);
+
+    match actor_id {
+        Some(id) => format!("{} #{}", type_name, id),
+        None => type_name.to_string(),
+    }
+}
+
+/// Renders the call tree, one line per call with the exit code and gas of the frame, e.g.
+///
+/// ```text
+/// Account #100 -> EVM contract #1001 InvokeContract (196 B) exit 0, gas 5821733
+/// └─ EVM contract #1001 -> StorageMarket GetDealClient (2 B) exit 0, gas 1862309
+/// ```
+pub fn render(frames: &[CallFrame], mut resolve: impl FnMut(&Address) -> ActorInfo) -> String {
+    let mut out = String::new();
+    for frame in frames {
+        render_frame(&mut out, frame, "", None, &mut resolve);
+    }
+
+    out
+}
+
+fn render_frame(
+    out: &mut String,
+    frame: &CallFrame,
+    indent: &str,
+    last: Option<bool>,
+    resolve: &mut impl FnMut(&Address) -> ActorInfo,
+) {
+    let from = resolve(&Address::new_id(frame.from));
+    let to = resolve(&frame.to);
+
+    let method = match methods::method_name(to.actor_type, frame.method) {
+        Some(name) => name.to_string(),
+        None => format!("method {}", frame.method),
+    };
+    let exit_code = match frame.exit_code {
+        Some(exit_code) => format!("exit {}", exit_code.value()),
+        None => "aborted".to_string(),
+    };
+
+    let branch = match last {
+        None => "",
+        Some(true) => "└─ ",
+        Some(false) => "├─ ",
+    };
+    let value = match frame.value.is_zero() {
+        true => String::new(),
+        false => format!(" value {}", frame.value),
+    };
+
+    writeln!(
+        out,
+        "{}{}{} -> {} {} ({} B){} {}, gas {}",
+        indent,
+        branch,
+        from.name,
+        to.name,
+        method,
+        frame.params_len,
+        value,
+        exit_code,
+        frame.gas.round_down()
+    )
+    .unwrap();
+
+    let child_indent = match last {
+        None => indent.to_string(),
+        Some(true) => format!("{}   ", indent),
+        Some(false) => format!("{}│  ", indent),
+    };
+    for (i, child) in frame.children.iter().enumerate() {
+        let last_child = i + 1 == frame.children.len();
+        render_frame(out, child, &child_indent, Some(last_child), resolve);
+    }
+}
@@ -0,0 +1,690 @@
+use anyhow::{bail, Context, Result};
+use cid::Cid;
+use fil_actors_runtime::{BatchReturn, FailCode};
+use fvm_ipld_encoding::{BytesDe, BytesSer, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::BigInt;
+use fvm_shared::crypto::signature::Signature;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::piece::PaddedPieceSize;
+use fvm_shared::sector::RegisteredPoStProof;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Version of the fixture layout, bumped whenever a field of `Vector` or `VectorFile` changes
+/// meaning. Consumers refuse fixtures of another version.
+pub const VECTORS_VERSION: u32 = 1;
+
+/// Release of builtin-actors the values are encoded with.
+pub const BUILTIN_ACTORS_VERSION: &str = "v12.0.0";
+
+const VECTORS_PATH: &str = "vectors/cbor_vectors.json";
+
+const SAMPLE_CID: &str = "baga6ea4seaqlkg6mss5qs56jqtajg5ycrhpkj2b66cgdkukf2qjmmzz6ayksuci";
+
+/// CBOR encoding of one value of a builtin-actors type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vector {
+    /// Unique name, `<type>/<case>`, e.g. `GetVestingFundsReturn/empty`.
+    pub name: String,
+    /// Rust type the value was built with, e.g. `fil_actor_miner::GetVestingFundsReturn`.
+    pub rust_type: String,
+    /// Solidity library function handling that type, e.g.
+    /// `MinerCBOR.deserializeGetVestingFundsReturn`.
+    pub solidity: String,
+    /// Hex of the CBOR encoding, without `0x`.
+    pub cbor: String,
+}
+
+impl Vector {
+    pub fn bytes(&self) -> Vec<u8> {
+        hex::decode(&self.cbor).unwrap()
+    }
+
+    /// Name of the Solidity function without its library, e.g. `deserializeGetBalanceReturn`.
+    pub fn function(&self) -> &str {
+        self.solidity.rsplit('.').next().unwrap()
+    }
+
+    pub fn is_deserializer(&self) -> bool {
+        self.function().starts_with("deserialize")
+    }
+}
+
+/// The fixture file shared by the Rust and Solidity tests.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorFile {
+    pub version: u32,
+    pub builtin_actors: String,
+    pub vectors: Vec<Vector>,
+}
+
+impl VectorFile {
+    pub fn get(&self, name: &str) -> Option<&Vector> {
+        self.vectors.iter().find(|vector| vector.name == name)
+    }
+}
+
+#[derive(Default)]
+struct Vectors(Vec<Vector>);
+
+impl Vectors {
+    fn add<T: Serialize>(&mut self, rust_type: &str, solidity: &str, case: &str, value: &T) {
+        let type_name = rust_type.rsplit("::").next().unwrap();
+        self.0.push(Vector {
+            name: format!("{}/{}", type_name, case),
+            rust_type: rust_type.to_string(),
+            solidity: solidity.to_string(),
+            cbor: hex::encode(fvm_ipld_encoding::to_vec(value).unwrap()),
+        });
+    }
+}
+
+/// Representative and edge-case values of every param and return type the Solidity CBOR
+/// libraries handle, encoded with the builtin-actors types.
+pub fn vectors() -> VectorFile {
+    let id_address = Address::new_id(1000);
+    let actor_address = Address::new_actor(b"miner");
+    let delegated_address = Address::new_delegated(10, &[0xff; 20]).unwrap();
+    let cid = Cid::from_str(SAMPLE_CID).unwrap();
+    let fil = TokenAmount::from_whole(1);
+    let zero = TokenAmount::from_atto(0);
+
+    let batch_failures = BatchReturn {
+        success_count: 1,
+        fail_codes: vec![FailCode {
+            idx: 1,
+            code: ExitCode::USR_NOT_FOUND,
+        }],
+    };
+
+    let mut v = Vectors::default();
+
+    // Market
+    v.add(
+        "fil_actor_market::WithdrawBalanceParams",
+        "MarketCBOR.serializeWithdrawBalanceParams",
+        "typical",
+        &fil_actor_market::WithdrawBalanceParams {
+            provider_or_client: id_address,
+            amount: fil.clone(),
+        },
+    );
+    v.add(
+        "fil_actor_market::GetBalanceReturn",
+        "MarketCBOR.deserializeGetBalanceReturn",
+        "typical",
+        &fil_actor_market::GetBalanceReturn {
+            balance: TokenAmount::from_whole(10),
+            locked: TokenAmount::from_atto(5000),
+        },
+    );
+    v.add(
+        "fil_actor_market::GetBalanceReturn",
+        "MarketCBOR.deserializeGetBalanceReturn",
+        "zero",
+        &fil_actor_market::GetBalanceReturn {
+            balance: zero.clone(),
+            locked: zero.clone(),
+        },
+    );
+    v.add(
+        "fil_actor_market::GetDealDataCommitmentReturn",
+        "MarketCBOR.deserializeGetDealDataCommitmentReturn",
+        "typical",
+        &fil_actor_market::GetDealDataCommitmentReturn {
+            data: cid,
+            size: PaddedPieceSize(2048),
+        },
+    );
+    v.add(
+        "fil_actor_market::GetDealDataCommitmentReturn",
+        "MarketCBOR.deserializeGetDealDataCommitmentReturn",
+        "64gib",
+        &fil_actor_market::GetDealDataCommitmentReturn {
+            data: cid,
+            size: PaddedPieceSize(64 << 30),
+        },
+    );
+    v.add(
+        "fil_actor_market::GetDealTermReturn",
+        "MarketCBOR.deserializeGetDealTermReturn",
+        "typical",
+        &fil_actor_market::GetDealTermReturn {
+            start: 1000,
+            duration: 518400,
+        },
+    );
+    v.add(
+        "fil_actor_market::GetDealActivationReturn",
+        "MarketCBOR.deserializeGetDealActivationReturn",
+        "active",
+        &fil_actor_market::GetDealActivationReturn {
+            activated: 1000,
+            terminated: -1,
+        },
+    );
+    v.add(
+        "fil_actor_market::GetDealActivationReturn",
+        "MarketCBOR.deserializeGetDealActivationReturn",
+        "not_activated",
+        &fil_actor_market::GetDealActivationReturn {
+            activated: -1,
+            terminated: -1,
+        },
+    );
+
+    let deal_proposal = |label: fil_actor_market::Label| fil_actor_market::DealProposal {
+        piece_cid: cid,
+        piece_size: PaddedPieceSize(2048),
+        verified_deal: false,
+        client: id_address,
+        provider: Address::new_id(1001),
+        label,
+        start_epoch: 1000,
+        end_epoch: 519400,
+        storage_price_per_epoch: TokenAmount::from_atto(100),
+        provider_collateral: fil.clone(),
+        client_collateral: zero.clone(),
+    };
+    let string_label = deal_proposal(fil_actor_market::Label::String("label".to_string()));
+    v.add(
+        "fil_actor_market::DealProposal",
+        "MarketCBOR.deserializeDealProposal",
+        "string_label",
+        &string_label,
+    );
+    v.add(
+        "fil_actor_market::DealProposal",
+        "MarketCBOR.deserializeDealProposal",
+        "bytes_label",
+        &deal_proposal(fil_actor_market::Label::Bytes(vec![0xde, 0xad, 0xbe, 0xef])),
+    );
+    v.add(
+        "fil_actor_market::DealProposal",
+        "MarketCBOR.deserializeDealProposal",
+        "empty_label",
+        &deal_proposal(fil_actor_market::Label::String(String::new())),
+    );
+    v.add(
+        "fil_actor_market::PublishStorageDealsParams",
+        "MarketCBOR.serializePublishStorageDealsParams",
+        "one_deal",
+        &fil_actor_market::PublishStorageDealsParams {
+            deals: vec![fil_actor_market::ClientDealProposal {
+                proposal: string_label.clone(),
+                client_signature: Signature::new_bls(vec![1; 96]),
+            }],
+        },
+    );
+    // RLE+ of the bitfield {0}: version 00, first run of ones, run length 1
+    let valid_deals =
+        fvm_ipld_encoding::from_slice(&fvm_ipld_encoding::to_vec(&BytesSer(&[0x0c])).unwrap())
+            .unwrap();
+    v.add(
+        "fil_actor_market::PublishStorageDealsReturn",
+        "MarketCBOR.deserializePublishStorageDealsReturn",
+        "one_deal",
+        &fil_actor_market::PublishStorageDealsReturn {
+            ids: vec![1000],
+            valid_deals,
+        },
+    );
+    v.add(
+        "fil_actor_market::MarketNotifyDealParams",
+        "MarketCBOR.deserializeMarketDealNotifyParams",
+        "typical",
+        &fil_actor_market::MarketNotifyDealParams {
+            proposal: fvm_ipld_encoding::to_vec(&string_label).unwrap(),
+            deal_id: 1000,
+        },
+    );
+
+    // Miner
+    v.add(
+        "fil_actor_miner::GetOwnerReturn",
+        "MinerCBOR.deserializeGetOwnerReturn",
+        "no_proposed",
+        &fil_actor_miner::GetOwnerReturn {
+            owner: id_address,
+            proposed: None,
+        },
+    );
+    v.add(
+        "fil_actor_miner::GetOwnerReturn",
+        "MinerCBOR.deserializeGetOwnerReturn",
+        "proposed",
+        &fil_actor_miner::GetOwnerReturn {
+            owner: id_address,
+            proposed: Some(delegated_address),
+        },
+    );
+    v.add(
+        "fil_actor_miner::ChangeBeneficiaryParams",
+        "MinerCBOR.serializeChangeBeneficiaryParams",
+        "typical",
+        &fil_actor_miner::ChangeBeneficiaryParams {
+            new_beneficiary: delegated_address,
+            new_quota: fil.clone(),
+            new_expiration: 519400,
+        },
+    );
+    let active = fil_actor_miner::ActiveBeneficiary {
+        beneficiary: id_address,
+        term: fil_actor_miner::BeneficiaryTerm {
+            quota: zero.clone(),
+            used_quota: zero.clone(),
+            expiration: 0,
+        },
+    };
+    v.add(
+        "fil_actor_miner::GetBeneficiaryReturn",
+        "MinerCBOR.deserializeGetBeneficiaryReturn",
+        "no_proposed",
+        &fil_actor_miner::GetBeneficiaryReturn {
+            active: active.clone(),
+            proposed: None,
+        },
+    );
+    v.add(
+        "fil_actor_miner::GetBeneficiaryReturn",
+        "MinerCBOR.deserializeGetBeneficiaryReturn",
+        "proposed",
+        &fil_actor_miner::GetBeneficiaryReturn {
+            active,
+            proposed: Some(fil_actor_miner::PendingBeneficiaryChange {
+                new_beneficiary: delegated_address,
+                new_quota: fil.clone(),
+                new_expiration: 519400,
+                approved_by_beneficiary: true,
+                approved_by_nominee: false,
+            }),
+        },
+    );
+    v.add(
+        "fil_actor_miner::GetVestingFundsReturn",
+        "MinerCBOR.deserializeGetVestingFundsReturn",
+        "default",
+        &fil_actor_miner::GetVestingFundsReturn {
+            vesting_funds: vec![(0, zero.clone())],
+        },
+    );
+    v.add(
+        "fil_actor_miner::GetVestingFundsReturn",
+        "MinerCBOR.deserializeGetVestingFundsReturn",
+        "empty",
+        &fil_actor_miner::GetVestingFundsReturn { vesting_funds: vec![] },
+    );
+    v.add(
+        "fil_actor_miner::GetVestingFundsReturn",
+        "MinerCBOR.deserializeGetVestingFundsReturn",
+        "several",
+        &fil_actor_miner::GetVestingFundsReturn {
+            vesting_funds: vec![(100, fil.clone()), (200, TokenAmount::from_whole(2))],
+        },
+    );
+    v.add(
+        "fil_actor_miner::ChangeWorkerAddressParams",
+        "MinerCBOR.serializeChangeWorkerAddressParams",
+        "one_control",
+        &fil_actor_miner::ChangeWorkerAddressParams {
+            new_worker: Address::new_id(1002),
+            new_control_addresses: vec![Address::new_id(1003)],
+        },
+    );
+    v.add(
+        "fil_actor_miner::ChangeWorkerAddressParams",
+        "MinerCBOR.serializeChangeWorkerAddressParams",
+        "no_control",
+        &fil_actor_miner::ChangeWorkerAddressParams {
+            new_worker: Address::new_id(1002),
+            new_control_addresses: vec![],
+        },
+    );
+    v.add(
+        "fil_actor_miner::ChangeMultiaddrsParams",
+        "MinerCBOR.serializeChangeMultiaddrsParams",
+        "two",
+        &fil_actor_miner::ChangeMultiaddrsParams {
+            new_multi_addrs: vec![BytesDe(vec![0x04, 127, 0, 0, 1]), BytesDe(vec![0x29])],
+        },
+    );
+    v.add(
+        "fil_actor_miner::GetMultiaddrsReturn",
+        "MinerCBOR.deserializeGetMultiaddrsReturn",
+        "empty",
+        &fil_actor_miner::GetMultiaddrsReturn { multi_addrs: vec![] },
+    );
+    v.add(
+        "fil_actor_miner::GetMultiaddrsReturn",
+        "MinerCBOR.deserializeGetMultiaddrsReturn",
+        "two",
+        &fil_actor_miner::GetMultiaddrsReturn {
+            multi_addrs: vec![BytesDe(vec![0x04, 127, 0, 0, 1]), BytesDe(vec![0x29])],
+        },
+    );
+
+    // Power
+    v.add(
+        "fil_actor_power::CreateMinerParams",
+        "PowerCBOR.serializeCreateMinerParams",
+        "typical",
+        &fil_actor_power::CreateMinerParams {
+            owner: id_address,
+            worker: id_address,
+            window_post_proof_type: RegisteredPoStProof::StackedDRGWindow32GiBV1P1,
+            peer: vec![1, 2, 3],
+            multiaddrs: vec![BytesDe(vec![0x04, 127, 0, 0, 1])],
+        },
+    );
+    v.add(
+        "fil_actor_power::CreateMinerReturn",
+        "PowerCBOR.deserializeCreateMinerReturn",
+        "typical",
+        &fil_actor_power::CreateMinerReturn {
+            id_address: Address::new_id(1004),
+            robust_address: actor_address,
+        },
+    );
+    v.add(
+        "fil_actor_power::MinerRawPowerReturn",
+        "PowerCBOR.deserializeMinerRawPowerReturn",
+        "zero",
+        &fil_actor_power::MinerRawPowerReturn {
+            raw_byte_power: BigInt::from(0),
+            meets_consensus_minimum: false,
+        },
+    );
+    v.add(
+        "fil_actor_power::MinerRawPowerReturn",
+        "PowerCBOR.deserializeMinerRawPowerReturn",
+        "u128_max",
+        &fil_actor_power::MinerRawPowerReturn {
+            raw_byte_power: BigInt::from(u128::MAX),
+            meets_consensus_minimum: true,
+        },
+    );
+
+    // Verified registry
+    let claim = fil_actor_verifreg::Claim {
+        provider: 1001,
+        client: 1000,
+        data: cid,
+        size: PaddedPieceSize(2048),
+        term_min: 518400,
+        term_max: 1555200,
+        term_start: 1000,
+        sector: 7,
+    };
+    v.add(
+        "fil_actor_verifreg::GetClaimsParams",
+        "VerifRegCBOR.serializeGetClaimsParams",
+        "typical",
+        &fil_actor_verifreg::GetClaimsParams {
+            provider: 1001,
+            claim_ids: vec![1, 2],
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::GetClaimsReturn",
+        "VerifRegCBOR.deserializeGetClaimsReturn",
+        "empty",
+        &fil_actor_verifreg::GetClaimsReturn {
+            batch_info: BatchReturn {
+                success_count: 0,
+                fail_codes: vec![],
+            },
+            claims: vec![],
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::GetClaimsReturn",
+        "VerifRegCBOR.deserializeGetClaimsReturn",
+        "one_claim",
+        &fil_actor_verifreg::GetClaimsReturn {
+            batch_info: batch_failures.clone(),
+            claims: vec![claim],
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::AddVerifiedClientParams",
+        "VerifRegCBOR.serializeAddVerifiedClientParams",
+        "typical",
+        &fil_actor_verifreg::AddVerifiedClientParams {
+            address: delegated_address,
+            allowance: TokenAmount::from_whole(2048),
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::RemoveExpiredAllocationsParams",
+        "VerifRegCBOR.serializeRemoveExpiredAllocationsParams",
+        "typical",
+        &fil_actor_verifreg::RemoveExpiredAllocationsParams {
+            client: 1000,
+            allocation_ids: vec![1, 2],
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::RemoveExpiredAllocationsParams",
+        "VerifRegCBOR.serializeRemoveExpiredAllocationsParams",
+        "all",
+        &fil_actor_verifreg::RemoveExpiredAllocationsParams {
+            client: 1000,
+            allocation_ids: vec![],
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::RemoveExpiredAllocationsReturn",
+        "VerifRegCBOR.deserializeRemoveExpiredAllocationsReturn",
+        "typical",
+        &fil_actor_verifreg::RemoveExpiredAllocationsReturn {
+            considered: vec![1, 2],
+            results: batch_failures.clone(),
+            datacap_recovered: TokenAmount::from_whole(2048),
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::RemoveExpiredClaimsParams",
+        "VerifRegCBOR.serializeRemoveExpiredClaimsParams",
+        "typical",
+        &fil_actor_verifreg::RemoveExpiredClaimsParams {
+            provider: 1001,
+            claim_ids: vec![1, 2],
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::RemoveExpiredClaimsReturn",
+        "VerifRegCBOR.deserializeRemoveExpiredClaimsReturn",
+        "typical",
+        &fil_actor_verifreg::RemoveExpiredClaimsReturn {
+            considered: vec![1, 2],
+            results: batch_failures.clone(),
+        },
+    );
+    v.add(
+        "fil_actor_verifreg::ExtendClaimTermsParams",
+        "VerifRegCBOR.serializeExtendClaimTermsParams",
+        "one_term",
+        &fil_actor_verifreg::ExtendClaimTermsParams {
+            terms: vec![fil_actor_verifreg::ClaimTerm {
+                provider: 1001,
+                claim_id: 1,
+                term_max: 3110400,
+            }],
+        },
+    );
+    v.add(
+        "fil_actors_runtime::BatchReturn",
+        "VerifRegCBOR.deserializeBatchReturn",
+        "all_ok",
+        &BatchReturn {
+            success_count: 2,
+            fail_codes: vec![],
+        },
+    );
+    v.add(
+        "fil_actors_runtime::BatchReturn",
+        "VerifRegCBOR.deserializeBatchReturn",
+        "with_failures",
+        &batch_failures,
+    );
+
+    // DataCap
+    v.add(
+        "frc46_token::token::types::GetAllowanceParams",
+        "DataCapCBOR.serializeGetAllowanceParams",
+        "typical",
+        &frc46_token::token::types::GetAllowanceParams {
+            owner: id_address,
+            operator: delegated_address,
+        },
+    );
+    v.add(
+        "frc46_token::token::types::TransferParams",
+        "DataCapCBOR.serializeTransferParams",
+        "typical",
+        &frc46_token::token::types::TransferParams {
+            to: Address::new_id(6),
+            amount: TokenAmount::from_whole(2),
+            operator_data: RawBytes::default(),
+        },
+    );
+    v.add(
+        "frc46_token::token::types::TransferReturn",
+        "DataCapCBOR.deserializeTransferReturn",
+        "typical",
+        &frc46_token::token::types::TransferReturn {
+            from_balance: TokenAmount::from_whole(8),
+            to_balance: TokenAmount::from_whole(2),
+            recipient_data: RawBytes::default(),
+        },
+    );
+    v.add(
+        "frc46_token::token::types::TransferFromParams",
+        "DataCapCBOR.serializeTransferFromParams",
+        "typical",
+        &frc46_token::token::types::TransferFromParams {
+            from: id_address,
+            to: Address::new_id(6),
+            amount: TokenAmount::from_whole(2),
+            operator_data: RawBytes::new(vec![0, 1, 2]),
+        },
+    );
+    v.add(
+        "frc46_token::token::types::TransferFromReturn",
+        "DataCapCBOR.deserializeTransferFromReturn",
+        "typical",
+        &frc46_token::token::types::TransferFromReturn {
+            from_balance: TokenAmount::from_whole(8),
+            to_balance: TokenAmount::from_whole(2),
+            allowance: zero.clone(),
+            recipient_data: RawBytes::new(vec![0, 1, 2]),
+        },
+    );
+    v.add(
+        "frc46_token::token::types::IncreaseAllowanceParams",
+        "DataCapCBOR.serializeIncreaseAllowanceParams",
+        "typical",
+        &frc46_token::token::types::IncreaseAllowanceParams {
+            operator: delegated_address,
+            increase: fil.clone(),
+        },
+    );
+    v.add(
+        "frc46_token::token::types::DecreaseAllowanceParams",
+        "DataCapCBOR.serializeDecreaseAllowanceParams",
+        "typical",
+        &frc46_token::token::types::DecreaseAllowanceParams {
+            operator: delegated_address,
+            decrease: fil.clone(),
+        },
+    );
+    v.add(
+        "frc46_token::token::types::BurnFromParams",
+        "DataCapCBOR.serializeBurnFromParams",
+        "typical",
+        &frc46_token::token::types::BurnFromParams {
+            owner: id_address,
+            amount: fil.clone(),
+        },
+    );
+    v.add(
+        "frc46_token::token::types::BurnFromReturn",
+        "DataCapCBOR.deserializeBurnFromReturn",
+        "typical",
+        &frc46_token::token::types::BurnFromReturn {
+            balance: TokenAmount::from_whole(9),
+            allowance: zero,
+        },
+    );
+
+    // Common
+    v.add(
+        "fvm_actor_utils::receiver::UniversalReceiverParams",
+        "FilecoinCBOR.deserializeUniversalReceiverParams",
+        "frc46",
+        &fvm_actor_utils::receiver::UniversalReceiverParams {
+            type_: 0x85223bdf,
+            payload: RawBytes::new(vec![0, 1, 2]),
+        },
+    );
+
+    VectorFile {
+        version: VECTORS_VERSION,
+        builtin_actors: BUILTIN_ACTORS_VERSION.to_string(),
+        vectors: v.0,
+    }
+}
+
+/// Pretty JSON of `vectors()`, as checked in.
+pub fn render() -> String {
+    let mut json = serde_json::to_string_pretty(&vectors()).unwrap();
+    json.push('\n');
+    json
+}
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(VECTORS_PATH)
+}
+
+/// Reads a fixture file, failing on any other layout version.
+pub fn load(path: &Path) -> Result<VectorFile> {
+    let json = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let file: VectorFile =
+        serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))?;
+    if file.version != VECTORS_VERSION {
+        bail!(
+            "{} has version {}, expected {}",
+            path.display(),
+            file.version,
+            VECTORS_VERSION
+        );
+    }
+
+    Ok(file)
+}
+
+/// Writes the fixture file, returning whether it changed.
+pub fn generate() -> Result<bool> {
+    let path = default_path();
+    let json = render();
+    if std::fs::read_to_string(&path).ok().as_deref() == Some(json.as_str()) {
+        return Ok(false);
+    }
+
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, json).with_context(|| format!("writing {}", path.display()))?;
+    Ok(true)
+}
+
+/// Fails if the checked-in fixture differs from what `generate` would write.
+pub fn check() -> Result<()> {
+    let path = default_path();
+    if std::fs::read_to_string(&path).ok().as_deref() != Some(render().as_str()) {
+        bail!("{} is out of date, run `cargo run --example vectors`", path.display());
+    }
+
+    Ok(())
+}
@@ -1,48 +1,16 @@
 use bls_signatures::Serialize;
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::EAM_ACTOR_ADDR;
-use fvm::executor::{ApplyKind, Executor};
 use fvm::state_tree::ActorState;
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
-use fvm_ipld_encoding::serde_bytes;
-use fvm_ipld_encoding::strict_bytes;
-use fvm_ipld_encoding::tuple::*;
 use fvm_ipld_encoding::CborStore;
-use fvm_ipld_encoding::RawBytes;
 use fvm_shared::address::Address;
 use fvm_shared::econ::TokenAmount;
-use fvm_shared::message::Message;
 use multihash::Code;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-use alloy_sol_types::{SolCall};
 
-use testing::{setup, api_contracts};
+use testing::bindings;
+use testing::env::TestEnv;
 use testing::GasResult;
 use testing::parse_gas;
 
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/AccountApiTest.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
-
-#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
-pub struct AuthenticateMessageParams {
-    #[serde(with = "serde_bytes")]
-    pub signature: Vec<u8>,
-    #[serde(with = "serde_bytes")]
-    pub message: Vec<u8>,
-}
-
-#[derive(Serialize_tuple, Deserialize_tuple, PartialEq, Eq, Clone, Debug)]
-pub struct UniversalReceiverParams {
-    /// Asset type
-    pub type_: u32,
-    /// Payload corresponding to asset type
-    pub payload: RawBytes,
-}
+const CONTRACT_NAME: &str = "AccountApiTest";
 
 #[test]
 fn account_tests() {
@@ -50,9 +18,7 @@ fn account_tests() {
 
     let mut gas_result: GasResult = vec![];
 
-    let (mut tester, manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
+    let mut env = TestEnv::new();
 
     // Instantiate Account Actor with a BLS address (not secure private key! Only use for tests!)
     let bls_private_key_provider = bls_signatures::PrivateKey::new(
@@ -60,7 +26,8 @@ fn account_tests() {
     );
     let worker = Address::new_bls(&bls_private_key_provider.public_key().as_bytes()).unwrap();
 
-    let state_tree = tester.state_tree.as_mut().unwrap();
+    let account_code = *env.manifest.get_account_code();
+    let state_tree = env.state_tree_mut();
     let assigned_addr = state_tree.register_new_address(&worker).unwrap();
     let state = fvm::account_actor::State { address: worker };
 
@@ -70,7 +37,7 @@ fn account_tests() {
         .unwrap();
 
     let actor_state = ActorState {
-        code: *manifest.get_account_code(),
+        code: account_code,
         state: cid,
         sequence: 0,
         balance: TokenAmount::from_whole(1_000_000),
@@ -79,74 +46,26 @@ fn account_tests() {
 
     state_tree.set_actor(assigned_addr, actor_state);
 
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-    let actor_id = exec_return.actor_id;
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
     println!("Calling `authenticate_message`");
 
     let message = hex::decode("8bd82a5828000181e203922020b51bcc94bb0977c984c093770289dea4e83ef08c355145d412c6673e06152a091a00800000f45831039216096d4dbc72ebdb22d0c8bd4af3f40e4396592200a95300d7ce319fb740048e01eece7a181cdad6fbe25374a9cb4542006778346d41584367354149673859425862466a7464427931695a6a704459417752537430656c474c463547765471756c4569693156634d19629d1a0008517e470001001d1bf8004800038d7ea4c680004800038d7ea4c68000").unwrap();
 
     let sig = bls_private_key_provider.sign(&message);
-    dbg!(hex::encode(sig.as_bytes()));
-    dbg!(hex::encode(worker.to_bytes()));
 
-    let params = api_contracts::account_test::AuthenticateMessageParams{
-        message: message,
-        signature: sig.as_bytes()
+    // target: 101 (assigned_addr actor id)
+    let call = bindings::AccountApiTest::authenticate_messageCall{
+        target: assigned_addr,
+        params: bindings::AuthenticateMessageParams{
+            message,
+            signature: sig.as_bytes()
+        }
     };
-    let abi_encoded_call = api_contracts::account_test::authenticate_messageCall{
-        target: 101_u64, 
-        params: params
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    dbg!(cbor_encoded.as_str());
-
-    // create message
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            // target: 101(assigned_addr actor id), params: ["0x8eabea2a4001061ac4c9fe3c517725b8829b159149a863b2a2320cc628d026a871d3cb34947371f384a9eb49ff9bd56a019fa70e10c06ac5ca93df3c1d6f54d540c57cbe2f5209cafdc12146d5d59172dd4d8359015e10584fa6327de0ce5a6a", "0x8bd82a5828000181e203922020b51bcc94bb0977c984c093770289dea4e83ef08c355145d412c6673e06152a091a00800000f45831039216096d4dbc72ebdb22d0c8bd4af3f40e4396592200a95300d7ce319fb740048e01eece7a181cdad6fbe25374a9cb4542006778346d41584367354149673859425862466a7464427931695a6a704459417752537430656c474c463547765471756c4569693156634d19629d1a0008517e470001001d1bf8004800038d7ea4c680004800038d7ea4c68000"]
-            params: RawBytes::new(hex::decode(
-                // "590204F5C62E8700000000000000000000000000000000000000000000000000000000000000650000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000C000000000000000000000000000000000000000000000000000000000000000608EABEA2A4001061AC4C9FE3C517725B8829B159149A863B2A2320CC628D026A871D3CB34947371F384A9EB49FF9BD56A019FA70E10C06AC5CA93DF3C1D6F54D540C57CBE2F5209CAFDC12146D5D59172DD4D8359015E10584FA6327DE0CE5A6A00000000000000000000000000000000000000000000000000000000000000C18BD82A5828000181E203922020B51BCC94BB0977C984C093770289DEA4E83EF08C355145D412C6673E06152A091A00800000F45831039216096D4DBC72EBDB22D0C8BD4AF3F40E4396592200A95300D7CE319FB740048E01EECE7A181CDAD6FBE25374A9CB4542006778346D41584367354149673859425862466A7464427931695A6A704459417752537430656C474C463547765471756C4569693156634D19629D1A0008517E470001001D1BF8004800038D7EA4C680004800038D7EA4C6800000000000000000000000000000000000000000000000000000000000000000"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+
+    let res = env.invoke(&contract, &call);
 
     let gas_used = parse_gas(res.exec_trace);
 
@@ -155,44 +74,26 @@ fn account_tests() {
 
     println!("Calling `universal_receiver_hook`");
 
-    let params = api_contracts::account_test::UniversalReceiverParams{
-        type_: 0_u32,
-        payload: vec![1, 2, 3]
+    let call = bindings::AccountApiTest::universal_receiver_hookCall{
+        target: assigned_addr,
+        params: bindings::UniversalReceiverParams{
+            type_: 0_u32,
+            payload: vec![1, 2, 3]
+        }
     };
-    let abi_encoded_call = api_contracts::account_test::universal_receiver_hookCall{
-        target: 101_u64, 
-        params: params
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    dbg!(cbor_encoded.as_str());
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 2,
-            // target: 101(assigned_addr actor id), params: UniversalReceiverParams{0, RawBytes([0,1,2])}
-            params: RawBytes::new(hex::decode(
-                // "58C4D6FB314E000000000000000000000000000000000000000000000000000000000000006500000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000030102030000000000000000000000000000000000000000000000000000000000"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+
+    let res = env.invoke(&contract, &call);
 
     let gas_used = parse_gas(res.exec_trace);
 
     gas_result.push(("universal_receiver_hook".into(),  gas_used));
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    let table = testing::create_gas_table(gas_result);
+    let table = testing::create_gas_table(gas_result.clone());
     testing::save_gas_table(&table, "account");
+    testing::report::save_gas_results(&gas_result, "account");
 
     table.printstd();
+
+    testing::gas::check_gas_baseline(&gas_result, "account");
 }
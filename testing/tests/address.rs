@@ -1,85 +1,200 @@
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::EAM_ACTOR_ADDR;
-use fvm::executor::{ApplyKind, Executor};
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
-use fvm_ipld_encoding::strict_bytes;
-use fvm_ipld_encoding::RawBytes;
-use fvm_shared::address::Address;
-use fvm_shared::message::Message;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-use alloy_sol_types::{SolCall};
-
-use testing::{setup, api_contracts};
-
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/AddressTest.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
-
-#[test]
-fn address_tests() {
-    println!("Testing Address lib");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
+use alloy_primitives::Address as EthAddress;
+use fvm_shared::address::{Address, Payload};
+use proptest::prelude::*;
+use testing::bindings;
+use testing::bindings::AddressTest as contract_api;
+use testing::env::{decode_return, ContractHandle, TestEnv};
+use testing::fuzz::{self, ContractFuzzer};
+
+const CONTRACT_NAME: &str = "AddressTest";
+
+/// What `FilAddresses.validate` answers for `bytes`. It agrees with `Address::from_bytes` on
+/// secp256k1, actor and BLS addresses, and is looser or stricter on the others.
+fn expected_validity(bytes: &[u8]) -> bool {
+    match bytes {
+        [] => false,
+        // ID payloads are bounded in length, not decoded
+        [0x00, payload @ ..] => (1..=10).contains(&payload.len()),
+        [0x01 | 0x02, payload @ ..] => payload.len() == 20,
+        [0x03, payload @ ..] => payload.len() == 48,
+        // EAM subaddresses must be Ethereum addresses, where fvm allows up to 54 bytes
+        [0x04, 0x0a, subaddress @ ..] => subaddress.len() == 20,
+        // Other namespaces and unknown protocols are accepted up to 256 bytes
+        _ => bytes.len() <= 256,
+    }
+}
 
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
+/// The Ethereum address of an EAM delegated address, as fvm sees it.
+fn eth_address(bytes: &[u8]) -> Option<Vec<u8>> {
+    match Address::from_bytes(bytes).ok()?.payload() {
+        Payload::Delegated(delegated)
+            if delegated.namespace() == 10 && delegated.subaddress().len() == 20 =>
+        {
+            Some(delegated.subaddress().to_vec())
+        }
+        _ => None,
+    }
+}
 
-    let executor = tester.executor.as_mut().unwrap();
+/// Addresses of every protocol at their edges, followed by malformed payloads.
+fn corpus() -> Vec<Vec<u8>> {
+    let mut corpus = vec![];
+
+    for id in [0, 1, 127, 128, 16383, 16384, 1 << 63, u64::MAX] {
+        corpus.push(Address::new_id(id).to_bytes());
+    }
+    corpus.push(Address::new_secp256k1(&[4; 65]).unwrap().to_bytes());
+    corpus.push(Address::new_actor(b"actor").to_bytes());
+    corpus.push(Address::new_bls(&[1; 48]).unwrap().to_bytes());
+    for (namespace, len) in [
+        (10, 20),
+        (10, 0),
+        (10, 19),
+        (10, 21),
+        (10, 54),
+        (0, 0),
+        (0, 20),
+        (32, 54),
+        (u64::MAX, 54),
+    ] {
+        corpus.push(Address::new_delegated(namespace, &vec![0xee; len]).unwrap().to_bytes());
+    }
+
+    corpus.extend([
+        vec![],
+        vec![0x00],
+        // truncated, non-minimal and overflowing leb128 IDs, and a trailing byte
+        vec![0x00, 0x80],
+        vec![0x00, 0x80, 0x00],
+        [vec![0x00], vec![0xff; 9], vec![0x02]].concat(),
+        [vec![0x00], vec![0xff; 10], vec![0x01]].concat(),
+        vec![0x00, 0x01, 0x01],
+        [vec![0x01], vec![0xaa; 19]].concat(),
+        [vec![0x01], vec![0xaa; 21]].concat(),
+        [vec![0x02], vec![0xaa; 19]].concat(),
+        [vec![0x02], vec![0xaa; 21]].concat(),
+        [vec![0x03], vec![0xbb; 47]].concat(),
+        [vec![0x03], vec![0xbb; 49]].concat(),
+        // delegated addresses without namespace, with a truncated one, and over 54 bytes
+        vec![0x04],
+        vec![0x04, 0x80],
+        [vec![0x04, 0x20], vec![0xee; 55]].concat(),
+        // unknown protocols
+        vec![0x05, 0x01],
+        vec![0xff],
+        vec![0x05; 257],
+    ]);
+
+    corpus
+}
 
-    println!("Calling init actor (EVM)");
+fn check_address(env: &mut TestEnv, contract: &ContractHandle, bytes: &[u8]) {
+    let hex = hex::encode(bytes);
+    let addr = bindings::FilAddress {
+        data: bytes.to_vec(),
+    };
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
+    let valid = env
+        .invoke_decoded(contract, &contract_api::validateCall { addr: addr.clone() })
+        ._0;
+    assert_eq!(valid, expected_validity(bytes), "validate({})", hex);
 
-    let constructor_params = CreateExternalParams(evm_bin);
+    let parsed = Address::from_bytes(bytes);
+    if parsed.is_ok() && !bytes.starts_with(&[0x04, 0x0a]) {
+        assert!(valid, "{} is a valid {:?} address", hex, parsed.unwrap().protocol());
+    }
 
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
+    let call = contract_api::fromBytesCall {
+        data: bytes.to_vec(),
     };
+    let res = env.invoke(contract, &call);
+    if valid {
+        assert_eq!(decode_return(&res)._0.data, bytes, "fromBytes({})", hex);
+    } else {
+        assert!(!res.msg_receipt.exit_code.is_success(), "fromBytes({}) succeeded", hex);
+    }
+
+    let call = contract_api::toEthAddressCall { addr };
+    let res = env.invoke(contract, &call);
+    match eth_address(bytes) {
+        Some(eth) => assert_eq!(decode_return(&res)._0.as_slice(), &eth[..], "toEthAddress({})", hex),
+        None => assert!(!res.msg_receipt.exit_code.is_success(), "toEthAddress({}) succeeded", hex),
+    }
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+#[test]
+fn address_tests() {
+    println!("Testing Address lib");
 
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    let mut env = TestEnv::new();
 
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+    println!("Calling init actor (EVM)");
 
-    let contract_actor_id = exec_return.actor_id;
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
     println!("Calling `fromActorID`");
 
-    let abi_encoded_call = api_contracts::address_test::actorid_conversionCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+    let res = env.invoke(&contract, &bindings::AddressTest::actorid_conversionCall{});
 
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode(
-                // "44a0a86647"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+#[test]
+fn address_conformance_tests() {
+    let mut env = TestEnv::new();
+    let contract = env.deploy_contract(CONTRACT_NAME);
+
+    for bytes in corpus() {
+        println!("Checking {}", hex::encode(&bytes));
+        check_address(&mut env, &contract, &bytes);
+    }
+
+    for id in [0, 1, 127, 128, 1 << 63, u64::MAX] {
+        let addr = env
+            .invoke_decoded(&contract, &contract_api::fromActorIDCall { actorID: id })
+            ._0;
+        assert_eq!(addr.data, Address::new_id(id).to_bytes());
+        assert_eq!(Address::from_bytes(&addr.data).unwrap().id().unwrap(), id);
+    }
+
+    for eth in [[0; 20], [0xff; 20]] {
+        let addr = env
+            .invoke_decoded(&contract, &contract_api::fromEthAddressCall { addr: EthAddress::from(eth) })
+            ._0;
+        assert_eq!(addr.data, Address::new_delegated(10, &eth).unwrap().to_bytes());
+    }
+}
 
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+#[test]
+fn address_fuzz_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run("validate", fuzz::address(), |caller, address| {
+        check_address(caller.env, caller.contract, &address.to_bytes());
+        Ok(())
+    });
+
+    fuzzer.run("validateBytes", fuzz::bytes(64), |caller, bytes| {
+        check_address(caller.env, caller.contract, &bytes);
+        Ok(())
+    });
+
+    fuzzer.run("fromActorID", any::<u64>(), |caller, id| {
+        let addr = caller.call(&contract_api::fromActorIDCall { actorID: id })?._0;
+        prop_assert_eq!(addr.data, Address::new_id(id).to_bytes());
+        Ok(())
+    });
+
+    fuzzer.run("fromEthAddress", any::<[u8; 20]>(), |caller, eth| {
+        let addr = caller
+            .call(&contract_api::fromEthAddressCall {
+                addr: EthAddress::from(eth),
+            })?
+            ._0;
+        prop_assert_eq!(&addr.data, &Address::new_delegated(10, &eth).unwrap().to_bytes());
+
+        let back = caller.call(&contract_api::toEthAddressCall { addr })?._0;
+        prop_assert_eq!(back.as_slice(), &eth[..]);
+        Ok(())
+    });
 }
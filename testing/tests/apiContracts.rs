@@ -0,0 +1,18 @@
+use testing::api_contracts;
+
+#[test]
+fn api_contracts_return_encoding_tests() {
+    // One length per byte string header size
+    for len in [0, 23, 24, 255, 256, 65535, 65536] {
+        let abi_encoded: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        let cbor_encoded = hex::decode(api_contracts::cbor_encode(abi_encoded.clone())).unwrap();
+
+        assert_eq!(api_contracts::cbor_decode(&cbor_encoded).unwrap(), abi_encoded);
+    }
+
+    assert!(api_contracts::cbor_decode(&[]).is_err());
+    // Text string instead of byte string
+    assert!(api_contracts::cbor_decode(&hex::decode("6161").unwrap()).is_err());
+    // Header announcing more bytes than present
+    assert!(api_contracts::cbor_decode(&hex::decode("5801").unwrap()).is_err());
+}
@@ -0,0 +1,65 @@
+use std::time::{Duration, SystemTime};
+
+use testing::artifacts::{artifacts, Artifacts};
+
+#[test]
+fn artifacts_tests() {
+    println!("Loading every test contract artifact");
+
+    let artifacts = artifacts();
+    let test_contracts: Vec<String> = artifacts
+        .names()
+        .into_iter()
+        .filter(|name| name.ends_with("Test"))
+        .collect();
+
+    assert!(
+        !test_contracts.is_empty(),
+        "no test contract in {}",
+        artifacts.build_dir().display()
+    );
+
+    for name in test_contracts {
+        let artifact = artifacts
+            .load(&name)
+            .unwrap_or_else(|err| panic!("{:#}", err));
+
+        assert!(!artifact.abi.functions.is_empty(), "{} has no function", name);
+    }
+
+    let err = artifacts.load("NotAContractTest").unwrap_err();
+    assert!(err.to_string().contains("no artifact named NotAContractTest"));
+}
+
+#[test]
+fn artifacts_stale_tests() {
+    let dir = std::env::temp_dir().join(format!("artifacts_{}", std::process::id()));
+    let build_dir = dir.join("build");
+    let sources_dir = dir.join("contracts");
+    std::fs::create_dir_all(&build_dir).unwrap();
+    std::fs::create_dir_all(sources_dir.join("types")).unwrap();
+
+    std::fs::write(sources_dir.join("Api.sol"), "import \"./types/Types.sol\";\ncontract Api {}\n").unwrap();
+    std::fs::write(sources_dir.join("types/Types.sol"), "library Types {}\n").unwrap();
+    std::fs::write(build_dir.join("Api.bin"), "6080").unwrap();
+    std::fs::write(build_dir.join("Api.abi"), "[]").unwrap();
+
+    let artifacts = Artifacts::index(&build_dir, &sources_dir).unwrap();
+    artifacts.load("Api").unwrap();
+
+    // Only the imported file changed since the build
+    let later = SystemTime::now() + Duration::from_secs(60);
+    std::fs::File::options()
+        .write(true)
+        .open(sources_dir.join("types/Types.sol"))
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+
+    let artifacts = Artifacts::index(&build_dir, &sources_dir).unwrap();
+    let err = artifacts.load("Api").unwrap_err().to_string();
+    assert!(err.contains("is stale"), "{}", err);
+    assert!(err.contains("Types.sol"), "{}", err);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
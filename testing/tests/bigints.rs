@@ -1,216 +1,214 @@
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::EAM_ACTOR_ADDR;
-use fvm::executor::{ApplyKind, Executor};
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
-use fvm_ipld_encoding::strict_bytes;
-use fvm_ipld_encoding::RawBytes;
-use fvm_shared::address::Address;
+use alloy_primitives::{I256, U256};
+use fvm_ipld_encoding::{BytesDe, BytesSer};
+use fvm_shared::bigint::{BigInt, Sign};
 use fvm_shared::econ::TokenAmount;
-use fvm_shared::message::Message;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-use alloy_sol_types::{SolCall};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use testing::bindings;
+use testing::bindings::BigIntsTest as contract_api;
+use testing::env::TestEnv;
+use testing::fuzz::{self, sol_bigint, ContractFuzzer};
 
-use testing::{setup, api_contracts};
-use testing::GasResult;
-
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/BigIntsTest.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+const CONTRACT_NAME: &str = "BigIntsTest";
 
 #[test]
 fn bigints_tests() {
     println!("Testing solidity API");
 
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
+    let mut env = TestEnv::new();
 
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
     println!("Calling `to_uint256`");
 
-    let abi_encoded_call = api_contracts::bigints_test::to_uint256Call{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 1,
-        params: RawBytes::new(hex::decode(
-            // "44C91A35AF"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::BigIntsTest::to_uint256Call{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-
     println!("Calling `to_int256_negative`");
 
-    let abi_encoded_call = api_contracts::bigints_test::to_int256_negativeCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+    let res = env.invoke(&contract, &bindings::BigIntsTest::to_int256_negativeCall{});
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 2,
-        params: RawBytes::new(hex::decode(
-            // "44A7579D7A"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    dbg!(&res);
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-
     println!("Calling `to_int256_positive`");
 
-    let abi_encoded_call = api_contracts::bigints_test::to_int256_positiveCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 3,
-        params: RawBytes::new(hex::decode(
-            //"44F807127B"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::BigIntsTest::to_int256_positiveCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-
     println!("Calling `from_uint256`");
 
-    let abi_encoded_call = api_contracts::bigints_test::from_uint256Call{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 4,
-        params: RawBytes::new(hex::decode(
-            // "44ED98A8D4"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::BigIntsTest::from_uint256Call{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `from_int256_positive`");
 
-    let abi_encoded_call = api_contracts::bigints_test::from_int256_positiveCall{}.abi_encode();
+    let res = env.invoke(&contract, &bindings::BigIntsTest::from_int256_positiveCall{});
 
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 5,
-        params: RawBytes::new(hex::decode(
-            // "4491A2B353"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
+    println!("Calling `from_int256_negative`");
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::BigIntsTest::from_int256_negativeCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
+}
 
-    println!("Calling `from_int256_negative`");
+fn uint_value(value: U256) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, &value.to_be_bytes::<32>())
+}
 
-    let abi_encoded_call = api_contracts::bigints_test::from_int256_negativeCall{}.abi_encode();
+fn int_value(value: I256) -> BigInt {
+    BigInt::from_signed_bytes_be(&value.into_raw().to_be_bytes::<32>())
+}
 
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+fn sol_value(val: &[u8], neg: bool) -> bindings::BigInt {
+    bindings::BigInt {
+        val: val.to_vec(),
+        neg,
+    }
+}
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 6,
-        params: RawBytes::new(hex::decode(
-            // "44CC15D401"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
+/// Magnitudes of random lengths around a word, with leading zeros, and at the bounds of the
+/// 256 bits integers: 2^255 - 1, 2^255, 2^256 - 1 and 2^256.
+fn magnitude() -> impl Strategy<Value = Vec<u8>> {
+    let mut max_int = vec![0xff; 32];
+    max_int[0] = 0x7f;
+    let mut min_int_abs = vec![0; 32];
+    min_int_abs[0] = 0x80;
+    let mut two_pow_256 = vec![0; 33];
+    two_pow_256[0] = 1;
+    let boundaries = vec![
+        vec![],
+        vec![0],
+        vec![0; 33],
+        max_int,
+        min_int_abs,
+        vec![0xff; 32],
+        two_pow_256,
+        vec![0xff; 33],
+    ];
+
+    prop_oneof![
+        fuzz::bytes(40),
+        (1_usize..8, fuzz::bytes(32)).prop_map(|(zeros, val)| [vec![0; zeros], val].concat()),
+        proptest::sample::select(boundaries),
+    ]
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+fn uint256() -> impl Strategy<Value = U256> {
+    prop_oneof![
+        any::<[u8; 32]>().prop_map(U256::from_be_bytes),
+        Just(U256::ZERO),
+        Just(U256::MAX),
+    ]
+}
 
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+fn int256() -> impl Strategy<Value = I256> {
+    prop_oneof![
+        uint256().prop_map(I256::from_raw),
+        Just(I256::MIN),
+        Just(I256::MAX),
+        Just(I256::MINUS_ONE),
+    ]
+}
+
+#[test]
+fn bigints_conversion_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    let uint_max = (BigInt::from(1) << 256) - 1;
+    let int_max = (BigInt::from(1) << 255) - 1;
+    let int_min = -(BigInt::from(1) << 255);
+
+    // Magnitudes over 256 bits are reported through the overflow flag, negative values revert
+    fuzzer.run("toUint256", (magnitude(), any::<bool>()), |caller, (val, neg)| {
+        let expected = sol_bigint(&val, neg);
+        let call = contract_api::toUint256Call {
+            value: sol_value(&val, neg),
+        };
+        if expected.sign() == Sign::Minus {
+            let res = caller.env.invoke(caller.contract, &call);
+            prop_assert!(!res.msg_receipt.exit_code.is_success(), "{} converted", expected);
+            return Ok(());
+        }
+
+        let ret = caller.call(&call)?;
+        if expected > uint_max {
+            prop_assert!(ret._1);
+            prop_assert_eq!(ret._0, U256::ZERO);
+        } else {
+            prop_assert!(!ret._1);
+            prop_assert_eq!(uint_value(ret._0), expected);
+        }
+        Ok(())
+    });
+
+    fuzzer.run("toInt256", (magnitude(), any::<bool>()), |caller, (val, neg)| {
+        let expected = sol_bigint(&val, neg);
+        let ret = caller.call(&contract_api::toInt256Call {
+            value: sol_value(&val, neg),
+        })?;
+
+        if expected < int_min || expected > int_max {
+            prop_assert!(ret._1);
+            prop_assert_eq!(ret._0, I256::ZERO);
+        } else {
+            prop_assert!(!ret._1);
+            prop_assert_eq!(int_value(ret._0), expected);
+        }
+        Ok(())
+    });
+
+    fuzzer.run("fromUint256", uint256(), |caller, value| {
+        let ret = caller.call(&contract_api::fromUint256Call { value })?._0;
+
+        prop_assert!(!ret.neg);
+        prop_assert_eq!(sol_bigint(&ret.val, ret.neg), uint_value(value));
+        Ok(())
+    });
+
+    fuzzer.run("fromInt256", int256(), |caller, value| {
+        let ret = caller.call(&contract_api::fromInt256Call { value })?._0;
+
+        prop_assert_eq!(ret.neg, value.is_negative());
+        prop_assert_eq!(sol_bigint(&ret.val, ret.neg), int_value(value));
+        Ok(())
+    });
+}
+
+#[test]
+fn bigints_cbor_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    // The serialized bytes are the content of the CBOR byte string of a TokenAmount
+    fuzzer.run("serializeBigInt", (magnitude(), any::<bool>()), |caller, (val, neg)| {
+        let raw = caller
+            .call(&contract_api::serializeBigIntCall {
+                value: sol_value(&val, neg),
+            })?
+            ._0;
+
+        let cbor = fvm_ipld_encoding::to_vec(&BytesSer(&raw)).unwrap();
+        let amount: TokenAmount = fvm_ipld_encoding::from_slice(&cbor)
+            .map_err(|err| TestCaseError::fail(format!("{}: {}", hex::encode(&raw), err)))?;
+        prop_assert_eq!(amount.atto(), &sol_bigint(&val, neg));
+        Ok(())
+    });
+
+    fuzzer.run("deserializeBigInt", fuzz::bigint(), |caller, value| {
+        let amount = TokenAmount::from_atto(value);
+        let raw: BytesDe =
+            fvm_ipld_encoding::from_slice(&fvm_ipld_encoding::to_vec(&amount).unwrap()).unwrap();
+        let ret = caller
+            .call(&contract_api::deserializeBigIntCall { raw: raw.0 })?
+            ._0;
+
+        prop_assert_eq!(&sol_bigint(&ret.val, ret.neg), amount.atto());
+        Ok(())
+    });
 }
@@ -1,260 +1,63 @@
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::EAM_ACTOR_ADDR;
-use fvm::executor::{ApplyKind, Executor};
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
-use fvm_ipld_encoding::strict_bytes;
-use fvm_ipld_encoding::RawBytes;
-use fvm_shared::address::Address;
-use fvm_shared::message::Message;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-use alloy_sol_types::{SolCall};
+use testing::bindings;
+use testing::env::TestEnv;
 
-use testing::{setup, api_contracts};
-
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/CborDecodeTest.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+const CONTRACT_NAME: &str = "CborDecodeTest";
 
 #[test]
 fn cbor_decode_tests() {
     println!("Testing solidity API");
 
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
+    let mut env = TestEnv::new();
 
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
     println!("Calling `decodeFixedArray`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeFixedArrayCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode(
-                // "44D67EC5C1"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeFixedArrayCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `decodeFalse`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeFalseCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 2,
-            params: RawBytes::new(hex::decode(
-                // "44fdcd47b2"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeFalseCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `decodeTrue`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeTrueCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 3,
-            params: RawBytes::new(hex::decode(
-                // "44dd0a0af9"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeTrueCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `decodeNull`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeNullCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 4,
-            params: RawBytes::new(hex::decode(
-                // "442adc75f2"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeNullCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `decodeInteger`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeIntegerCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 5,
-            params: RawBytes::new(hex::decode(
-                // "449ad7774f"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeIntegerCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `decodeString`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeStringCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 6,
-            params: RawBytes::new(hex::decode(
-                // "44a77b0360"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeStringCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `decodeStringWithWeirdChar`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeStringWithWeirdCharCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 7,
-            params: RawBytes::new(hex::decode(
-                // "4410ed3fc5"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeStringWithWeirdCharCall{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `decodeArrayU8`");
 
-    let abi_encoded_call = api_contracts::cbor_decode_test::decodeArrayU8Call{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 8,
-            params: RawBytes::new(hex::decode(
-                // "44cdee5b7d"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::CborDecodeTest::decodeArrayU8Call{});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 }
@@ -0,0 +1,556 @@
+use alloy_primitives::{I256, U256};
+use fvm_shared::bigint::{BigInt, Sign};
+use fvm_shared::error::ExitCode;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::{TestCaseError, TestError};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use testing::bindings::CborDecodeTest as contract_api;
+use testing::env::{decode_return, ContractHandle, TestEnv};
+use testing::fuzz::{self, ContractFuzzer};
+use testing::revert::EVM_CONTRACT_REVERTED;
+
+const CONTRACT_NAME: &str = "CborDecodeTest";
+
+const REGRESSIONS_PATH: &str = "vectors/cbor_decode_regressions.json";
+
+/// The `CBORDecoder` functions exposed by the test contract, each reading from index 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Helper {
+    Bool,
+    FixedArray,
+    String,
+    Bytes,
+    Bytes32,
+    UInt256,
+    Int256,
+    UInt64,
+    UInt32,
+    UInt16,
+    UInt8,
+    Int64,
+    Int32,
+    Int16,
+    Int8,
+}
+
+const HELPERS: [Helper; 15] = [
+    Helper::Bool,
+    Helper::FixedArray,
+    Helper::String,
+    Helper::Bytes,
+    Helper::Bytes32,
+    Helper::UInt256,
+    Helper::Int256,
+    Helper::UInt64,
+    Helper::UInt32,
+    Helper::UInt16,
+    Helper::UInt8,
+    Helper::Int64,
+    Helper::Int32,
+    Helper::Int16,
+    Helper::Int8,
+];
+
+/// A decoded value, whatever the Solidity type it was returned as.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Bool(bool),
+    Int(BigInt),
+    Bytes(Vec<u8>),
+}
+
+/// A decoded value and the index after it.
+type Decoded = (Value, usize);
+
+fn uint(value: U256) -> Value {
+    Value::Int(BigInt::from_bytes_be(
+        Sign::Plus,
+        &value.to_be_bytes::<32>(),
+    ))
+}
+
+fn int(value: I256) -> Value {
+    Value::Int(BigInt::from_signed_bytes_be(
+        &value.into_raw().to_be_bytes::<32>(),
+    ))
+}
+
+fn small(value: impl Into<BigInt>) -> Value {
+    Value::Int(value.into())
+}
+
+fn bytes(value: impl AsRef<[u8]>) -> Value {
+    Value::Bytes(value.as_ref().to_vec())
+}
+
+fn index(value: U256) -> usize {
+    value.to::<usize>()
+}
+
+macro_rules! invoke {
+    ($env:expr, $contract:expr, $call:ident, $data:expr, $value:expr) => {{
+        let call = contract_api::$call {
+            cborData: $data.to_vec().into(),
+        };
+        let res = $env.invoke($contract, &call);
+        let decoded = res.msg_receipt.exit_code.is_success().then(|| {
+            let ret = decode_return::<contract_api::$call>(&res);
+            ($value(ret._0), index(ret._1))
+        });
+        (res.msg_receipt.exit_code, decoded)
+    }};
+}
+
+impl Helper {
+    fn from_name(name: &str) -> Option<Self> {
+        HELPERS.into_iter().find(|helper| helper.name() == name)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Helper::Bool => "readBool",
+            Helper::FixedArray => "readFixedArray",
+            Helper::String => "readString",
+            Helper::Bytes => "readBytes",
+            Helper::Bytes32 => "readBytes32",
+            Helper::UInt256 => "readUInt256",
+            Helper::Int256 => "readInt256",
+            Helper::UInt64 => "readUInt64",
+            Helper::UInt32 => "readUInt32",
+            Helper::UInt16 => "readUInt16",
+            Helper::UInt8 => "readUInt8",
+            Helper::Int64 => "readInt64",
+            Helper::Int32 => "readInt32",
+            Helper::Int16 => "readInt16",
+            Helper::Int8 => "readInt8",
+        }
+    }
+
+    /// Calls the helper on `data`, returning the exit code and, if it succeeded, what it read.
+    fn invoke(
+        self,
+        env: &mut TestEnv,
+        contract: &ContractHandle,
+        data: &[u8],
+    ) -> (ExitCode, Option<Decoded>) {
+        match self {
+            Helper::Bool => invoke!(env, contract, readBoolCall, data, Value::Bool),
+            Helper::FixedArray => invoke!(env, contract, readFixedArrayCall, data, uint),
+            Helper::String => invoke!(env, contract, readStringCall, data, bytes),
+            Helper::Bytes => invoke!(env, contract, readBytesCall, data, bytes),
+            Helper::Bytes32 => invoke!(env, contract, readBytes32Call, data, bytes),
+            Helper::UInt256 => invoke!(env, contract, readUInt256Call, data, uint),
+            Helper::Int256 => invoke!(env, contract, readInt256Call, data, int),
+            Helper::UInt64 => invoke!(env, contract, readUInt64Call, data, small),
+            Helper::UInt32 => invoke!(env, contract, readUInt32Call, data, small),
+            Helper::UInt16 => invoke!(env, contract, readUInt16Call, data, small),
+            Helper::UInt8 => invoke!(env, contract, readUInt8Call, data, small),
+            Helper::Int64 => invoke!(env, contract, readInt64Call, data, small),
+            Helper::Int32 => invoke!(env, contract, readInt32Call, data, small),
+            Helper::Int16 => invoke!(env, contract, readInt16Call, data, small),
+            Helper::Int8 => invoke!(env, contract, readInt8Call, data, small),
+        }
+    }
+
+    /// What the helper should read from `data` per RFC 8949, `None` where it should revert.
+    /// Non-minimal heads, unknown tags before byte strings and invalid UTF-8 are accepted, so
+    /// the contract may only be stricter than this.
+    fn reference(self, data: &[u8]) -> Option<Decoded> {
+        let (maj, arg, idx) = head(data, 0)?;
+        let int_in = |min: i128, max: i128| {
+            let value = match maj {
+                0 => arg as i128,
+                1 => -1 - arg as i128,
+                _ => return None,
+            };
+            (min..=max)
+                .contains(&value)
+                .then(|| (Value::Int(value.into()), idx))
+        };
+
+        match self {
+            Helper::Bool => match (maj, arg) {
+                (7, 20) => Some((Value::Bool(false), idx)),
+                (7, 21) => Some((Value::Bool(true), idx)),
+                _ => None,
+            },
+            Helper::FixedArray => (maj == 4).then(|| (Value::Int(arg.into()), idx)),
+            Helper::String => {
+                let (bytes, next) = (maj == 3).then(|| slice(data, idx, arg))??;
+                Some((Value::Bytes(bytes), next))
+            }
+            Helper::Bytes => {
+                let (maj, arg, idx) = if maj == 6 {
+                    head(data, idx)?
+                } else {
+                    (maj, arg, idx)
+                };
+                let (bytes, next) = (maj == 2).then(|| slice(data, idx, arg))??;
+                Some((Value::Bytes(bytes), next))
+            }
+            Helper::Bytes32 => {
+                let (bytes, next) = (maj == 2 && arg <= 32).then(|| slice(data, idx, arg))??;
+                let mut word = vec![0; 32 - bytes.len()];
+                word.extend(bytes);
+                Some((Value::Bytes(word), next))
+            }
+            Helper::UInt256 => match (maj, arg) {
+                (0, _) => Some((Value::Int(arg.into()), idx)),
+                (6, 2) => {
+                    let (magnitude, next) = bignum(data, idx)?;
+                    (magnitude.bits() <= 256).then(|| (Value::Int(magnitude), next))
+                }
+                _ => None,
+            },
+            Helper::Int256 => {
+                let (magnitude, next) = match (maj, arg) {
+                    (1, _) => (BigInt::from(arg), idx),
+                    (6, 3) => bignum(data, idx)?,
+                    _ => return None,
+                };
+                (magnitude.bits() <= 255).then(|| (Value::Int(-1 - magnitude), next))
+            }
+            Helper::UInt64 => (maj == 0).then(|| (Value::Int(arg.into()), idx)),
+            Helper::UInt32 => int_in(0, u32::MAX.into()),
+            Helper::UInt16 => int_in(0, u16::MAX.into()),
+            Helper::UInt8 => int_in(0, u8::MAX.into()),
+            Helper::Int64 => int_in(i64::MIN.into(), i64::MAX.into()),
+            Helper::Int32 => int_in(i32::MIN.into(), i32::MAX.into()),
+            Helper::Int16 => int_in(i16::MIN.into(), i16::MAX.into()),
+            Helper::Int8 => int_in(i8::MIN.into(), i8::MAX.into()),
+        }
+    }
+}
+
+/// Major type, argument and the index after the head at `idx`.
+fn head(data: &[u8], idx: usize) -> Option<(u8, u64, usize)> {
+    let first = *data.get(idx)?;
+    let (maj, low) = (first >> 5, first & 0x1f);
+    let len = match low {
+        0..=23 => return Some((maj, low.into(), idx + 1)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        // reserved values and indefinite lengths
+        _ => return None,
+    };
+    let arg = data
+        .get(idx + 1..idx + 1 + len)?
+        .iter()
+        .fold(0, |arg, byte| arg << 8 | u64::from(*byte));
+    Some((maj, arg, idx + 1 + len))
+}
+
+fn slice(data: &[u8], idx: usize, len: u64) -> Option<(Vec<u8>, usize)> {
+    let end = idx.checked_add(usize::try_from(len).ok()?)?;
+    Some((data.get(idx..end)?.to_vec(), end))
+}
+
+/// The magnitude of a bignum whose tag was read, and the index after it.
+fn bignum(data: &[u8], idx: usize) -> Option<(BigInt, usize)> {
+    let (maj, len, idx) = head(data, idx)?;
+    let (bytes, next) = (maj == 2).then(|| slice(data, idx, len))??;
+    Some((BigInt::from_bytes_be(Sign::Plus, &bytes), next))
+}
+
+/// How a helper handled an input.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    /// Read what the reference reads.
+    Accepted,
+    /// Reverted, on input the reference rejects or that the library is stricter about.
+    CleanRevert,
+    /// Returned something else than the reference, or a value where it rejects the input.
+    WrongValue { decoded: String, expected: String },
+    /// Ran out of gas, or aborted in another way than a revert, on an allocation or loop sized
+    /// by the input.
+    OutOfGas { exit_code: u32 },
+}
+
+impl Outcome {
+    fn is_failure(&self) -> bool {
+        matches!(self, Outcome::WrongValue { .. } | Outcome::OutOfGas { .. })
+    }
+}
+
+fn classify(helper: Helper, env: &mut TestEnv, contract: &ContractHandle, data: &[u8]) -> Outcome {
+    let (exit_code, decoded) = helper.invoke(env, contract, data);
+    let Some(decoded) = decoded else {
+        if exit_code.value() == EVM_CONTRACT_REVERTED {
+            return Outcome::CleanRevert;
+        }
+        return Outcome::OutOfGas {
+            exit_code: exit_code.value(),
+        };
+    };
+
+    match helper.reference(data) {
+        Some(expected) if expected == decoded => Outcome::Accepted,
+        expected => Outcome::WrongValue {
+            decoded: format!("{:?}", decoded),
+            expected: format!("{:?}", expected),
+        },
+    }
+}
+
+/// An input a helper once mishandled, replayed by `cbor_decode_regression_tests`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Regression {
+    helper: String,
+    /// Hex of the input, without `0x`.
+    input: String,
+    /// The outcome when it was recorded.
+    outcome: Outcome,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct RegressionFile {
+    regressions: Vec<Regression>,
+}
+
+fn regressions_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(REGRESSIONS_PATH)
+}
+
+fn load_regressions() -> RegressionFile {
+    let json = std::fs::read_to_string(regressions_path()).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+/// Appends the failures not recorded yet to the regression fixture.
+fn save_regressions(failures: Vec<Regression>) {
+    let mut file = load_regressions();
+    for failure in failures {
+        let known = file.regressions.iter().any(|regression| {
+            regression.helper == failure.helper && regression.input == failure.input
+        });
+        if !known {
+            file.regressions.push(failure);
+        }
+    }
+
+    let mut json = serde_json::to_string_pretty(&file).unwrap();
+    json.push('\n');
+    std::fs::write(regressions_path(), json).unwrap();
+}
+
+/// Changes made to a valid CBOR item.
+#[derive(Clone, Debug)]
+enum Mutation {
+    None,
+    /// Cuts the input at the given index, modulo its length.
+    Truncate(usize),
+    /// Replaces the major type of the first head.
+    MajorType(u8),
+    /// Replaces the argument of the first head by a large 8 bytes one.
+    Oversize(u64),
+    /// Turns the first head into an indefinite length one, terminated by a break.
+    Indefinite,
+    /// Wraps the item in a tag.
+    Tag(u64),
+    /// Encodes the argument of the first head on 1, 2, 4 or 8 bytes where it fits.
+    NonMinimal(usize),
+    /// Xors a byte, at an index modulo the input length.
+    Flip(usize, u8),
+}
+
+/// The minimal head of major type `maj` with argument `arg`.
+fn encode_head(maj: u8, arg: u64) -> Vec<u8> {
+    let len = match arg {
+        0..=23 => return vec![maj << 5 | arg as u8],
+        24..=0xff => 1,
+        0x100..=0xffff => 2,
+        0x1_0000..=0xffff_ffff => 4,
+        _ => 8,
+    };
+    sized_head(maj, arg, len)
+}
+
+/// A head of major type `maj` with `arg` on `len` bytes.
+fn sized_head(maj: u8, arg: u64, len: usize) -> Vec<u8> {
+    let low = match len {
+        1 => 24,
+        2 => 25,
+        4 => 26,
+        _ => 27,
+    };
+    let mut head = vec![maj << 5 | low];
+    head.extend(&arg.to_be_bytes()[8 - len..]);
+    head
+}
+
+fn mutate(item: &[u8], mutation: &Mutation) -> Vec<u8> {
+    let mut data = item.to_vec();
+    let (maj, arg, idx) = head(item, 0).unwrap();
+    let with_head = |head: Vec<u8>| [head, item[idx..].to_vec()].concat();
+
+    match *mutation {
+        Mutation::None => data,
+        Mutation::Truncate(at) => {
+            data.truncate(at % item.len());
+            data
+        }
+        Mutation::MajorType(new) => {
+            data[0] = new << 5 | (data[0] & 0x1f);
+            data
+        }
+        Mutation::Oversize(arg) => with_head(sized_head(maj, arg, 8)),
+        Mutation::Indefinite => {
+            let mut data = with_head(vec![maj << 5 | 31]);
+            data.push(0xff);
+            data
+        }
+        Mutation::Tag(tag) => [encode_head(6, tag), data].concat(),
+        Mutation::NonMinimal(len) if len == 8 || arg < 1 << (8 * len) => {
+            with_head(sized_head(maj, arg, len))
+        }
+        Mutation::NonMinimal(_) => data,
+        Mutation::Flip(at, mask) => {
+            data[at % item.len()] ^= mask;
+            data
+        }
+    }
+}
+
+/// Valid CBOR items of every type the helpers read, with the edges of their ranges.
+fn item() -> impl Strategy<Value = Vec<u8>> {
+    let arg = prop_oneof![
+        any::<u64>(),
+        proptest::sample::select(vec![
+            0,
+            23,
+            24,
+            0x7f,
+            0xff,
+            0x100,
+            0x7fff,
+            0xffff,
+            0x7fff_ffff,
+            0xffff_ffff,
+            i64::MAX as u64,
+            u64::MAX,
+        ]),
+    ];
+    let magnitude = prop_oneof![fuzz::bytes(40), vec(Just(0xff_u8), 31..=33)];
+
+    prop_oneof![
+        (0_u8..2, arg).prop_map(|(maj, arg)| encode_head(maj, arg)),
+        fuzz::bytes(40).prop_map(|bytes| [encode_head(2, bytes.len() as u64), bytes].concat()),
+        "\\PC{0,16}"
+            .prop_map(|text| [encode_head(3, text.len() as u64), text.into_bytes()].concat()),
+        vec(0_u8..24, 0..8).prop_map(|items| [encode_head(4, items.len() as u64), items].concat()),
+        (
+            prop_oneof![Just(2_u64), Just(3), Just(42), any::<u64>()],
+            magnitude
+        )
+            .prop_map(|(tag, bytes)| {
+                [
+                    encode_head(6, tag),
+                    encode_head(2, bytes.len() as u64),
+                    bytes,
+                ]
+                .concat()
+            }),
+        proptest::sample::select(vec![vec![0xf4], vec![0xf5], vec![0xf6], vec![0xf7]]),
+    ]
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        Just(Mutation::None),
+        any::<usize>().prop_map(Mutation::Truncate),
+        (0_u8..8).prop_map(Mutation::MajorType),
+        prop_oneof![Just(u64::MAX), Just(1 << 32), any::<u64>()].prop_map(Mutation::Oversize),
+        Just(Mutation::Indefinite),
+        prop_oneof![Just(2_u64), Just(3), Just(42), any::<u64>()].prop_map(Mutation::Tag),
+        proptest::sample::select(vec![1, 2, 4, 8]).prop_map(Mutation::NonMinimal),
+        (any::<usize>(), 1_u8..=0xff).prop_map(|(at, mask)| Mutation::Flip(at, mask)),
+    ]
+}
+
+/// The helpers mishandling `data`, with their outcome.
+fn find_failures(env: &mut TestEnv, contract: &ContractHandle, data: &[u8]) -> Vec<Regression> {
+    HELPERS
+        .into_iter()
+        .map(|helper| Regression {
+            helper: helper.name().to_string(),
+            input: hex::encode(data),
+            outcome: classify(helper, env, contract, data),
+        })
+        .filter(|regression| regression.outcome.is_failure())
+        .collect()
+}
+
+#[test]
+fn cbor_decode_fuzz_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    let result = fuzzer.try_run(
+        "CBORDecoder",
+        (item(), mutation()),
+        |caller, (item, mutation)| {
+            let data = mutate(&item, &mutation);
+            let failures = find_failures(caller.env, caller.contract, &data);
+            if !failures.is_empty() {
+                return Err(TestCaseError::fail(format!("{:?}", failures)));
+            }
+
+            // `isNullNext` reads a single byte and only reverts on empty input
+            let call = contract_api::isNullNextCall {
+                cborData: data.clone().into(),
+            };
+            let res = caller.env.invoke(caller.contract, &call);
+            match data.first() {
+                Some(first) => prop_assert_eq!(
+                    decode_return::<contract_api::isNullNextCall>(&res)._0,
+                    *first == 0xf6
+                ),
+                None => prop_assert!(!res.msg_receipt.exit_code.is_success()),
+            }
+            Ok(())
+        },
+    );
+
+    match result {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, (item, mutation))) => {
+            let data = mutate(&item, &mutation);
+            let failures =
+                fuzzer.with_caller(|caller| find_failures(caller.env, caller.contract, &data));
+            save_regressions(failures);
+            panic!(
+                "CBORDecoder failed on {}: {}\nsaved to {}",
+                hex::encode(&data),
+                reason,
+                REGRESSIONS_PATH
+            );
+        }
+        Err(TestError::Abort(reason)) => panic!("CBORDecoder aborted: {}", reason),
+    }
+}
+
+#[test]
+fn cbor_decode_regression_tests() {
+    let mut env = TestEnv::new();
+    let contract = env.deploy_contract(CONTRACT_NAME);
+
+    for regression in load_regressions().regressions {
+        println!("Replaying {} on {}", regression.helper, regression.input);
+
+        let helper = Helper::from_name(&regression.helper).unwrap();
+        let data = hex::decode(&regression.input).unwrap();
+        let outcome = classify(helper, &mut env, &contract, &data);
+        assert!(
+            !outcome.is_failure(),
+            "{}({}) is still {:?}, was {:?}",
+            regression.helper,
+            regression.input,
+            outcome,
+            regression.outcome
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use fvm_shared::address::Address;
+use fvm_shared::bigint::BigInt;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::piece::PaddedPieceSize;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use testing::bindings;
+use testing::fuzz;
+
+fn label() -> impl Strategy<Value = fil_actor_market::Label> {
+    prop_oneof![
+        "\\PC{0,64}".prop_map(fil_actor_market::Label::String),
+        fuzz::bytes(64).prop_map(fil_actor_market::Label::Bytes),
+    ]
+}
+
+#[test]
+fn convert_tests() {
+    let zero = bindings::BigInt::from(TokenAmount::from_atto(0));
+    assert!(zero.val.is_empty() && !zero.neg);
+
+    let amount = bindings::BigInt::from(TokenAmount::from_atto(-0x100000));
+    assert_eq!(amount.val, vec![0x10, 0x00, 0x00]);
+    assert!(amount.neg);
+
+    // Leading zeros and negative zero, which the Solidity libraries may return
+    let padded = bindings::BigInt {
+        val: vec![0x00, 0x00, 0x64],
+        neg: false,
+    };
+    assert_eq!(TokenAmount::from(padded), TokenAmount::from_atto(100));
+    let negative_zero = bindings::BigInt {
+        val: vec![],
+        neg: true,
+    };
+    assert_eq!(BigInt::from(negative_zero), BigInt::from(0));
+
+    let address = bindings::FilAddress::from(Address::new_id(1000));
+    assert_eq!(address.data, vec![0x00, 0xe8, 0x07]);
+    assert!(Address::try_from(bindings::FilAddress { data: vec![] }).is_err());
+    assert!(cid::Cid::try_from(bindings::Cid { data: vec![0x01] }).is_err());
+
+    let label = bindings::DealLabel {
+        data: vec![0xff],
+        isString: true,
+    };
+    assert!(fil_actor_market::Label::try_from(&label).is_err());
+
+    let claim = bindings::Claim {
+        provider: 1,
+        client: 2,
+        data: vec![0x01],
+        size: 2048,
+        term_min: 0,
+        term_max: 0,
+        term_start: 0,
+        sector: 0,
+    };
+    assert!(fil_actor_verifreg::Claim::try_from(&claim).is_err());
+}
+
+proptest! {
+    #[test]
+    fn convert_bigint_round_trip(value in fuzz::bigint()) {
+        let sol = bindings::BigInt::from(&value);
+        // Minimal magnitudes, zero being empty
+        prop_assert_eq!(sol.val.is_empty(), value == BigInt::from(0));
+        prop_assert_ne!(sol.val.first(), Some(&0));
+        prop_assert_eq!(BigInt::from(&sol), value);
+    }
+
+    #[test]
+    fn convert_address_round_trip(address in fuzz::address()) {
+        let sol = bindings::FilAddress::from(&address);
+        prop_assert_eq!(&sol.data, &address.to_bytes());
+        prop_assert_eq!(Address::try_from(&sol).unwrap(), address);
+    }
+
+    #[test]
+    fn convert_cid_round_trip(cid in fuzz::cid()) {
+        let sol = bindings::Cid::from(&cid);
+        prop_assert_eq!(&sol.data, &cid.to_bytes());
+        prop_assert_eq!(cid::Cid::try_from(&sol).unwrap(), cid);
+    }
+
+    #[test]
+    fn convert_deal_proposal_round_trip(
+        (piece_cid, piece_size, verified_deal, client, provider) in
+            (fuzz::cid(), any::<u64>(), any::<bool>(), fuzz::address(), fuzz::address()),
+        (label, start_epoch, end_epoch) in (label(), fuzz::chain_epoch(), fuzz::chain_epoch()),
+        (storage_price_per_epoch, provider_collateral, client_collateral) in
+            (fuzz::token_amount(), fuzz::token_amount(), fuzz::token_amount()),
+    ) {
+        let proposal = fil_actor_market::DealProposal {
+            piece_cid,
+            piece_size: PaddedPieceSize(piece_size),
+            verified_deal,
+            client,
+            provider,
+            label,
+            start_epoch,
+            end_epoch,
+            storage_price_per_epoch,
+            provider_collateral,
+            client_collateral,
+        };
+
+        let sol = bindings::DealProposal::from(&proposal);
+        prop_assert_eq!(fil_actor_market::DealProposal::try_from(&sol).unwrap(), proposal);
+    }
+
+    #[test]
+    fn convert_miner_round_trip(
+        vesting_funds in vec((fuzz::chain_epoch(), fuzz::token_amount()), 0..8),
+        (beneficiary, quota, used_quota, expiration) in
+            (fuzz::address(), fuzz::token_amount(), fuzz::token_amount(), fuzz::chain_epoch()),
+    ) {
+        for funds in &vesting_funds {
+            let sol = bindings::VestingFunds::from(funds);
+            prop_assert_eq!(&<(i64, TokenAmount)>::from(&sol), funds);
+        }
+
+        let active = fil_actor_miner::ActiveBeneficiary {
+            beneficiary,
+            term: fil_actor_miner::BeneficiaryTerm {
+                quota,
+                used_quota,
+                expiration,
+            },
+        };
+        let sol = bindings::ActiveBeneficiary::from(&active);
+        prop_assert_eq!(fil_actor_miner::ActiveBeneficiary::try_from(&sol).unwrap(), active);
+    }
+
+    #[test]
+    fn convert_verifreg_round_trip(
+        (provider, client, data, size) in (any::<u64>(), any::<u64>(), fuzz::cid(), any::<u64>()),
+        (term_min, term_max, term_start, sector) in
+            (fuzz::chain_epoch(), fuzz::chain_epoch(), fuzz::chain_epoch(), any::<u64>()),
+        fail_codes in vec((any::<u32>(), any::<u32>()), 0..4),
+    ) {
+        let claim = fil_actor_verifreg::Claim {
+            provider,
+            client,
+            data,
+            size: PaddedPieceSize(size),
+            term_min,
+            term_max,
+            term_start,
+            sector,
+        };
+        let sol = bindings::Claim::from(&claim);
+        prop_assert_eq!(&sol.data, &fuzz::cid_link_bytes(&claim.data));
+        prop_assert_eq!(fil_actor_verifreg::Claim::try_from(&sol).unwrap(), claim);
+
+        let term = fil_actor_verifreg::ClaimTerm {
+            provider,
+            claim_id: client,
+            term_max,
+        };
+        let sol = bindings::ClaimTerm::from(&term);
+        prop_assert_eq!(fil_actor_verifreg::ClaimTerm::from(&sol), term);
+
+        let batch = fil_actors_runtime::BatchReturn {
+            success_count: fail_codes.len() as u32,
+            fail_codes: fail_codes
+                .into_iter()
+                .map(|(idx, code)| fil_actors_runtime::FailCode {
+                    idx,
+                    code: ExitCode::new(code),
+                })
+                .collect(),
+        };
+        let sol = bindings::BatchReturn::from(&batch);
+        prop_assert_eq!(fil_actors_runtime::BatchReturn::from(&sol), batch);
+    }
+}
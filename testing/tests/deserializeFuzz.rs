@@ -0,0 +1,560 @@
+use fil_actors_runtime::{BatchReturn, FailCode};
+use fvm_ipld_bitfield::BitField;
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::piece::PaddedPieceSize;
+use proptest::collection::{btree_set, vec};
+use proptest::prelude::*;
+use testing::bindings::DeserializeParamsTest as contract_api;
+use testing::fuzz::{self, sol_bigint, ContractFuzzer};
+
+const CONTRACT_NAME: &str = "DeserializeParamsTest";
+
+fn cbor<T: serde::Serialize>(value: &T) -> Vec<u8> {
+    fvm_ipld_encoding::to_vec(value).unwrap()
+}
+
+fn amount_eq(val: &[u8], neg: bool, amount: &TokenAmount) -> bool {
+    sol_bigint(val, neg) == *amount.atto()
+}
+
+fn batch_return() -> impl Strategy<Value = BatchReturn> {
+    (any::<u32>(), vec((any::<u32>(), fuzz::exit_code()), 0..4)).prop_map(|(success_count, fails)| {
+        BatchReturn {
+            success_count,
+            fail_codes: fails.into_iter().map(|(idx, code)| FailCode { idx, code }).collect(),
+        }
+    })
+}
+
+macro_rules! prop_assert_batch_return {
+    ($sol:expr, $batch:expr) => {
+        prop_assert_eq!($sol.success_count, $batch.success_count);
+        prop_assert_eq!($sol.fail_codes.len(), $batch.fail_codes.len());
+        for (sol, fail) in $sol.fail_codes.iter().zip(&$batch.fail_codes) {
+            prop_assert_eq!(sol.idx, fail.idx);
+            prop_assert_eq!(sol.code, fail.code.value());
+        }
+    };
+}
+
+fn label() -> impl Strategy<Value = fil_actor_market::Label> {
+    prop_oneof![
+        "\\PC{0,64}".prop_map(fil_actor_market::Label::String),
+        fuzz::bytes(64).prop_map(fil_actor_market::Label::Bytes),
+    ]
+}
+
+fn deal_proposal() -> impl Strategy<Value = fil_actor_market::DealProposal> {
+    (
+        (fuzz::cid(), any::<u64>(), any::<bool>(), fuzz::address(), fuzz::address()),
+        (label(), fuzz::chain_epoch(), fuzz::chain_epoch()),
+        (fuzz::token_amount(), fuzz::token_amount(), fuzz::token_amount()),
+    )
+        .prop_map(
+            |(
+                (piece_cid, piece_size, verified_deal, client, provider),
+                (label, start_epoch, end_epoch),
+                (storage_price_per_epoch, provider_collateral, client_collateral),
+            )| fil_actor_market::DealProposal {
+                piece_cid,
+                piece_size: PaddedPieceSize(piece_size),
+                verified_deal,
+                client,
+                provider,
+                label,
+                start_epoch,
+                end_epoch,
+                storage_price_per_epoch,
+                provider_collateral,
+                client_collateral,
+            },
+        )
+}
+
+#[test]
+fn deserialize_fuzz_market_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "deserializeGetBalanceReturn",
+        (fuzz::token_amount(), fuzz::token_amount()),
+        |caller, (balance, locked)| {
+            let ret = fil_actor_market::GetBalanceReturn { balance, locked };
+            let sol = caller
+                .call(&contract_api::deserializeGetBalanceReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert!(amount_eq(&sol.balance.val, sol.balance.neg, &ret.balance));
+            prop_assert!(amount_eq(&sol.locked.val, sol.locked.neg, &ret.locked));
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeGetDealDataCommitmentReturn",
+        (fuzz::cid(), any::<u64>()),
+        |caller, (data, size)| {
+            let ret = fil_actor_market::GetDealDataCommitmentReturn {
+                data,
+                size: PaddedPieceSize(size),
+            };
+            let sol = caller
+                .call(&contract_api::deserializeGetDealDataCommitmentReturnCall {
+                    params: cbor(&ret),
+                })?
+                ._0;
+
+            prop_assert_eq!(sol.data, fuzz::cid_link_bytes(&ret.data));
+            prop_assert_eq!(sol.size, ret.size.0);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeGetDealTermReturn",
+        (fuzz::chain_epoch(), fuzz::chain_epoch()),
+        |caller, (start, duration)| {
+            let ret = fil_actor_market::GetDealTermReturn { start, duration };
+            let sol = caller
+                .call(&contract_api::deserializeGetDealTermReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert_eq!(sol.start, ret.start);
+            prop_assert_eq!(sol.duration, ret.duration);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeGetDealActivationReturn",
+        (fuzz::chain_epoch(), fuzz::chain_epoch()),
+        |caller, (activated, terminated)| {
+            let ret = fil_actor_market::GetDealActivationReturn {
+                activated,
+                terminated,
+            };
+            let sol = caller
+                .call(&contract_api::deserializeGetDealActivationReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert_eq!(sol.activated, ret.activated);
+            prop_assert_eq!(sol.terminated, ret.terminated);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializePublishStorageDealsReturn",
+        (vec(any::<u64>(), 0..8), btree_set(0_u64..1024, 0..8)),
+        |caller, (ids, valid)| {
+            let ret = fil_actor_market::PublishStorageDealsReturn {
+                ids,
+                valid_deals: BitField::try_from_bits(valid).unwrap(),
+            };
+            let sol = caller
+                .call(&contract_api::deserializePublishStorageDealsReturnCall {
+                    params: cbor(&ret),
+                })?
+                ._0;
+
+            // The bitfield is handed over as its RLE+ bytes
+            let rle: BytesDe = fvm_ipld_encoding::from_slice(&cbor(&ret.valid_deals)).unwrap();
+            prop_assert_eq!(sol.ids, ret.ids);
+            prop_assert_eq!(sol.valid_deals, rle.0);
+            Ok(())
+        },
+    );
+
+    fuzzer.run("deserializeDealProposal", deal_proposal(), |caller, proposal| {
+        let sol = caller
+            .call(&contract_api::deserializeDealProposalCall { params: cbor(&proposal) })?
+            ._0;
+
+        prop_assert_eq!(sol.piece_cid.data, proposal.piece_cid.to_bytes());
+        prop_assert_eq!(sol.piece_size, proposal.piece_size.0);
+        prop_assert_eq!(sol.verified_deal, proposal.verified_deal);
+        prop_assert_eq!(sol.client.data, proposal.client.to_bytes());
+        prop_assert_eq!(sol.provider.data, proposal.provider.to_bytes());
+        match &proposal.label {
+            fil_actor_market::Label::String(label) => {
+                prop_assert!(sol.label.isString);
+                prop_assert_eq!(&sol.label.data, label.as_bytes());
+            }
+            fil_actor_market::Label::Bytes(label) => {
+                prop_assert!(!sol.label.isString);
+                prop_assert_eq!(&sol.label.data, label);
+            }
+        }
+        prop_assert_eq!(sol.start_epoch, proposal.start_epoch);
+        prop_assert_eq!(sol.end_epoch, proposal.end_epoch);
+        let price = &sol.storage_price_per_epoch;
+        prop_assert!(amount_eq(&price.val, price.neg, &proposal.storage_price_per_epoch));
+        let collateral = &sol.provider_collateral;
+        prop_assert!(amount_eq(&collateral.val, collateral.neg, &proposal.provider_collateral));
+        let collateral = &sol.client_collateral;
+        prop_assert!(amount_eq(&collateral.val, collateral.neg, &proposal.client_collateral));
+        Ok(())
+    });
+
+    fuzzer.run(
+        "deserializeMarketDealNotifyParams",
+        (deal_proposal(), any::<u64>()),
+        |caller, (proposal, deal_id)| {
+            let params = fil_actor_market::MarketNotifyDealParams {
+                proposal: cbor(&proposal),
+                deal_id,
+            };
+            let sol = caller
+                .call(&contract_api::deserializeMarketDealNotifyParamsCall {
+                    params: cbor(&params),
+                })?
+                ._0;
+
+            prop_assert_eq!(sol.dealProposal, params.proposal);
+            prop_assert_eq!(sol.dealId, params.deal_id);
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn deserialize_fuzz_miner_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "deserializeGetOwnerReturn",
+        (fuzz::address(), proptest::option::of(fuzz::address())),
+        |caller, (owner, proposed)| {
+            let ret = fil_actor_miner::GetOwnerReturn { owner, proposed };
+            let sol = caller
+                .call(&contract_api::deserializeGetOwnerReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert_eq!(sol.owner.data, ret.owner.to_bytes());
+            prop_assert_eq!(sol.proposed.data, ret.proposed.map(|a| a.to_bytes()).unwrap_or_default());
+            Ok(())
+        },
+    );
+
+    let pending = (
+        fuzz::address(),
+        fuzz::token_amount(),
+        fuzz::chain_epoch(),
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_map(|(new_beneficiary, new_quota, new_expiration, by_beneficiary, by_nominee)| {
+            fil_actor_miner::PendingBeneficiaryChange {
+                new_beneficiary,
+                new_quota,
+                new_expiration,
+                approved_by_beneficiary: by_beneficiary,
+                approved_by_nominee: by_nominee,
+            }
+        });
+    fuzzer.run(
+        "deserializeGetBeneficiaryReturn",
+        (
+            fuzz::address(),
+            fuzz::token_amount(),
+            fuzz::token_amount(),
+            fuzz::chain_epoch(),
+            proptest::option::of(pending),
+        ),
+        |caller, (beneficiary, quota, used_quota, expiration, proposed)| {
+            let ret = fil_actor_miner::GetBeneficiaryReturn {
+                active: fil_actor_miner::ActiveBeneficiary {
+                    beneficiary,
+                    term: fil_actor_miner::BeneficiaryTerm {
+                        quota,
+                        used_quota,
+                        expiration,
+                    },
+                },
+                proposed,
+            };
+            let sol = caller
+                .call(&contract_api::deserializeGetBeneficiaryReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            let term = &sol.active.term;
+            prop_assert_eq!(&sol.active.beneficiary.data, &ret.active.beneficiary.to_bytes());
+            prop_assert!(amount_eq(&term.quota.val, term.quota.neg, &ret.active.term.quota));
+            prop_assert!(amount_eq(
+                &term.used_quota.val,
+                term.used_quota.neg,
+                &ret.active.term.used_quota
+            ));
+            prop_assert_eq!(term.expiration, ret.active.term.expiration);
+
+            // An absent change decodes to the zero value of the struct
+            let proposed = &sol.proposed;
+            match &ret.proposed {
+                Some(change) => {
+                    prop_assert_eq!(&proposed.new_beneficiary.data, &change.new_beneficiary.to_bytes());
+                    prop_assert!(amount_eq(
+                        &proposed.new_quota.val,
+                        proposed.new_quota.neg,
+                        &change.new_quota
+                    ));
+                    prop_assert_eq!(proposed.new_expiration, change.new_expiration);
+                    prop_assert_eq!(proposed.approved_by_beneficiary, change.approved_by_beneficiary);
+                    prop_assert_eq!(proposed.approved_by_nominee, change.approved_by_nominee);
+                }
+                None => {
+                    prop_assert!(proposed.new_beneficiary.data.is_empty());
+                    prop_assert!(proposed.new_quota.val.is_empty());
+                    prop_assert_eq!(proposed.new_expiration, 0);
+                    prop_assert!(!proposed.approved_by_beneficiary && !proposed.approved_by_nominee);
+                }
+            }
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeGetVestingFundsReturn",
+        vec((fuzz::chain_epoch(), fuzz::token_amount()), 0..8),
+        |caller, vesting_funds| {
+            let ret = fil_actor_miner::GetVestingFundsReturn { vesting_funds };
+            let sol = caller
+                .call(&contract_api::deserializeGetVestingFundsReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert_eq!(sol.len(), ret.vesting_funds.len());
+            for (sol, (epoch, amount)) in sol.iter().zip(&ret.vesting_funds) {
+                prop_assert_eq!(sol.epoch, *epoch);
+                prop_assert!(amount_eq(&sol.amount.val, sol.amount.neg, amount));
+            }
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeGetMultiaddrsReturn",
+        vec(fuzz::bytes(64), 0..8),
+        |caller, multi_addrs| {
+            let ret = fil_actor_miner::GetMultiaddrsReturn {
+                multi_addrs: multi_addrs.into_iter().map(BytesDe).collect(),
+            };
+            let sol = caller
+                .call(&contract_api::deserializeGetMultiaddrsReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            let sol: Vec<Vec<u8>> = sol.into_iter().map(|addr| addr.data).collect();
+            let multi_addrs: Vec<Vec<u8>> = ret.multi_addrs.into_iter().map(|addr| addr.0).collect();
+            prop_assert_eq!(sol, multi_addrs);
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn deserialize_fuzz_power_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "deserializeCreateMinerReturn",
+        (fuzz::id_address(), fuzz::address()),
+        |caller, (id_address, robust_address)| {
+            let ret = fil_actor_power::CreateMinerReturn {
+                id_address,
+                robust_address,
+            };
+            let sol = caller
+                .call(&contract_api::deserializeCreateMinerReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert_eq!(sol.id_address.data, ret.id_address.to_bytes());
+            prop_assert_eq!(sol.robust_address.data, ret.robust_address.to_bytes());
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeMinerRawPowerReturn",
+        (fuzz::bigint(), any::<bool>()),
+        |caller, (raw_byte_power, meets_consensus_minimum)| {
+            let ret = fil_actor_power::MinerRawPowerReturn {
+                raw_byte_power,
+                meets_consensus_minimum,
+            };
+            let sol = caller
+                .call(&contract_api::deserializeMinerRawPowerReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            let power = &sol.raw_byte_power;
+            prop_assert_eq!(sol_bigint(&power.val, power.neg), ret.raw_byte_power);
+            prop_assert_eq!(sol.meets_consensus_minimum, ret.meets_consensus_minimum);
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn deserialize_fuzz_verifreg_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    let claim = (
+        (any::<u64>(), any::<u64>(), fuzz::cid(), any::<u64>()),
+        (fuzz::chain_epoch(), fuzz::chain_epoch(), fuzz::chain_epoch(), any::<u64>()),
+    )
+        .prop_map(
+            |((provider, client, data, size), (term_min, term_max, term_start, sector))| {
+                fil_actor_verifreg::Claim {
+                    provider,
+                    client,
+                    data,
+                    size: PaddedPieceSize(size),
+                    term_min,
+                    term_max,
+                    term_start,
+                    sector,
+                }
+            },
+        );
+    fuzzer.run(
+        "deserializeGetClaimsReturn",
+        (batch_return(), vec(claim, 0..4)),
+        |caller, (batch_info, claims)| {
+            let ret = fil_actor_verifreg::GetClaimsReturn { batch_info, claims };
+            let sol = caller
+                .call(&contract_api::deserializeGetClaimsReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert_batch_return!(sol.batch_info, ret.batch_info);
+            prop_assert_eq!(sol.claims.len(), ret.claims.len());
+            for (sol, claim) in sol.claims.iter().zip(&ret.claims) {
+                prop_assert_eq!(sol.provider, claim.provider);
+                prop_assert_eq!(sol.client, claim.client);
+                prop_assert_eq!(&sol.data, &fuzz::cid_link_bytes(&claim.data));
+                prop_assert_eq!(sol.size, claim.size.0);
+                prop_assert_eq!(sol.term_min, claim.term_min);
+                prop_assert_eq!(sol.term_max, claim.term_max);
+                prop_assert_eq!(sol.term_start, claim.term_start);
+                prop_assert_eq!(sol.sector, claim.sector);
+            }
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeRemoveExpiredAllocationsReturn",
+        (vec(any::<u64>(), 0..8), batch_return(), fuzz::token_amount()),
+        |caller, (considered, results, datacap_recovered)| {
+            let ret = fil_actor_verifreg::RemoveExpiredAllocationsReturn {
+                considered,
+                results,
+                datacap_recovered,
+            };
+            let sol = caller
+                .call(&contract_api::deserializeRemoveExpiredAllocationsReturnCall {
+                    params: cbor(&ret),
+                })?
+                ._0;
+
+            prop_assert_eq!(&sol.considered, &ret.considered);
+            prop_assert_batch_return!(sol.results, ret.results);
+            let recovered = &sol.datacap_recovered;
+            prop_assert!(amount_eq(&recovered.val, recovered.neg, &ret.datacap_recovered));
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeRemoveExpiredClaimsReturn",
+        (vec(any::<u64>(), 0..8), batch_return()),
+        |caller, (considered, results)| {
+            let ret = fil_actor_verifreg::RemoveExpiredClaimsReturn {
+                considered,
+                results,
+            };
+            let sol = caller
+                .call(&contract_api::deserializeRemoveExpiredClaimsReturnCall {
+                    params: cbor(&ret),
+                })?
+                ._0;
+
+            prop_assert_eq!(&sol.considered, &ret.considered);
+            prop_assert_batch_return!(sol.results, ret.results);
+            Ok(())
+        },
+    );
+
+    fuzzer.run("deserializeBatchReturn", batch_return(), |caller, ret| {
+        let sol = caller
+            .call(&contract_api::deserializeBatchReturnCall { params: cbor(&ret) })?
+            ._0;
+
+        prop_assert_batch_return!(sol, ret);
+        Ok(())
+    });
+}
+
+#[test]
+fn deserialize_fuzz_datacap_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "deserializeTransferReturn",
+        (fuzz::token_amount(), fuzz::token_amount(), fuzz::bytes(64)),
+        |caller, (from_balance, to_balance, recipient_data)| {
+            let ret = frc46_token::token::types::TransferReturn {
+                from_balance,
+                to_balance,
+                recipient_data: RawBytes::new(recipient_data),
+            };
+            let sol = caller
+                .call(&contract_api::deserializeTransferReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert!(amount_eq(&sol.from_balance.val, sol.from_balance.neg, &ret.from_balance));
+            prop_assert!(amount_eq(&sol.to_balance.val, sol.to_balance.neg, &ret.to_balance));
+            prop_assert_eq!(sol.recipient_data, ret.recipient_data.bytes().to_vec());
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeTransferFromReturn",
+        (
+            fuzz::token_amount(),
+            fuzz::token_amount(),
+            fuzz::token_amount(),
+            fuzz::bytes(64),
+        ),
+        |caller, (from_balance, to_balance, allowance, recipient_data)| {
+            let ret = frc46_token::token::types::TransferFromReturn {
+                from_balance,
+                to_balance,
+                allowance,
+                recipient_data: RawBytes::new(recipient_data),
+            };
+            let sol = caller
+                .call(&contract_api::deserializeTransferFromReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert!(amount_eq(&sol.from_balance.val, sol.from_balance.neg, &ret.from_balance));
+            prop_assert!(amount_eq(&sol.to_balance.val, sol.to_balance.neg, &ret.to_balance));
+            prop_assert!(amount_eq(&sol.allowance.val, sol.allowance.neg, &ret.allowance));
+            prop_assert_eq!(sol.recipient_data, ret.recipient_data.bytes().to_vec());
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "deserializeBurnFromReturn",
+        (fuzz::token_amount(), fuzz::token_amount()),
+        |caller, (balance, allowance)| {
+            let ret = frc46_token::token::types::BurnFromReturn { balance, allowance };
+            let sol = caller
+                .call(&contract_api::deserializeBurnFromReturnCall { params: cbor(&ret) })?
+                ._0;
+
+            prop_assert!(amount_eq(&sol.balance.val, sol.balance.neg, &ret.balance));
+            prop_assert!(amount_eq(&sol.allowance.val, sol.allowance.neg, &ret.allowance));
+            Ok(())
+        },
+    );
+}
@@ -1,86 +1,119 @@
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::EAM_ACTOR_ADDR;
-use fvm::executor::{ApplyKind, Executor};
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
-use fvm_ipld_encoding::strict_bytes;
-use fvm_ipld_encoding::RawBytes;
-use fvm_shared::address::Address;
-use fvm_shared::message::Message;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-use alloy_primitives::{fixed_bytes};
-use alloy_sol_types::{SolCall};
-
-use testing::{setup, api_contracts};
-
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/DeserializeParamsTest.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+use fvm::executor::ApplyRet;
+use testing::bindings::DeserializeParamsTest as contract_api;
+use testing::env::{ContractHandle, TestEnv};
+use testing::vectors;
+
+const CONTRACT_NAME: &str = "DeserializeParamsTest";
+
+fn deserialize(
+    env: &mut TestEnv,
+    contract: &ContractHandle,
+    function: &str,
+    params: Vec<u8>,
+) -> ApplyRet {
+    match function {
+        "deserializeGetBalanceReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetBalanceReturnCall { params })
+        }
+        "deserializeGetDealDataCommitmentReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetDealDataCommitmentReturnCall { params })
+        }
+        "deserializeGetDealTermReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetDealTermReturnCall { params })
+        }
+        "deserializeGetDealActivationReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetDealActivationReturnCall { params })
+        }
+        "deserializePublishStorageDealsReturn" => {
+            env.invoke(contract, &contract_api::deserializePublishStorageDealsReturnCall { params })
+        }
+        "deserializeDealProposal" => {
+            env.invoke(contract, &contract_api::deserializeDealProposalCall { params })
+        }
+        "deserializeMarketDealNotifyParams" => {
+            env.invoke(contract, &contract_api::deserializeMarketDealNotifyParamsCall { params })
+        }
+        "deserializeGetOwnerReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetOwnerReturnCall { params })
+        }
+        "deserializeGetBeneficiaryReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetBeneficiaryReturnCall { params })
+        }
+        "deserializeGetVestingFundsReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetVestingFundsReturnCall { params })
+        }
+        "deserializeGetMultiaddrsReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetMultiaddrsReturnCall { params })
+        }
+        "deserializeCreateMinerReturn" => {
+            env.invoke(contract, &contract_api::deserializeCreateMinerReturnCall { params })
+        }
+        "deserializeMinerRawPowerReturn" => {
+            env.invoke(contract, &contract_api::deserializeMinerRawPowerReturnCall { params })
+        }
+        "deserializeGetClaimsReturn" => {
+            env.invoke(contract, &contract_api::deserializeGetClaimsReturnCall { params })
+        }
+        "deserializeRemoveExpiredAllocationsReturn" => {
+            env.invoke(contract, &contract_api::deserializeRemoveExpiredAllocationsReturnCall { params })
+        }
+        "deserializeRemoveExpiredClaimsReturn" => {
+            env.invoke(contract, &contract_api::deserializeRemoveExpiredClaimsReturnCall { params })
+        }
+        "deserializeBatchReturn" => {
+            env.invoke(contract, &contract_api::deserializeBatchReturnCall { params })
+        }
+        "deserializeTransferReturn" => {
+            env.invoke(contract, &contract_api::deserializeTransferReturnCall { params })
+        }
+        "deserializeTransferFromReturn" => {
+            env.invoke(contract, &contract_api::deserializeTransferFromReturnCall { params })
+        }
+        "deserializeBurnFromReturn" => {
+            env.invoke(contract, &contract_api::deserializeBurnFromReturnCall { params })
+        }
+        "deserializeUniversalReceiverParams" => {
+            env.invoke(contract, &contract_api::deserializeUniversalReceiverParamsCall { params })
+        }
+        _ => panic!("{} has no `{}`", CONTRACT_NAME, function),
+    }
+}
 
 #[test]
 fn deserialize_params_tests() {
     println!("Testing solidity API");
 
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
+    let file = vectors::load(&vectors::default_path()).unwrap();
 
-    let executor = tester.executor.as_mut().unwrap();
+    let mut env = TestEnv::new();
 
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `deserializeGetVestingFundsReturn`");
-
-    let abi_encoded_call = api_contracts::deserialize_params_test::deserializeGetVestingFundsReturnCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode(
-                // "4407854007"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
\ No newline at end of file
+    let contract = env.deploy_contract(CONTRACT_NAME);
+
+    for vector in file.vectors.iter().filter(|vector| vector.is_deserializer()) {
+        println!("Calling `{}` with {}", vector.function(), vector.name);
+
+        let res = deserialize(&mut env, &contract, vector.function(), vector.bytes());
+
+        assert_eq!(res.msg_receipt.exit_code.value(), 0, "{}", vector.name);
+    }
+
+    // Spot checks of the decoded values
+    let vector = file.get("GetVestingFundsReturn/several").unwrap();
+    let vesting_funds = env
+        .invoke_decoded(
+            &contract,
+            &contract_api::deserializeGetVestingFundsReturnCall { params: vector.bytes() },
+        )
+        ._0;
+    assert_eq!(vesting_funds.len(), 2);
+    assert_eq!(vesting_funds[1].epoch, 200);
+
+    let vector = file.get("GetOwnerReturn/no_proposed").unwrap();
+    let owner = env
+        .invoke_decoded(&contract, &contract_api::deserializeGetOwnerReturnCall { params: vector.bytes() })
+        ._0;
+    assert_eq!(owner.owner.data, vec![0x00, 0xe8, 0x07]);
+    assert!(owner.proposed.data.is_empty());
+}
@@ -0,0 +1,108 @@
+use fvm::gas::{Gas, GasCharge};
+use fvm::trace::ExecutionEvent;
+use fvm_shared::address::Address;
+use fvm_shared::bigint::Zero;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use testing::gas::{parse_flag, GasBaseline, GasDiff, GasReport};
+use testing::parse_gas;
+
+fn call(from: u64, to: u64) -> ExecutionEvent {
+    ExecutionEvent::Call {
+        from,
+        to: Address::new_id(to),
+        method: 2,
+        params: None,
+        value: TokenAmount::default(),
+        gas_limit: 1000000000,
+        read_only: false,
+    }
+}
+
+fn charge(name: &'static str, compute: i64, other: i64) -> ExecutionEvent {
+    ExecutionEvent::GasCharge(GasCharge::new(name, Gas::new(compute), Gas::new(other)))
+}
+
+#[test]
+fn gas_report_tests() {
+    let exec_trace = vec![
+        charge("OnChainMessage", 10, 100),
+        call(100, 1001),
+        charge("wasm_exec", 50, 0),
+        charge("OnBlockOpen", 5, 20),
+        call(1001, 5),
+        charge("wasm_exec", 70, 0),
+        ExecutionEvent::CallReturn(ExitCode::OK, None),
+        charge("wasm_exec", 30, 0),
+        ExecutionEvent::CallReturn(ExitCode::OK, None),
+    ];
+
+    let report = GasReport::from_trace(&exec_trace);
+
+    // The callee builtin actor is left out of the total, like parse_gas always did
+    assert_eq!(report.total(), 10 + 100 + 50 + 5 + 20 + 30);
+    assert_eq!(report.total(), parse_gas(exec_trace.clone()));
+
+    let wasm_exec = &report.entries[&(1, "wasm_exec".to_string())];
+    assert_eq!(wasm_exec.count, 2);
+    assert_eq!(wasm_exec.compute_gas, Gas::new(80));
+    assert_eq!(wasm_exec.other_gas, Gas::zero());
+
+    let block_open = &report.entries[&(1, "OnBlockOpen".to_string())];
+    assert_eq!(block_open.compute_gas, Gas::new(5));
+    assert_eq!(block_open.other_gas, Gas::new(20));
+
+    assert_eq!(report.total_at_depth(2), Gas::new(70));
+    assert_eq!(report.by_name("wasm_exec").total(), Gas::new(150));
+}
+
+#[test]
+fn gas_baseline_tests() {
+    let baseline = GasBaseline {
+        entries: vec![
+            ("get_deal_label".to_string(), 1000, None),
+            ("get_deal_term".to_string(), 1000, Some(30.0)),
+            ("get_deal_client".to_string(), 1000, None),
+        ],
+    };
+    let gas_result = vec![
+        ("get_deal_label".to_string(), 1200),
+        ("get_deal_term".to_string(), 1200),
+        ("get_balance".to_string(), 500),
+    ];
+
+    let diff = GasDiff::compare(&baseline, &gas_result, 5.0);
+
+    let label = &diff.rows[0];
+    assert_eq!(label.delta(), Some(200));
+    assert_eq!(label.percent(), Some(20.0));
+    assert!(label.exceeds_threshold());
+
+    // Its own threshold allows 30%
+    assert!(!diff.rows[1].exceeds_threshold());
+
+    // New and removed functions are reported, never regressions
+    assert_eq!(diff.rows[2].baseline, None);
+    assert_eq!(diff.rows[3].function, "get_deal_client");
+    assert_eq!(diff.rows[3].current, None);
+
+    let regressions: Vec<&str> = diff.regressions().iter().map(|row| row.function.as_str()).collect();
+    assert_eq!(regressions, vec!["get_deal_label"]);
+
+    let path = std::env::temp_dir().join(format!("gas_baseline_{}.csv", std::process::id()));
+    let updated = baseline.updated(&gas_result);
+    updated.save(&path).unwrap();
+    assert_eq!(GasBaseline::load(&path).unwrap(), Some(updated));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn gas_update_flag_tests() {
+    for value in ["1", "true", "TRUE", "yes", "on"] {
+        assert_eq!(parse_flag(value), Some(true), "{}", value);
+    }
+    for value in ["", "0", "false", "no", "off"] {
+        assert_eq!(parse_flag(value), Some(false), "{}", value);
+    }
+    assert_eq!(parse_flag("2"), None);
+}
@@ -1,958 +1,109 @@
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::EAM_ACTOR_ADDR;
-use fvm::executor::{ApplyKind, Executor};
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
-use fvm_ipld_encoding::strict_bytes;
-use fvm_ipld_encoding::RawBytes;
-use fvm_shared::address::Address;
-use fvm_shared::econ::TokenAmount;
-use fvm_shared::message::Message;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-
-use testing::setup;
-
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/Leb128Test.bin";
-const WASM_COMPILED_GENERATED1_PATH: &str = "../build/v0.8/tests/Leb128Generated1Test.bin";
-const WASM_COMPILED_GENERATED2_PATH: &str = "../build/v0.8/tests/Leb128Generated2Test.bin";
-const WASM_COMPILED_GENERATED3_PATH: &str = "../build/v0.8/tests/Leb128Generated3Test.bin";
-const WASM_COMPILED_GENERATED4_PATH: &str = "../build/v0.8/tests/Leb128Generated4Test.bin";
-const WASM_COMPILED_GENERATED5_PATH: &str = "../build/v0.8/tests/Leb128Generated5Test.bin";
-const WASM_COMPILED_GENERATED6_PATH: &str = "../build/v0.8/tests/Leb128Generated6Test.bin";
-const WASM_COMPILED_GENERATED7_PATH: &str = "../build/v0.8/tests/Leb128Generated7Test.bin";
-const WASM_COMPILED_GENERATED8_PATH: &str = "../build/v0.8/tests/Leb128Generated8Test.bin";
-const WASM_COMPILED_GENERATED9_PATH: &str = "../build/v0.8/tests/Leb128Generated9Test.bin";
-const WASM_COMPILED_GENERATED10_PATH: &str = "../build/v0.8/tests/Leb128Generated10Test.bin";
-const WASM_COMPILED_GENERATED11_PATH: &str = "../build/v0.8/tests/Leb128Generated11Test.bin";
-const WASM_COMPILED_GENERATED12_PATH: &str = "../build/v0.8/tests/Leb128Generated12Test.bin";
-const WASM_COMPILED_GENERATED13_PATH: &str = "../build/v0.8/tests/Leb128Generated13Test.bin";
-const WASM_COMPILED_GENERATED14_PATH: &str = "../build/v0.8/tests/Leb128Generated14Test.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
-
-#[test]
-fn leb128_tests() {
-    println!("Testing Leb128 lib");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("4439f87ced").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated1_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED1_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated2_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED2_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated3_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED3_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated4_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED4_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated5_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED5_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated6_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED6_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated7_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED7_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated8_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED8_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated9_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED9_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-}
-
-#[test]
-fn leb128_generated10_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED10_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+use alloy_primitives::U256;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use testing::bindings::Leb128Test as contract_api;
+use testing::env::TestEnv;
+use testing::fuzz::{self, ContractFuzzer};
+
+const CONTRACT_NAME: &str = "Leb128Test";
+
+fn reference_encode(value: u64) -> Vec<u8> {
+    let mut bytes = vec![];
+    leb128::write::unsigned(&mut bytes, value).unwrap();
+    bytes
 }
 
-#[test]
-fn leb128_generated11_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED11_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    dbg!(&res.failure_info);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 1,
-        params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+/// The decoded value and the number of bytes read, `None` for truncated or overflowing input.
+fn reference_decode(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut reader = bytes;
+    let value = leb128::read::unsigned(&mut reader).ok()?;
+    Some((value, bytes.len() - reader.len()))
 }
 
-
-
-#[test]
-fn leb128_generated12_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED12_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    dbg!(&res.failure_info);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 1,
-        params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+/// Values on both sides of every 7 bits group boundary, and the extremes of `u64`.
+fn boundary_values() -> Vec<u64> {
+    let mut values = vec![0, 1, 624485, u64::MAX];
+    for bits in (7..64).step_by(7) {
+        values.push((1 << bits) - 1);
+        values.push(1 << bits);
+    }
+    values.push(1 << 63);
+    values
 }
 
 #[test]
-fn leb128_generated13_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
+fn leb128_tests() {
+    println!("Testing Leb128 lib");
 
-    let executor = tester.executor.as_mut().unwrap();
+    let mut env = TestEnv::new();
 
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED13_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
+    println!("Calling `unsiged_integer_leb128_encoding`");
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    dbg!(&res.failure_info);
+    let res = env.invoke(&contract, &contract_api::unsiged_integer_leb128_encodingCall {});
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
+    for value in boundary_values() {
+        println!("Encoding and decoding {}", value);
 
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
+        let expected = reference_encode(value);
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 1,
-        params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-        ..Message::default()
-    };
+        let encoded = env
+            .invoke_decoded(&contract, &contract_api::encodeUnsignedLeb128FromUInt64Call { value })
+            ._0;
+        assert_eq!(encoded, expected, "{} encodes to {}", value, hex::encode(&encoded));
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+        let decoded = env.invoke_decoded(
+            &contract,
+            &contract_api::decodeUnsignedLeb128ToUInt64Call { data: expected.clone() },
+        );
+        assert_eq!(decoded._0, value);
+        assert_eq!(decoded._1, U256::from(expected.len()));
+    }
 }
 
-
 #[test]
-fn leb128_generated14_tests() {
-    println!("Testing Leb128 lib with more tests");
-
-    let (mut tester, _manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_GENERATED14_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    dbg!(&res.failure_info);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-
-    let contract_actor_id = exec_return.actor_id;
-
-    println!("Calling `encodeUnsignedLeb128FromUInt64`");
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 1,
-        params: RawBytes::new(hex::decode("44cb3dcfa7").unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    // show gas usage for curiosity
-    dbg!(&res.msg_receipt.gas_used);
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+fn leb128_fuzz_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "encodeUnsignedLeb128FromUInt64",
+        prop_oneof![any::<u64>(), proptest::sample::select(boundary_values())],
+        |caller, value| {
+            let encoded = caller
+                .call(&contract_api::encodeUnsignedLeb128FromUInt64Call { value })?
+                ._0;
+            prop_assert_eq!(encoded, reference_encode(value));
+            Ok(())
+        },
+    );
+
+    // Arbitrary bytes, and runs of continuation groups reaching truncated input, overflowing
+    // tenth groups and the non-minimal encodings the reference accepts
+    let leb128_like = (vec(0x80_u8..=0xff, 0..12), proptest::option::of(any::<u8>())).prop_map(
+        |(mut groups, last)| {
+            groups.extend(last);
+            groups
+        },
+    );
+    let data = prop_oneof![fuzz::bytes(12), leb128_like];
+    fuzzer.run("decodeUnsignedLeb128ToUInt64", data, |caller, data| {
+        let call = contract_api::decodeUnsignedLeb128ToUInt64Call { data: data.clone() };
+        match reference_decode(&data) {
+            Some((value, len)) => {
+                let decoded = caller.call(&call)?;
+                prop_assert_eq!(decoded._0, value);
+                prop_assert_eq!(decoded._1, U256::from(len));
+            }
+            None => {
+                let res = caller.env.invoke(caller.contract, &call);
+                prop_assert!(!res.msg_receipt.exit_code.is_success(), "{} decoded", hex::encode(&data));
+            }
+        }
+        Ok(())
+    });
 }
-
@@ -1,123 +1,49 @@
 use bls_signatures::Serialize;
 use cid::Cid;
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::cbor;
-use fil_actors_runtime::{
-    runtime::builtins, EAM_ACTOR_ADDR, REWARD_ACTOR_ADDR, STORAGE_MARKET_ACTOR_ADDR,
-    STORAGE_POWER_ACTOR_ADDR, SYSTEM_ACTOR_ADDR,
-};
-use fvm::executor::{ApplyKind, Executor};
+use fvm::executor::ApplyRet;
 use fvm::state_tree::ActorState;
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
 use fvm_ipld_encoding::BytesDe;
-use fvm_ipld_encoding::BytesSer;
 use fvm_ipld_encoding::CborStore;
 use fvm_ipld_encoding::RawBytes;
 use fvm_ipld_encoding::{serde_bytes, strict_bytes, tuple::*};
 use fvm_shared::address::Address;
 use fvm_shared::clock::ChainEpoch;
-use fvm_shared::crypto::signature::Signature;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::message::Message;
 use fvm_shared::piece::PaddedPieceSize;
 use fvm_shared::sector::RegisteredPoStProof;
-use libipld_core::ipld::Ipld;
 use rand_core::OsRng;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
 use std::str::FromStr;
+use std::sync::OnceLock;
 
-use alloy_primitives::{fixed_bytes, I256, U256, keccak256};
-use alloy_json_abi::{JsonAbi, AbiItem};
-use alloy_sol_types::SolType;
-use alloy_sol_types::{SolCall};
-use cbor_data::{CborBuilder, Encoder};
-use libipld_core::multibase::Base;
-use multihash::{Code, MultihashDigest};
-
-use testing::api_contracts;
-use testing::helpers;
-use testing::parse_gas;
-use testing::setup;
-use testing::GasResult;
+use alloy_primitives::{I256, U256};
+use multihash::Code;
 
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/MarketApiTest.bin";
+use testing::bindings;
+use testing::consts;
+use testing::env::{decode_return, invoke_message, ContractHandle, Snapshot, TestEnv};
+use testing::gas::{save_gas_reports, GasReport};
+use testing::gas_limit::{call_gas_limit, gas_limit_table, min_gas_limits};
+use testing::revert::{expect_revert, NonZeroExitCode};
+use testing::GasResult;
 
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+const CONTRACT_NAME: &str = "MarketApiTest";
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum Label {
-    String(String),
-    Bytes(Vec<u8>),
-}
-
-/// Serialize the Label like an untagged enum.
-impl serde::Serialize for Label {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        match self {
-            Label::String(v) => v.serialize(serializer),
-            Label::Bytes(v) => BytesSer(v).serialize(serializer),
-        }
-    }
-}
-
-/// Deserialize the Label like an untagged enum.
-impl<'de> serde::Deserialize<'de> for Label {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        Ipld::deserialize(deserializer)
-            .and_then(|ipld| ipld.try_into().map_err(serde::de::Error::custom))
-    }
-}
+// The client signs the deal proposals, its key is fixed so they can be signed again later.
+const CLIENT_PRIVATE_KEY: &str = "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef";
 
-impl TryFrom<Ipld> for Label {
-    type Error = String;
-
-    fn try_from(ipld: Ipld) -> Result<Self, Self::Error> {
-        match ipld {
-            Ipld::String(s) => Ok(Label::String(s)),
-            Ipld::Bytes(b) => Ok(Label::Bytes(b)),
-            other => Err(format!(
-                "Expected `Ipld::String` or `Ipld::Bytes`, got {:#?}",
-                other
-            )),
-        }
-    }
-}
+const PIECE_CID: &str = "baga6ea4seaqlkg6mss5qs56jqtajg5ycrhpkj2b66cgdkukf2qjmmzz6ayksuci";
+const PIECE_SIZE: u64 = 8388608;
+const PROVIDER_ID: u64 = 104;
+const LABEL: &str = "mAXCg5AIg8YBXbFjtdBy1iZjpDYAwRSt0elGLF5GvTqulEii1VcM";
+const START_EPOCH: i64 = 25245;
+const END_EPOCH: i64 = 545150;
+const STORAGE_PRICE_PER_EPOCH: i64 = 1_100_000_000_000;
+const PROVIDER_COLLATERAL: i64 = 1_000_000_000_000_000;
+const CLIENT_COLLATERAL: i64 = 1_000_000_000_000_000;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
-pub struct ClientDealProposal {
-    pub proposal: DealProposal,
-    pub client_signature: Signature,
-}
-
-#[derive(Serialize_tuple, Deserialize_tuple, Debug, Clone, Eq, PartialEq)]
-pub struct PublishStorageDealsParams {
-    pub deals: Vec<ClientDealProposal>,
-}
-
-#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
-pub struct DealProposal {
-    pub piece_cid: Cid,
-    pub piece_size: PaddedPieceSize,
-    pub verified_deal: bool,
-    pub client: Address,
-    pub provider: Address,
-    pub label: Label,
-    pub start_epoch: ChainEpoch,
-    pub end_epoch: ChainEpoch,
-    pub storage_price_per_epoch: TokenAmount,
-    pub provider_collateral: TokenAmount,
-    pub client_collateral: TokenAmount,
-}
+// Published by the fixture, every case reads it.
+const DEAL_ID: u64 = 0;
 
 #[derive(Serialize_tuple, Deserialize_tuple, Debug, Clone, Eq, PartialEq)]
 pub struct CreateMinerParams {
@@ -129,8 +55,6 @@ pub struct CreateMinerParams {
     pub multiaddrs: Vec<BytesDe>,
 }
 
-pub const AUTHENTICATE_MESSAGE_METHOD: u64 = 2643134072;
-
 #[derive(Serialize_tuple, Deserialize_tuple)]
 pub struct AuthenticateMessageParams {
     #[serde(with = "serde_bytes")]
@@ -139,50 +63,29 @@ pub struct AuthenticateMessageParams {
     pub message: Vec<u8>,
 }
 
+/// State shared by every market test: the contract deployed, a miner with the contract as
+/// control address, client and provider escrow funded, and deal 0 published.
+struct MarketFixture {
+    snapshot: Snapshot,
+    /// State right before deal 0 is published, to publish other deals instead.
+    pre_publish: Snapshot,
+    contract: ContractHandle,
+    client: Address,
+    proposal: bindings::DealProposal,
+    publish_gas: GasReport,
+}
 
+static FIXTURE: OnceLock<MarketFixture> = OnceLock::new();
 
-#[test]
-fn market_tests() {
-    println!("Testing solidity API");
-
-    let mut gas_result: GasResult = vec![];
-    let (mut tester, manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-    //let client: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Set storagemarket actor
-    let state_tree = tester.state_tree.as_mut().unwrap();
-    helpers::set_storagemarket_actor(
-        state_tree,
-        *manifest.code_by_id(builtins::Type::Market as u32).unwrap(),
-    )
-    .unwrap();
-    // Set storagepower actor
-    helpers::set_storagepower_actor(
-        state_tree,
-        *manifest.code_by_id(builtins::Type::Power as u32).unwrap(),
-    )
-    .unwrap();
-    helpers::set_reward_actor(
-        state_tree,
-        *manifest.code_by_id(builtins::Type::Reward as u32).unwrap(),
-    )
-    .unwrap();
-
-    /***********************************************
-     *
-     * Instantiate Account Actor with a BLS address
-     *
-     ***********************************************/
-    let bls_private_key_client = bls_signatures::PrivateKey::new(
-        hex::decode("deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef").unwrap(),
-    );
-    let client = Address::new_bls(&bls_private_key_client.public_key().as_bytes()).unwrap();
+fn fixture() -> &'static MarketFixture {
+    FIXTURE.get_or_init(setup_market)
+}
 
-    let state_tree = tester.state_tree.as_mut().unwrap();
-    let assigned_addr = state_tree.register_new_address(&client).unwrap();
-    let state = fvm::account_actor::State { address: client };
+fn install_bls_account(env: &mut TestEnv, address: Address) {
+    let account_code = *env.manifest.get_account_code();
+    let state_tree = env.state_tree_mut();
+    let assigned_addr = state_tree.register_new_address(&address).unwrap();
+    let state = fvm::account_actor::State { address };
 
     let cid = state_tree
         .store()
@@ -190,917 +93,583 @@ fn market_tests() {
         .unwrap();
 
     let actor_state = ActorState {
-        code: *manifest.get_account_code(),
+        code: account_code,
         state: cid,
         sequence: 0,
         balance: TokenAmount::from_whole(1_000_000),
-        delegated_address: Some(client),
+        delegated_address: Some(address),
     };
 
     state_tree.set_actor(assigned_addr, actor_state);
+}
 
-    let bls_private_key_provider = bls_signatures::PrivateKey::generate(&mut OsRng);
-    let worker = Address::new_bls(&bls_private_key_provider.public_key().as_bytes()).unwrap();
-
-    let state_tree = tester.state_tree.as_mut().unwrap();
-    let assigned_addr = state_tree.register_new_address(&worker).unwrap();
-    let state = fvm::account_actor::State { address: worker };
-
-    let cid = state_tree
-        .store()
-        .put_cbor(&state, Code::Blake2b256)
-        .unwrap();
+fn setup_market() -> MarketFixture {
+    let mut env = TestEnv::new();
+    let sender = env.sender.1;
 
-    let actor_state = ActorState {
-        code: *manifest.get_account_code(),
-        state: cid,
-        sequence: 0,
-        balance: TokenAmount::from_whole(1_000_000),
-        delegated_address: Some(worker),
-    };
+    /***********************************************
+     *
+     * Instantiate Account Actor with a BLS address
+     *
+     ***********************************************/
+    let client = Address::new_bls(&client_key().public_key().as_bytes()).unwrap();
+    install_bls_account(&mut env, client);
 
-    state_tree.set_actor(assigned_addr, actor_state);
+    let bls_private_key_provider = bls_signatures::PrivateKey::generate(&mut OsRng);
+    let worker = Address::new_bls(&bls_private_key_provider.public_key().as_bytes()).unwrap();
+    install_bls_account(&mut env, worker);
 
     // Create embryo address to deploy the contract on it (assign some FILs to it)
     let tmp = hex::decode("DAFEA492D9c6733ae3d56b7Ed1ADB60692c98Bc5").unwrap();
     let embryo_eth_address = tmp.as_slice();
     let embryo_delegated_address = Address::new_delegated(10, embryo_eth_address).unwrap();
-    tester
-        .create_placeholder(&embryo_delegated_address, TokenAmount::from_whole(100))
-        .unwrap();
-
-    dbg!(hex::encode(&embryo_delegated_address.to_bytes()));
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    // Try to call "constructor"
-    println!("Try to call constructor on storage power actor");
-
-    let message = Message {
-        from: SYSTEM_ACTOR_ADDR,
-        to: STORAGE_POWER_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 1,
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Implicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    // Try to call "constructor"
-    println!("Try to call constructor on storage market actor");
-
-    let message = Message {
-        from: SYSTEM_ACTOR_ADDR,
-        to: STORAGE_MARKET_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 1,
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Implicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    // Try to call "constructor"
-    println!("Try to call constructor on reward actor");
-
-    let message = Message {
-        from: SYSTEM_ACTOR_ADDR,
-        to: REWARD_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        params: RawBytes::new(vec![0]), // I have to send the power start value (0)
-        method_num: 1,
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Implicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    env.create_placeholder(&embryo_delegated_address, TokenAmount::from_whole(100));
 
     println!("Create Miner actor to be able to publish deal");
 
     let constructor_params = CreateMinerParams {
-        owner: sender[0].1,
+        owner: sender,
         worker,
         window_post_proof_type: fvm_shared::sector::RegisteredPoStProof::StackedDRGWindow32GiBV1P1,
         peer: vec![1, 2, 3],
         multiaddrs: vec![BytesDe(vec![1, 2, 3])],
     };
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(4),
-        gas_limit: 1000000000,
-        method_num: 2,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let res = env.call(
+        sender,
+        Address::new_id(4),
+        2,
+        RawBytes::serialize(constructor_params).unwrap(),
+        TokenAmount::default(),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: embryo_delegated_address,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+    let contract = env.deploy_contract_from(embryo_delegated_address, CONTRACT_NAME);
 
     println!("Adding a deal!");
 
-    let provider_id = 104;
-
-    let piece_cid = Cid::from_str("baga6ea4seaqlkg6mss5qs56jqtajg5ycrhpkj2b66cgdkukf2qjmmzz6ayksuci").unwrap(); 
-    let piece_size = 8388608_u64;
-    let verified_deal = false;
-    let provider = Address::new_id(provider_id);
-    let label = "mAXCg5AIg8YBXbFjtdBy1iZjpDYAwRSt0elGLF5GvTqulEii1VcM".to_string();
-    let start_epoch = 25245;
-    let end_epoch = 545150;
-    let storage_price_per_epoch = 1_100_000_000_000_i64;
-    let provider_collateral = 1_000_000_000_000_000_i64;
-    let client_collateral = 1_000_000_000_000_000_i64;
-
-    let proposal = DealProposal {
-        piece_cid: piece_cid,
-        piece_size: PaddedPieceSize(piece_size),
-        verified_deal: verified_deal,
-        client: client,
-        provider: provider,
-        label: Label::String(label.clone()),
-        start_epoch: ChainEpoch::from(start_epoch),
-        end_epoch: ChainEpoch::from(end_epoch),
-        storage_price_per_epoch: TokenAmount::from_atto(storage_price_per_epoch),
-        provider_collateral: TokenAmount::from_atto(provider_collateral),
-        client_collateral: TokenAmount::from_atto(client_collateral),
-    };
-
-    let deal = RawBytes::serialize(&proposal).unwrap();
-    let sig = bls_private_key_client.sign(deal.to_vec());
+    let provider = Address::new_id(PROVIDER_ID);
 
-    dbg!("serialized deal {}", hex::encode(deal.to_vec()));
-    dbg!("sig deal {}", hex::encode(sig.as_bytes()));
+    let deal = RawBytes::serialize(deal_proposal(client, LABEL)).unwrap();
+    let sig = client_key().sign(deal.to_vec());
 
     let params = AuthenticateMessageParams {
         signature: sig.as_bytes(),
         message: deal.to_vec(),
     };
 
-    let message = Message {
-        from: client, // from need to be the miner
-        to: client,
-        gas_limit: 1000000000,
-        method_num: AUTHENTICATE_MESSAGE_METHOD,
-        sequence: 0,
-        params: RawBytes::serialize(params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let res = env.call(
+        client,
+        client,
+        consts::account::AUTHENTICATE_MESSAGE,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    let message = Message {
-        from: client,
-        to: Address::new_id(5),
-        gas_limit: 1000000000,
-        method_num: 2,
-        sequence: 1,
-        value: TokenAmount::from_whole(100),
-        params: RawBytes::serialize(client).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let res = env.call(
+        client,
+        Address::new_id(5),
+        2,
+        RawBytes::serialize(client).unwrap(),
+        TokenAmount::from_whole(100),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    let message = Message {
-        from: worker,
-        to: Address::new_id(5),
-        gas_limit: 1000000000,
-        method_num: 2,
-        sequence: 0,
-        value: TokenAmount::from_whole(100_000),
-        params: RawBytes::serialize(provider).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let res = env.call(
+        worker,
+        Address::new_id(5),
+        2,
+        RawBytes::serialize(provider).unwrap(),
+        TokenAmount::from_whole(100_000),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     // We need to add our solidity contract as a control address
-
-    dbg!(&exec_return.actor_id);
-
     let params = fil_actor_miner::ChangeWorkerAddressParams {
         new_worker: worker,
-        new_control_addresses: vec![Address::new_id(exec_return.actor_id)],
-    };
-
-    let message = Message {
-        from: sender[0].1,
-        to: provider,
-        gas_limit: 1000000000,
-        method_num: fil_actor_miner::Method::ChangeWorkerAddress as u64,
-        sequence: 1,
-        params: RawBytes::serialize(params).unwrap(),
-        ..Message::default()
+        new_control_addresses: vec![contract.address()],
     };
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let res = env.call(
+        sender,
+        provider,
+        fil_actor_miner::Method::ChangeWorkerAddress as u64,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    /*let deal = ClientDealProposal {
-        proposal,
-        client_signature: Signature::new_bls(sig.as_bytes()),
-    };
-
-    let params = PublishStorageDealsParams { deals: vec![deal] };
-
-    let message = Message {
-        from: worker, // from need to be the miner
-        to: Address::new_id(5),
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 1,
-        params: RawBytes::serialize(params).unwrap(),
-        //params: RawBytes::new(hex::decode("8181828bd82a5828000181e2039220206b86b273ff34fce19d6b804eff5a3f5747ada4eaa22f1d49c01e52ddb7875b4b190800f4420068420066656c6162656c0a1a0008ca0a42000a42000a42000a584d028bd82a5828000181e2039220206b86b273ff34fce19d6b804eff5a3f5747ada4eaa22f1d49c01e52ddb7875b4b190800f4420068420066656c6162656c0a1a0008ca0a42000a42000a42000a").unwrap()),
-        ..Message::default()
-    };*/
+    let pre_publish = env.snapshot();
 
     println!("Calling `publish_storage_deals`");
 
-    //Append the BLS signature type - 02
-    let sig_string:String = "02".to_string() + &hex::encode(sig.as_bytes());
-    let sig_slice: &str = &sig_string[..]; 
-    let client_signature = hex::decode(sig_slice).unwrap();
+    let (message, mut proposals) = publish_storage_deals_message(sender, &contract, client, &[LABEL]);
+    let res = env.send(message);
+    assert_eq!(
+        res.msg_receipt.exit_code.value(),
+        0,
+        "publish_storage_deals failed:\n{}",
+        env.render_trace(&res.exec_trace)
+    );
 
-    let client_collateral_bigint = api_contracts::market_test::BigInt{
-        val: client_collateral.to_be_bytes().to_vec().into_iter().skip_while(|&x| x == 0).collect(),
-        neg: false
-    };
-    let provider_collateral_bigint = api_contracts::market_test::BigInt{
-        val: provider_collateral.to_be_bytes().to_vec().into_iter().skip_while(|&x| x == 0).collect(),
-        neg: false
-    };
+    let publish_gas = GasReport::from_trace(&res.exec_trace);
 
-    let proposal = api_contracts::market_test::DealProposal {
-        piece_cid: api_contracts::market_test::Cid {
-            data: piece_cid.to_bytes()
-        },
-        piece_size: piece_size,
-        verified_deal: verified_deal,
-        client: api_contracts::market_test::FilAddress{
-            data: client.to_bytes()
-        },
-        provider: api_contracts::market_test::FilAddress{
-            data: provider.to_bytes()
-        },
-        label: api_contracts::market_test::DealLabel{
-            data: label.as_bytes().to_vec(),
-            isString: true
-        },
-        start_epoch: i64::from(start_epoch),
-        end_epoch: i64::from(end_epoch),
-        storage_price_per_epoch: api_contracts::market_test::BigInt{
-            val: storage_price_per_epoch.to_be_bytes().to_vec(),
-            neg: false
-        },
-        provider_collateral: provider_collateral_bigint,
-        client_collateral: client_collateral_bigint
-    };
+    MarketFixture {
+        snapshot: env.snapshot(),
+        pre_publish,
+        contract,
+        client,
+        proposal: proposals.remove(0),
+        publish_gas,
+    }
+}
 
-    let client_deal_params = (proposal.clone(), client_signature);
-
-    let abi_encoded_call = api_contracts::market_test::publish_storage_dealsCall{
-        params: (vec![client_deal_params.clone()],)
-    }.abi_encode();
-
-    let temp = api_contracts::cbor_encode(abi_encoded_call);
-    let cbor_encoded = temp.as_str();
-    let temp = cbor_encoded.replace("00044000", "00052000");
-    let cbor_encoded_str = temp.as_str();
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 2,
-        params: RawBytes::new(
-            // [[[[["0x0181E203922020B51BCC94BB0977C984C093770289DEA4E83EF08C355145D412C6673E06152A09"], 8388608, false, ["0x0390A40613DFB06445DFC3759EC22146D66B832AFE57B4AC441E5209D154131B1540E937CB837831855553E17EEFEEEED1"], ["0x0068"], ["0x6d41584367354149673859425862466a7464427931695a6a704459417752537430656c474c463547765471756c4569693156634d", true], 25245, 545150, ["0x01001D1BF800", false], ["0x038D7EA4C68000", false], ["0x038D7EA4C68000", false]], "0x02B7E4AD239896D5DF3491AFE01F9A6F9D5C4A1E59C16E6B386CE16797C00A1224D5ABB8EFE0EDC7B052FC0AB5772BA4DA10C064537320FEFCADA4167017508D882207B23DD457966DAF21393710A26CC5509AC079EC9A0846028B279435BD5F22" ]]]
-            hex::decode(
-                //"5906443b61c67200000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000052000000000000000000000000000000000000000000000000000000000000001600000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001E0000000000000000000000000000000000000000000000000000000000000026000000000000000000000000000000000000000000000000000000000000002C0000000000000000000000000000000000000000000000000000000000000629D000000000000000000000000000000000000000000000000000000000008517E000000000000000000000000000000000000000000000000000000000000036000000000000000000000000000000000000000000000000000000000000003E00000000000000000000000000000000000000000000000000000000000000460000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000270181E203922020B51BCC94BB0977C984C093770289DEA4E83EF08C355145D412C6673E06152A0900000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000310390A40613DFB06445DFC3759EC22146D66B832AFE57B4AC441E5209D154131B1540E937CB837831855553E17EEFEEEED10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000200680000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000346D41584367354149673859425862466A7464427931695A6A704459417752537430656C474C463547765471756C4569693156634D00000000000000000000000000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000601001D1BF8000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007038D7EA4C6800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007038D7EA4C6800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006102B7E4AD239896D5DF3491AFE01F9A6F9D5C4A1E59C16E6B386CE16797C00A1224D5ABB8EFE0EDC7B052FC0AB5772BA4DA10C064537320FEFCADA4167017508D882207B23DD457966DAF21393710A26CC5509AC079EC9A0846028B279435BD5F2200000000000000000000000000000000000000000000000000000000000000",
-                cbor_encoded_str
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+fn client_key() -> bls_signatures::PrivateKey {
+    bls_signatures::PrivateKey::new(hex::decode(CLIENT_PRIVATE_KEY).unwrap())
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+fn deal_proposal(client: Address, label: &str) -> fil_actor_market::DealProposal {
+    fil_actor_market::DealProposal {
+        piece_cid: Cid::from_str(PIECE_CID).unwrap(),
+        piece_size: PaddedPieceSize(PIECE_SIZE),
+        verified_deal: false,
+        client,
+        provider: Address::new_id(PROVIDER_ID),
+        label: fil_actor_market::Label::String(label.to_string()),
+        start_epoch: ChainEpoch::from(START_EPOCH),
+        end_epoch: ChainEpoch::from(END_EPOCH),
+        storage_price_per_epoch: TokenAmount::from_atto(STORAGE_PRICE_PER_EPOCH),
+        provider_collateral: TokenAmount::from_atto(PROVIDER_COLLATERAL),
+        client_collateral: TokenAmount::from_atto(CLIENT_COLLATERAL),
+    }
+}
 
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("publish_storage_deals".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+/// `publish_storage_deals` message publishing one deal per label, signed by the client.
+fn publish_storage_deals_message(
+    from: Address,
+    contract: &ContractHandle,
+    client: Address,
+    labels: &[&str],
+) -> (Message, Vec<bindings::DealProposal>) {
+    let mut deals = vec![];
+    let mut proposals = vec![];
+
+    for label in labels {
+        let deal = deal_proposal(client, label);
+        let sig = client_key().sign(RawBytes::serialize(&deal).unwrap().to_vec());
+
+        //Append the BLS signature type - 02
+        let sig_string: String = "02".to_string() + &hex::encode(sig.as_bytes());
+        let client_signature = hex::decode(sig_string).unwrap();
+
+        let proposal = bindings::DealProposal::from(&deal);
+        deals.push(bindings::ClientDealProposal{
+            proposal: proposal.clone(),
+            client_signature,
+        });
+        proposals.push(proposal);
+    }
 
-    println!("Calling `add_balance`");
-
-    let user = Address::new_id(105);
-    let user_amount = U256::from(100);
-
-    let abi_encoded_call = api_contracts::market_test::add_balanceCall{
-        providerOrClient: api_contracts::market_test::FilAddress{
-            data: user.to_bytes()
-        }, 
-        value: user_amount
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 3,
-        value: TokenAmount::from_atto(1_000),
-        params: RawBytes::new(hex::decode(
-            // "58A44DFAD08C00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000020069000000000000000000000000000000000000000000000000000000000000"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
+    let call = bindings::MarketApiTest::publish_storage_dealsCall{
+        params: bindings::PublishStorageDealsParams{deals}
     };
+    let message = invoke_message(from, contract, &call, TokenAmount::default());
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("add_balance".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "40");
-
-    println!("Calling `withdraw_balance`");
+    (message, proposals)
+}
 
-    let withdrawal_token_amount = api_contracts::market_test::BigInt{
-        val: fixed_bytes!("64").to_vec(),
-        neg: false
-    };
-    let abi_encoded_call = api_contracts::market_test::withdraw_balanceCall{
-        params: api_contracts::market_test::WithdrawBalanceParams{
-            provider_or_client: api_contracts::market_test::FilAddress{
-                data: user.to_bytes()
-            }, 
-            tokenAmount: withdrawal_token_amount.clone()
-        }
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 4,
-        params: RawBytes::new(hex::decode(
-            // "590144D3C69C430000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000A00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000200690000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000016400000000000000000000000000000000000000000000000000000000000000"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
+fn add_balance(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::add_balanceCall{
+        providerOrClient: fixture.contract.address().into(),
+        value: U256::from(100)
     };
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let from = env.sender.1;
+    env.invoke_with_value(from, &fixture.contract, &call, TokenAmount::from_atto(1_000))
+}
 
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("withdraw_balance".into(), gas_used));
+fn check_add_balance(_: &MarketFixture, res: &ApplyRet) {
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "40");
+}
 
-    let abi_encoded_call = api_contracts::market_test::BigInt::abi_encode(&withdrawal_token_amount.clone());
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()), 
-         // "58a000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000016400000000000000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
-
-    println!("Calling `get_balance`");
+fn withdraw_balance(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    // Nothing to withdraw from the contract escrow otherwise
+    let res = add_balance(env, fixture);
+    check_add_balance(fixture, &res);
 
-    let abi_encoded_call = api_contracts::market_test::get_balanceCall{
-        addr: api_contracts::market_test::FilAddress{
-            data: Address::new_id(101).to_bytes()
+    let call = bindings::MarketApiTest::withdraw_balanceCall{
+        params: bindings::WithdrawBalanceParams{
+            provider_or_client: fixture.contract.address().into(),
+            tokenAmount: TokenAmount::from_atto(100).into()
         }
-    }.abi_encode();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    dbg!(cbor_encoded.as_str());
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 5,
-        params: RawBytes::new(
-            hex::decode(
-                //5884C961F5430000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000020065000000000000000000000000000000000000000000000000000000000000
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
     };
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    env.invoke(&fixture.contract, &call)
+}
 
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_balance".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+fn check_withdraw_balance(_: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::withdraw_balanceCall>(res);
+    assert_eq!(ret._0.val, vec![100]);
+    assert!(!ret._0.neg);
+}
 
-    let expected_balance = api_contracts::market_test::GetBalanceReturn{
-        balance: api_contracts::market_test::BigInt{
-            val: fixed_bytes!("056bc75e2d63100000").to_vec(),
-            neg: false
-        },
-        locked: api_contracts::market_test::BigInt{
-            val: fixed_bytes!("07f3556c02eb7800").to_vec(),
-            neg: false
-        }
+fn get_balance(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_balanceCall{
+        addr: Address::new_id(101).into()
     };
-    
-    let abi_encoded_call = api_contracts::market_test::GetBalanceReturn::abi_encode(&expected_balance);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
 
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()), 
-         // "5901600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000c0000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000009056bc75e2d63100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000807f3556c02eb7800000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+    env.invoke(&fixture.contract, &call)
+}
 
-    println!("Calling `get_deal_data_commitment`");
-
-    let deal_id = 0_u64;
-
-    let abi_encoded_call = api_contracts::market_test::get_deal_data_commitmentCall{
-        dealID: deal_id
-    }.abi_encode();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 6,
-        params: RawBytes::new(
-            hex::decode(
-                // "5824915BD52A0000000000000000000000000000000000000000000000000000000000000000",
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+fn check_get_balance(_: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_balanceCall>(res);
+    // 100 FIL added by the client, of which the deal locks the client collateral and the total price
+    let balance = bindings::BigInt::from(TokenAmount::from_whole(100));
+    let deal_price = (END_EPOCH - START_EPOCH) * STORAGE_PRICE_PER_EPOCH;
+    let locked = bindings::BigInt::from(TokenAmount::from_atto(CLIENT_COLLATERAL + deal_price));
+    assert_eq!(ret._0.balance.val, balance.val);
+    assert_eq!(ret._0.locked.val, locked.val);
+    assert!(!ret._0.balance.neg && !ret._0.locked.neg);
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+fn get_deal_data_commitment(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_data_commitmentCall{dealID: DEAL_ID};
 
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_data_commitment".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    env.invoke(&fixture.contract, &call)
+}
 
+fn check_get_deal_data_commitment(_: &MarketFixture, res: &ApplyRet) {
     let mut padded_piece_cid = vec![0_u8];
-    padded_piece_cid.append(&mut piece_cid.to_bytes());
-    let expected_res = api_contracts::market_test::GetDealDataCommitmentReturn{
-        data: padded_piece_cid,
-        size: piece_size
-    };
+    padded_piece_cid.append(&mut Cid::from_str(PIECE_CID).unwrap().to_bytes());
 
-    let abi_encoded_call = api_contracts::market_test::GetDealDataCommitmentReturn::abi_encode(&expected_res);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), 
-        //"58c00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000028000181e203922020b51bcc94bb0977c984c093770289dea4e83ef08c355145d412c6673e06152a09000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_data_commitmentCall>(res);
+    assert_eq!(ret._0.data, padded_piece_cid);
+    assert_eq!(ret._0.size, PIECE_SIZE);
+}
 
-    println!("Calling `get_deal_client`");
-
-    let abi_encoded_call = api_contracts::market_test::get_deal_clientCall{
-        dealID: deal_id
-    }.abi_encode();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 7,
-        params: RawBytes::new(
-            hex::decode(
-                // "5824AABB67B40000000000000000000000000000000000000000000000000000000000000000",
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+fn get_deal_client(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_clientCall{dealID: DEAL_ID};
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    env.invoke(&fixture.contract, &call)
+}
 
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_client".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+fn check_get_deal_client(_: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_clientCall>(res);
+    assert_eq!(ret._0, 101);
+}
 
-    let temp: [u8; U256::BYTES] = U256::from(101).to_be_bytes();
-    let abi_encoded_call = temp.to_vec();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+fn get_deal_provider(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_providerCall{dealID: DEAL_ID};
 
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()),
-        // "58200000000000000000000000000000000000000000000000000000000000000065"
-        cbor_encoded.as_str()
-    );
+    env.invoke(&fixture.contract, &call)
+}
 
-    println!("Calling `get_deal_provider`");
-
-    let abi_encoded_call = api_contracts::market_test::get_deal_providerCall{
-        dealID: deal_id
-    }.abi_encode();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 8,
-        params: RawBytes::new(
-            hex::decode(
-                // "58240E2F33670000000000000000000000000000000000000000000000000000000000000000",
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+fn check_get_deal_provider(_: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_providerCall>(res);
+    assert_eq!(ret._0, PROVIDER_ID);
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_provider".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+fn get_deal_label(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_labelCall{dealID: DEAL_ID};
 
-    let temp: [u8; U256::BYTES] = U256::from(provider_id).to_be_bytes();
-    let abi_encoded_call = temp.to_vec();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+    env.invoke(&fixture.contract, &call)
+}
 
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()),
-        // "58200000000000000000000000000000000000000000000000000000000000000068"
-        cbor_encoded.as_str()
-    );
+fn check_get_deal_label(fixture: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_labelCall>(res);
+    assert_eq!(ret._0.data, fixture.proposal.label.data);
+    assert_eq!(ret._0.isString, fixture.proposal.label.isString);
+}
 
-    println!("Calling `get_deal_label`");
-
-    let abi_encoded_call = api_contracts::market_test::get_deal_labelCall{
-        dealID: deal_id
-    }.abi_encode();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 9,
-        params: RawBytes::new(
-            hex::decode(
-                // "5824B6D312EA0000000000000000000000000000000000000000000000000000000000000000",
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+fn get_deal_term(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_termCall{dealID: DEAL_ID};
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_label".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    env.invoke(&fixture.contract, &call)
+}
 
-    let abi_encoded_call = api_contracts::market_test::DealLabel::abi_encode(&proposal.label);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+fn check_get_deal_term(_: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_termCall>(res);
+    assert_eq!(ret._0.start, START_EPOCH);
+    assert_eq!(ret._0.duration, END_EPOCH - START_EPOCH);
+}
 
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), 
-        //"58c000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000346d41584367354149673859425862466a7464427931695a6a704459417752537430656c474c463547765471756c4569693156634d000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+fn get_deal_total_price(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_total_priceCall{dealID: DEAL_ID};
 
-    println!("Calling `get_deal_term`");
+    env.invoke(&fixture.contract, &call)
+}
 
-    let abi_encoded_call = api_contracts::market_test::get_deal_termCall{dealID: deal_id}.abi_encode();
+fn check_get_deal_total_price(_: &MarketFixture, res: &ApplyRet) {
+    let deal_price = (END_EPOCH - START_EPOCH) * STORAGE_PRICE_PER_EPOCH;
+    let total_price = bindings::BigInt::from(TokenAmount::from_atto(deal_price));
 
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_total_priceCall>(res);
+    assert_eq!(ret._0.val, total_price.val);
+    assert!(!ret._0.neg);
+}
 
+fn get_deal_client_collateral(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_client_collateralCall{dealID: DEAL_ID};
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 10,
-        params: RawBytes::new(
-            hex::decode(
-                cbor_encoded.as_str()
-                //"58249CFC4C330000000000000000000000000000000000000000000000000000000000000000",
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+    env.invoke(&fixture.contract, &call)
+}
 
-    let expected_res = api_contracts::market_test::GetDealTermReturn {
-        start: proposal.start_epoch,
-        duration: proposal.end_epoch - proposal.start_epoch
-    };
+fn check_get_deal_client_collateral(fixture: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_client_collateralCall>(res);
+    assert_eq!(ret._0.val, fixture.proposal.client_collateral.val);
+    assert!(!ret._0.neg);
+}
 
-    let abi_encoded_call = api_contracts::market_test::GetDealTermReturn::abi_encode(&expected_res);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+fn get_deal_provider_collateral(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_provider_collateralCall{dealID: DEAL_ID};
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_term".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-    //5840000000000000000000000000000000000000000000000000000000000000629d000000000000000000000000000000000000000000000000000000000007eee1
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), cbor_encoded.as_str());
-
-    println!("Calling `get_deal_total_price`");
-
-    let abi_encoded_call = api_contracts::market_test::get_deal_total_priceCall{dealID: deal_id}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 11,
-        params: RawBytes::new(
-            hex::decode(
-                cbor_encoded.as_str()
-                //"5824614C34150000000000000000000000000000000000000000000000000000000000000000",
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+    env.invoke(&fixture.contract, &call)
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_total_price".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+fn check_get_deal_provider_collateral(fixture: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_provider_collateralCall>(res);
+    assert_eq!(ret._0.val, fixture.proposal.provider_collateral.val);
+    assert!(!ret._0.neg);
+}
 
-    let deal_duration = proposal.end_epoch - proposal.start_epoch;
-    let deal_price = deal_duration * storage_price_per_epoch;
-    let total_price = api_contracts::market_test::BigInt{
-        val: deal_price.to_be_bytes().to_vec().into_iter().skip_while(|&x| x == 0).collect(),
-        neg: false
-    };
-    let abi_encoded_call = api_contracts::market_test::BigInt::abi_encode(&total_price);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+fn get_deal_verified(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_verifiedCall{dealID: DEAL_ID};
 
+    env.invoke(&fixture.contract, &call)
+}
 
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), 
-        //"58a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000807efc7ed5e24f800000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+fn check_get_deal_verified(_: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_verifiedCall>(res);
+    assert!(!ret._0);
+}
 
-    println!("Calling `get_deal_client_collateral`");
+fn get_deal_activation(env: &mut TestEnv, fixture: &MarketFixture) -> ApplyRet {
+    let call = bindings::MarketApiTest::get_deal_activationCall{dealID: DEAL_ID};
 
-    let abi_encoded_call = api_contracts::market_test::get_deal_client_collateralCall{dealID: deal_id}.abi_encode();
+    env.invoke(&fixture.contract, &call)
+}
 
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+fn check_get_deal_activation(_: &MarketFixture, res: &ApplyRet) {
+    let ret = decode_return::<bindings::MarketApiTest::get_deal_activationCall>(res);
+    assert_eq!(ret._0.activated, 0);
+    assert_eq!(ret._0.terminated, 0);
+}
 
+/// A contract call on a fork of the fixture and the checks on its result, kept apart so
+/// `market_tests` can collect the gas of every call without re-running the checks.
+struct MarketCase {
+    name: &'static str,
+    call: fn(&mut TestEnv, &MarketFixture) -> ApplyRet,
+    check: fn(&MarketFixture, &ApplyRet),
+}
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 12,
-        params: RawBytes::new(
-            hex::decode(
-                cbor_encoded.as_str()
-                //"5824D5E7B9DB0000000000000000000000000000000000000000000000000000000000000000",
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+const CASES: &[MarketCase] = &[
+    MarketCase { name: "add_balance", call: add_balance, check: check_add_balance },
+    MarketCase { name: "withdraw_balance", call: withdraw_balance, check: check_withdraw_balance },
+    MarketCase { name: "get_balance", call: get_balance, check: check_get_balance },
+    MarketCase { name: "get_deal_data_commitment", call: get_deal_data_commitment, check: check_get_deal_data_commitment },
+    MarketCase { name: "get_deal_client", call: get_deal_client, check: check_get_deal_client },
+    MarketCase { name: "get_deal_provider", call: get_deal_provider, check: check_get_deal_provider },
+    MarketCase { name: "get_deal_label", call: get_deal_label, check: check_get_deal_label },
+    MarketCase { name: "get_deal_term", call: get_deal_term, check: check_get_deal_term },
+    MarketCase { name: "get_deal_total_price", call: get_deal_total_price, check: check_get_deal_total_price },
+    MarketCase { name: "get_deal_client_collateral", call: get_deal_client_collateral, check: check_get_deal_client_collateral },
+    MarketCase { name: "get_deal_provider_collateral", call: get_deal_provider_collateral, check: check_get_deal_provider_collateral },
+    MarketCase { name: "get_deal_verified", call: get_deal_verified, check: check_get_deal_verified },
+    MarketCase { name: "get_deal_activation", call: get_deal_activation, check: check_get_deal_activation },
+];
+
+/// Runs the call of `case` on its own fork of the fixture.
+fn call_case(case: &MarketCase) -> ApplyRet {
+    let fixture = fixture();
+    let mut env = TestEnv::fork(&fixture.snapshot);
+
+    (case.call)(&mut env, fixture)
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_client_collateral".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+/// Runs the call of the case `name` and checks its result.
+fn run_case(name: &str) {
+    let case = CASES
+        .iter()
+        .find(|case| case.name == name)
+        .unwrap_or_else(|| panic!("no market case {}", name));
+    let res = call_case(case);
 
-    let abi_encoded_call = api_contracts::market_test::BigInt::abi_encode(&proposal.client_collateral);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+    (case.check)(fixture(), &res);
+}
 
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), 
-        //"58a00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007038d7ea4c6800000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+/// Collects the gas of every case for the gas table and baselines, the results are
+/// checked by the per-case tests below.
+#[test]
+fn market_tests() {
+    println!("Testing solidity API");
 
-    println!("Calling `get_deal_provider_collateral`");
+    let mut gas_reports = vec![("publish_storage_deals".to_string(), fixture().publish_gas.clone())];
 
-    let abi_encoded_call = api_contracts::market_test::get_deal_provider_collateralCall{dealID: deal_id}.abi_encode();
+    for case in CASES {
+        println!("Calling `{}`", case.name);
 
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+        let res = call_case(case);
+        assert_eq!(res.msg_receipt.exit_code.value(), 0, "calling {}", case.name);
+        gas_reports.push((case.name.to_string(), GasReport::from_trace(&res.exec_trace)));
+    }
 
+    let gas_result: GasResult = gas_reports
+        .iter()
+        .map(|(name, report)| (name.clone(), report.total()))
+        .collect();
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 13,
-        params: RawBytes::new(
-            hex::decode(
-                cbor_encoded.as_str()
-                //"58242F2229FE0000000000000000000000000000000000000000000000000000000000000000",
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+    let table = testing::create_gas_table(gas_result.clone());
+    testing::save_gas_table(&table, "market");
+    testing::report::save_gas_results(&gas_result, "market");
+    save_gas_reports(&gas_reports, "market");
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_provider_collateral".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    table.printstd();
 
-    let abi_encoded_call = api_contracts::market_test::BigInt::abi_encode(&proposal.provider_collateral);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+    testing::gas::check_gas_baseline(&gas_result, "market");
+}
 
+#[test]
+fn market_test_add_balance() {
+    run_case("add_balance");
+}
 
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), 
-        //"58a00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007038d7ea4c6800000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+#[test]
+fn market_test_withdraw_balance() {
+    run_case("withdraw_balance");
+}
 
-    println!("Calling `get_deal_verified`");
+#[test]
+fn market_test_get_balance() {
+    run_case("get_balance");
+}
 
-    let abi_encoded_call = api_contracts::market_test::get_deal_verifiedCall{dealID: deal_id}.abi_encode();
+#[test]
+fn market_test_get_deal_data_commitment() {
+    run_case("get_deal_data_commitment");
+}
 
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+#[test]
+fn market_test_get_deal_client() {
+    run_case("get_deal_client");
+}
 
+#[test]
+fn market_test_get_deal_provider() {
+    run_case("get_deal_provider");
+}
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 14,
-        params: RawBytes::new(
-            hex::decode(
-                // "58243219A6290000000000000000000000000000000000000000000000000000000000000000",
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+#[test]
+fn market_test_get_deal_label() {
+    run_case("get_deal_label");
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_verified".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+#[test]
+fn market_test_get_deal_term() {
+    run_case("get_deal_term");
+}
 
-    let temp: [u8; U256::BYTES] = U256::from(0).to_be_bytes();
-    let abi_encoded_call = temp.to_vec();
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+#[test]
+fn market_test_get_deal_total_price() {
+    run_case("get_deal_total_price");
+}
 
+#[test]
+fn market_test_get_deal_client_collateral() {
+    run_case("get_deal_client_collateral");
+}
 
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()),
-        // "58200000000000000000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+#[test]
+fn market_test_get_deal_provider_collateral() {
+    run_case("get_deal_provider_collateral");
+}
 
-    println!("Calling `get_deal_activation`");
-
-    let abi_encoded_call = api_contracts::market_test::get_deal_activationCall{dealID: deal_id}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 15,
-        params: RawBytes::new(
-            hex::decode(
-                // "5824F5C036580000000000000000000000000000000000000000000000000000000000000000",
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
-    };
+#[test]
+fn market_test_get_deal_verified() {
+    run_case("get_deal_verified");
+}
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-    let gas_used = parse_gas(res.exec_trace);
-    gas_result.push(("get_deal_activation".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+#[test]
+fn market_test_get_deal_activation() {
+    run_case("get_deal_activation");
+}
 
-    let expected_res = api_contracts::market_test::GetDealActivationReturn {
-        activated: 0_i64,
-        terminated: 0_i64
-    };
-    let abi_encoded_call = api_contracts::market_test::GetDealActivationReturn::abi_encode(&expected_res);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
+#[test]
+fn market_test_get_deal_client_not_found() {
+    let fixture = fixture();
+    let mut env = TestEnv::fork(&fixture.snapshot);
 
+    let call = bindings::MarketApiTest::get_deal_clientCall{dealID: DEAL_ID + 1};
+    let res = env.invoke(&fixture.contract, &call);
 
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), 
-        //"584000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
+    let err = expect_revert::<NonZeroExitCode>(&res);
+    assert_eq!(err.exit_code, I256::try_from(17).unwrap());
+    assert_eq!(err.description, "USR_NOT_FOUND");
+}
 
-    let table = testing::create_gas_table(gas_result);
-    testing::save_gas_table(&table, "market");
+#[test]
+fn market_test_gas_limits() {
+    let fixture = fixture();
+    let snapshot = &fixture.snapshot;
+    let contract = &fixture.contract;
+
+    // withdraw_balance needs funds in the contract escrow, added on a fork of the fixture
+    let mut env = TestEnv::fork(snapshot);
+    let res = add_balance(&mut env, fixture);
+    check_add_balance(fixture, &res);
+    let funded = env.snapshot();
+
+    let mut estimates = vec![
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::add_balanceCall{
+            providerOrClient: contract.address().into(),
+            value: U256::from(100)
+        }, TokenAmount::from_atto(1_000)).unwrap(),
+        call_gas_limit(&funded, contract, &bindings::MarketApiTest::withdraw_balanceCall{
+            params: bindings::WithdrawBalanceParams{
+                provider_or_client: contract.address().into(),
+                tokenAmount: TokenAmount::from_atto(100).into()
+            }
+        }, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_balanceCall{
+            addr: Address::new_id(101).into()
+        }, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_data_commitmentCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_clientCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_providerCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_labelCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_termCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_total_priceCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_client_collateralCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_provider_collateralCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_verifiedCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+        call_gas_limit(snapshot, contract, &bindings::MarketApiTest::get_deal_activationCall{dealID: DEAL_ID}, TokenAmount::default()).unwrap(),
+    ];
+    gas_limit_table("Call", &estimates).printstd();
+
+    // Publishing a deal with a longer label, up to the 256 bytes the market accepts
+    let by_label = min_gas_limits(&fixture.pre_publish, [1, 128, 256], |env, label_len| {
+        let label = "a".repeat(*label_len);
+        publish_storage_deals_message(env.sender.1, contract, fixture.client, &[&label]).0
+    })
+    .unwrap();
+    gas_limit_table("Label length", &by_label).printstd();
+
+    // Publishing more deals at once, the labels tell the proposals apart
+    let by_count = min_gas_limits(&fixture.pre_publish, [1, 2, 4, 8], |env, count| {
+        let labels: Vec<String> = (0..*count).map(|i| format!("{}-{}", LABEL, i)).collect();
+        let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+        publish_storage_deals_message(env.sender.1, contract, fixture.client, &labels).0
+    })
+    .unwrap();
+    gas_limit_table("Deal count", &by_count).printstd();
 
-    table.printstd();
+    estimates.extend(by_label);
+    estimates.extend(by_count);
+    for (name, estimate) in &estimates {
+        assert!(estimate.gas_limit >= estimate.gas_used, "{}", name);
+    }
 }
@@ -0,0 +1,98 @@
+use testing::methods::{self, ActorMethods, MethodNumMismatch, REGISTRY};
+use testing::{consts, method_consts};
+
+#[test]
+fn methods_registry_tests() {
+    // Every constant of contracts/v0.8/types matches the registry, and the other way around
+    if let Err(err) = methods::check_types() {
+        panic!("{:#}", err);
+    }
+
+    // Spot checks against hashes computed outside of frc42_dispatch
+    let datacap = REGISTRY.iter().find(|actor| actor.actor == "datacap").unwrap();
+    assert_eq!(datacap.constant_name("Balance"), "BalanceOfMethodNum");
+    assert!(datacap.constants().contains(&("BalanceOfMethodNum".to_string(), 3261979605)));
+
+    let market = REGISTRY.iter().find(|actor| actor.actor == "market").unwrap();
+    assert!(market.constants().contains(&("AddBalanceMethodNum".to_string(), 822473126)));
+
+    // Exported by the actor without being called by the library
+    assert!(!datacap.constants().iter().any(|(name, _)| name == "GranularityMethodNum"));
+    assert_eq!(
+        consts::datacap::GRANULARITY,
+        fil_actor_datacap::Method::GranularityExported as u64
+    );
+}
+
+#[test]
+fn methods_registry_mismatch_tests() {
+    let dir = std::env::temp_dir().join(format!("methods_registry_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("MarketTypes.sol"),
+        "library MarketTypes {\n\
+         \x20   uint constant AddBalanceMethodNum = 822473126;\n\
+         \x20   uint constant WithdrawBalanceMethodNum = 1; // stale\n\
+         \x20   uint constant SettleDealsMethodNum = 2;\n\
+         \x20   uint256 constant MAX_DEAL_LABEL_LENGTH = 256;\n\
+         }\n",
+    )
+    .unwrap();
+
+    let registry = [ActorMethods {
+        actor: "market",
+        types_file: Some("MarketTypes.sol"),
+        methods: &[
+            ("AddBalance", 822473126),
+            ("WithdrawBalance", 2280458852),
+            ("GetBalance", 726108461),
+        ],
+        aliases: &[],
+        rust_only: &[],
+    }];
+
+    let mismatches = methods::check_types_dir(&dir, &registry).unwrap();
+    let file = "MarketTypes.sol".to_string();
+    assert_eq!(
+        mismatches,
+        vec![
+            MethodNumMismatch::WrongValue {
+                file: file.clone(),
+                constant: "WithdrawBalanceMethodNum".to_string(),
+                registry: 2280458852,
+                solidity: 1,
+            },
+            MethodNumMismatch::NotInRegistry {
+                file: file.clone(),
+                constant: "SettleDealsMethodNum".to_string(),
+            },
+            MethodNumMismatch::NotInSolidity {
+                file,
+                constant: "GetBalanceMethodNum".to_string(),
+            },
+        ]
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn methods_generated_consts_tests() {
+    // Checked-in Solidity constants and testing::consts are what the registry generates
+    if let Err(err) = method_consts::check() {
+        panic!("{:#}", err);
+    }
+
+    assert_eq!(method_consts::rust_const_name("GetPeerID"), "GET_PEER_ID");
+    assert_eq!(method_consts::rust_const_name("GetDealDataCommitment"), "GET_DEAL_DATA_COMMITMENT");
+    assert_eq!(consts::datacap::MINT, 116935346);
+
+    let source = "library MarketTypes {\n    CommonTypes.FilActorId constant ActorID = CommonTypes.FilActorId.wrap(5);\n    uint constant AddBalanceMethodNum = 1;\n    uint constant RemovedMethodNum = 2;\n\n    struct Foo {}\n}\n";
+    let market = REGISTRY.iter().find(|actor| actor.actor == "market").unwrap();
+    let rendered = method_consts::render_types_file(source, "MarketTypes.sol", &[market]).unwrap();
+
+    assert!(rendered.starts_with("library MarketTypes {\n    CommonTypes.FilActorId constant ActorID"));
+    assert!(rendered.contains("\n    uint constant AddBalanceMethodNum = 822473126;\n"));
+    assert!(!rendered.contains("RemovedMethodNum"));
+    assert!(rendered.ends_with("    uint constant PublishStorageDealsMethodNum = 2236929350;\n\n    struct Foo {}\n}\n"));
+}
@@ -1,39 +1,22 @@
 use bls_signatures::Serialize;
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::{
-    runtime::builtins, EAM_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR, SYSTEM_ACTOR_ADDR,
-};
-use fvm::executor::{ApplyKind, Executor};
 use fvm::state_tree::ActorState;
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
 use fvm_ipld_encoding::BytesDe;
 use fvm_ipld_encoding::CborStore;
 use fvm_ipld_encoding::RawBytes;
 use fvm_ipld_encoding::{strict_bytes, tuple::*};
 use fvm_shared::address::Address;
 use fvm_shared::econ::TokenAmount;
-use fvm_shared::message::Message;
 use fvm_shared::sector::RegisteredPoStProof;
 use multihash::Code;
 use rand_core::OsRng;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
 
-use alloy_primitives::{fixed_bytes};
-use alloy_sol_types::{SolCall, SolType, sol_data};
+use testing::bindings;
+use testing::env::{decode_return, TestEnv};
 
-use testing::{setup, api_contracts};
-
-use testing::helpers::set_storagepower_actor;
 use testing::GasResult;
 use testing::parse_gas;
 
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/PowerApiTest.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+const CONTRACT_NAME: &str = "PowerApiTest";
 
 #[derive(Serialize_tuple, Deserialize_tuple, Debug, Clone, Eq, PartialEq)]
 pub struct CreateMinerParams {
@@ -50,17 +33,8 @@ fn power_tests() {
     println!("Testing solidity API");
 
     let mut gas_result: GasResult = vec![];
-    let (mut tester, manifest) = setup::setup_tester();
-
-    let sender: [Account; 1] = tester.create_accounts().unwrap();
-
-    // Set power actor
-    let state_tree = tester.state_tree.as_mut().unwrap();
-    set_storagepower_actor(
-        state_tree,
-        *manifest.code_by_id(builtins::Type::Power as u32).unwrap(),
-    )
-    .unwrap();
+    let mut env = TestEnv::new();
+    let sender = env.sender.1;
 
     /***********************************************
      *
@@ -71,7 +45,8 @@ fn power_tests() {
     let bls_private_key_provider = bls_signatures::PrivateKey::generate(&mut OsRng);
     let worker = Address::new_bls(&bls_private_key_provider.public_key().as_bytes()).unwrap();
 
-    let state_tree = tester.state_tree.as_mut().unwrap();
+    let account_code = *env.manifest.get_account_code();
+    let state_tree = env.state_tree_mut();
     let assigned_addr = state_tree.register_new_address(&worker).unwrap();
     let state = fvm::account_actor::State { address: worker };
 
@@ -81,7 +56,7 @@ fn power_tests() {
         .unwrap();
 
     let actor_state = ActorState {
-        code: *manifest.get_account_code(),
+        code: account_code,
         state: cid,
         sequence: 0,
         balance: TokenAmount::from_whole(1_000_000),
@@ -90,241 +65,75 @@ fn power_tests() {
 
     state_tree.set_actor(assigned_addr, actor_state);
 
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    // Try to call "constructor"
-    println!("Try to call constructor on storage power actor");
-
-    let message = Message {
-        from: SYSTEM_ACTOR_ADDR,
-        to: STORAGE_POWER_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 1,
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Implicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
     println!("Create Miner actor to be able to claim power");
 
     let constructor_params = CreateMinerParams {
-        owner: sender[0].1,
+        owner: sender,
         worker,
         window_post_proof_type: fvm_shared::sector::RegisteredPoStProof::StackedDRGWindow32GiBV1P1,
         peer: vec![1, 2, 3],
         multiaddrs: vec![BytesDe(vec![1, 2, 3])],
     };
 
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(4),
-        gas_limit: 1000000000,
-        method_num: 2,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let res = env.call(
+        sender,
+        Address::new_id(4),
+        2,
+        RawBytes::serialize(constructor_params).unwrap(),
+        TokenAmount::default(),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    println!("Calling init actor (EVM)");
-
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 1,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
+    let miner: fil_actor_power::CreateMinerReturn = res.msg_receipt.return_data.deserialize().unwrap();
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    println!("Calling init actor (EVM)");
 
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
     println!("Calling `miner_count`");
 
-    let abi_encoded_call = api_contracts::power_test::miner_countCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 2,
-        params: RawBytes::new(hex::decode(
-            // "4487A8D7D6"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::PowerApiTest::miner_countCall{});
+    let ret = decode_return::<bindings::PowerApiTest::miner_countCall>(&res);
+    assert_eq!(ret._0, 1);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("miner_count".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let expected_miner_count = 1_u64;
-
-    let abi_encoded_call = sol_data::Uint::<64>::abi_encode(&expected_miner_count);
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()),
-        // "58200000000000000000000000000000000000000000000000000000000000000001"
-        cbor_encoded.as_str()
-    );
 
     println!("Calling `network_raw_power`");
 
-    let abi_encoded_call = api_contracts::power_test::network_raw_powerCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 3,
-        params: RawBytes::new(hex::decode(
-            // "446C3D7356"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::PowerApiTest::network_raw_powerCall{});
+    let ret = decode_return::<bindings::PowerApiTest::network_raw_powerCall>(&res);
+    assert!(ret._0.val.is_empty());
+    assert!(!ret._0.neg);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("network_raw_power".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let expected_network_raw_power = api_contracts::power_test::BigInt{
-        val: fixed_bytes!("").to_vec(),
-        neg: false
-    };
-    let abi_encoded_call = api_contracts::power_test::BigInt::abi_encode(&expected_network_raw_power);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()), 
-        // "58800000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
 
     println!("Calling `miner_raw_power`");
 
-    let abi_encoded_call = api_contracts::power_test::miner_raw_powerCall{
-        minerID: 0x66_u64
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 4,
-        params: RawBytes::new(
-            hex::decode(
-                // "58245D8543640000000000000000000000000000000000000000000000000000000000000066",
-                cbor_encoded.as_str()
-            )
-            .unwrap(),
-        ),
-        ..Message::default()
+    let call = bindings::PowerApiTest::miner_raw_powerCall{
+        minerID: miner.id_address.id().unwrap()
     };
-    //
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+
+    let res = env.invoke(&contract, &call);
+    let ret = decode_return::<bindings::PowerApiTest::miner_raw_powerCall>(&res);
+    assert!(ret._0.raw_byte_power.val.is_empty());
+    assert!(!ret._0.raw_byte_power.neg);
+    assert!(!ret._0.meets_consensus_minimum);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("miner_raw_power".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let expected_miner_raw_power = api_contracts::power_test::MinerRawPowerReturn{
-        raw_byte_power: api_contracts::power_test::BigInt{
-            val: fixed_bytes!("").to_vec(),
-            neg: false
-        },
-        meets_consensus_minimum: false
-    };
-    let abi_encoded_call = api_contracts::power_test::MinerRawPowerReturn::abi_encode(&expected_miner_raw_power);
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()), 
-        // "58c0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
 
     println!("Calling `miner_consensus_count`");
 
-    let abi_encoded_call = api_contracts::power_test::miner_consensus_countCall{}.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(exec_return.actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 5,
-        params: RawBytes::new(hex::decode(
-            // "44DF16B842"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &bindings::PowerApiTest::miner_consensus_countCall{});
+    let ret = decode_return::<bindings::PowerApiTest::miner_consensus_countCall>(&res);
+    assert_eq!(ret._0, 0);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("miner_consensus_count".into(), gas_used));
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let expected_miner_consensus_count = 0_u64;
-
-    let abi_encoded_call = sol_data::Uint::<64>::abi_encode(&expected_miner_consensus_count);
 
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    assert_eq!(
-        hex::encode(res.msg_receipt.return_data.bytes()),
-        // "58200000000000000000000000000000000000000000000000000000000000000000"
-        cbor_encoded.as_str()
-    );
-
-    let table = testing::create_gas_table(gas_result);
+    let table = testing::create_gas_table(gas_result.clone());
     testing::save_gas_table(&table, "power");
+    testing::report::save_gas_results(&gas_result, "power");
 
     table.printstd();
+
+    testing::gas::check_gas_baseline(&gas_result, "power");
 }
@@ -0,0 +1,66 @@
+use testing::report::{self, GasRunInfo, SuiteGasResults};
+
+fn suite(name: &str, results: &[(&str, i64)]) -> SuiteGasResults {
+    SuiteGasResults {
+        suite: name.to_string(),
+        info: GasRunInfo {
+            solc_version: "0.8.17+commit.8df45f5f.Linux.g++".to_string(),
+            optimizer: "enabled, 200 runs".to_string(),
+            bundle: "builtin-actors v12".to_string(),
+            network_version: 21,
+            state_tree_version: 5,
+        },
+        results: results
+            .iter()
+            .map(|(function, gas)| (function.to_string(), *gas))
+            .collect(),
+    }
+}
+
+#[test]
+fn gas_report_render_tests() {
+    let dir = std::env::temp_dir().join(format!("gas_report_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let market = suite("market", &[("add_balance", 1200), ("get_deal_client", 800)]);
+    let account = suite("account", &[("universal_receiver_hook", 500)]);
+    market.save(&dir).unwrap();
+    account.save(&dir).unwrap();
+    std::fs::write(dir.join("gas_results_market.csv"), "Function,Gas\n").unwrap();
+
+    // Sorted by suite, the CSV tables are ignored
+    let suites = report::collect(&dir).unwrap();
+    assert_eq!(suites, vec![account.clone(), market.clone()]);
+
+    let markdown = report::render_markdown(&suites);
+    assert!(markdown.contains("## market\n"));
+    assert!(markdown.contains("solc 0.8.17+commit.8df45f5f.Linux.g++ (optimizer enabled, 200 runs), builtin-actors v12, nv21, stv5"));
+    assert!(markdown.contains("| get_deal_client | 800 |\n"));
+
+    let csv = report::render_csv(&suites);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(
+        lines[1],
+        "account,universal_receiver_hook,500,0.8.17+commit.8df45f5f.Linux.g++,\"enabled, 200 runs\",builtin-actors v12,21,5"
+    );
+
+    let json = report::render_json(&suites).unwrap();
+    let parsed: Vec<SuiteGasResults> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, suites);
+
+    let out_dir = dir.join("out");
+    let paths = report::write_report(&dir, &out_dir).unwrap();
+    assert_eq!(paths.len(), 3);
+    assert!(paths.iter().all(|path| path.is_file()));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn gas_report_optimizer_tests() {
+    assert_eq!(report::describe_optimizer("--optimize\n"), "enabled, 200 runs");
+    assert_eq!(report::describe_optimizer("--optimize --optimize-runs 10000"), "enabled, 10000 runs");
+    assert_eq!(report::describe_optimizer("--optimize --optimize-runs 10000 --via-ir"), "enabled, 10000 runs, via IR");
+    assert_eq!(report::describe_optimizer(""), "disabled");
+}
@@ -0,0 +1,57 @@
+use alloy_primitives::{I256, U256};
+use alloy_sol_types::{Revert as SolRevert, SolError};
+use testing::revert::{exit_code_name, ActorError, InvalidActorID, NonZeroExitCode, NotEnoughBalance, Revert};
+
+#[test]
+fn revert_decoding_tests() {
+    let data = ActorError {
+        errorCode: I256::try_from(17).unwrap(),
+    }
+    .abi_encode();
+    assert_eq!(Revert::decode(&data).to_string(), "ActorError(USR_NOT_FOUND=17)");
+
+    let data = NonZeroExitCode {
+        exit_code: I256::try_from(16).unwrap(),
+        description: "USR_ILLEGAL_ARGUMENT".to_string(),
+    }
+    .abi_encode();
+    assert_eq!(Revert::decode(&data).to_string(), "NonZeroExitCode(USR_ILLEGAL_ARGUMENT=16)");
+
+    let data = NonZeroExitCode {
+        exit_code: I256::try_from(33).unwrap(),
+        description: "Actor specific exit code".to_string(),
+    }
+    .abi_encode();
+    assert_eq!(
+        Revert::decode(&data).to_string(),
+        "NonZeroExitCode(33, \"Actor specific exit code\")"
+    );
+
+    let data = NotEnoughBalance {
+        balance: U256::from(1),
+        value: U256::from(2),
+    }
+    .abi_encode();
+    assert_eq!(
+        Revert::decode(&data),
+        Revert::NotEnoughBalance {
+            balance: U256::from(1),
+            value: U256::from(2)
+        }
+    );
+
+    let data = InvalidActorID { actorId: 7 }.abi_encode();
+    assert_eq!(Revert::decode(&data).to_string(), "InvalidActorID(7)");
+
+    let data = SolRevert {
+        reason: "not enough datacap".to_string(),
+    }
+    .abi_encode();
+    assert_eq!(Revert::decode(&data).to_string(), "Error(\"not enough datacap\")");
+
+    assert_eq!(Revert::decode(&[]), Revert::Empty);
+    assert_eq!(Revert::decode(&[0xde, 0xad, 0xbe, 0xef]), Revert::Unknown(vec![0xde, 0xad, 0xbe, 0xef]));
+
+    assert_eq!(exit_code_name(I256::try_from(19).unwrap()), Some("USR_INSUFFICIENT_FUNDS"));
+    assert_eq!(exit_code_name(I256::try_from(32).unwrap()), None);
+}
@@ -0,0 +1,139 @@
+use libipld_core::ipld::Ipld;
+use testing::schema::{self, Conformance, Drift, SolSchema};
+
+#[test]
+fn schema_conformance_tests() {
+    // Every struct of contracts/v0.8/types matches the CBOR tuple of its builtin-actors type
+    let drifts = schema::check_types().unwrap();
+    for drift in &drifts {
+        println!("{}", drift);
+    }
+
+    // Only the declaration order of the known structs differs, nested ones included
+    let (misordered, drifts): (Vec<&Drift>, Vec<&Drift>) = drifts
+        .iter()
+        .partition(|drift| drift.problem.starts_with("declared where the CBOR tuple has"));
+    assert!(drifts.is_empty(), "{} drifted fields", drifts.len());
+
+    let mut structs: Vec<&str> = misordered
+        .iter()
+        .filter_map(|drift| {
+            let (name, field) = drift.path.rsplit_once('.')?;
+            (!field.contains('[') && name.matches('.').count() == 1).then_some(name)
+        })
+        .collect();
+    structs.sort();
+    structs.dedup();
+    assert_eq!(structs, schema::MISORDERED);
+}
+
+#[test]
+fn schema_conformance_drift_tests() {
+    let mut solidity = SolSchema::default();
+    solidity.add_source(
+        "library CommonTypes {\n\
+         \x20   struct BigInt {\n\
+         \x20       bytes val;\n\
+         \x20       bool neg;\n\
+         \x20   }\n\
+         \x20   type ChainEpoch is int64;\n\
+         }\n\
+         library MarketTypes {\n\
+         \x20   struct GetDealTermReturn {\n\
+         \x20       CommonTypes.ChainEpoch start;\n\
+         \x20       // renamed from duration\n\
+         \x20       CommonTypes.ChainEpoch length;\n\
+         \x20       bool active;\n\
+         \x20   }\n\
+         \x20   struct GetBalanceReturn {\n\
+         \x20       CommonTypes.BigInt balance;\n\
+         \x20       uint64 locked;\n\
+         \x20   }\n\
+         \x20   struct GetDealActivationReturn {\n\
+         \x20       CommonTypes.ChainEpoch terminated;\n\
+         \x20       CommonTypes.ChainEpoch activated;\n\
+         \x20   }\n\
+         \x20   struct Unmapped {}\n\
+         }\n\
+         library MinerTypes {\n\
+         \x20   struct BeneficiaryTerm {\n\
+         \x20       CommonTypes.BigInt quota;\n\
+         \x20       CommonTypes.BigInt used_quota;\n\
+         \x20       CommonTypes.ChainEpoch expiration;\n\
+         \x20   }\n\
+         }\n",
+    );
+    assert_eq!(
+        solidity.structs["MarketTypes.GetDealTermReturn"]
+            .iter()
+            .map(|(field, _)| field.as_str())
+            .collect::<Vec<_>>(),
+        vec!["start", "length", "active"]
+    );
+
+    let conformances = [
+        Conformance {
+            solidity: "MarketTypes.GetDealTermReturn",
+            rust: "fil_actor_market::GetDealTermReturn",
+            cbor_fields: vec![("start", Ipld::Integer(100)), ("duration", Ipld::Integer(50))],
+            sample: Ipld::List(vec![Ipld::Integer(100), Ipld::Integer(50)]),
+        },
+        Conformance {
+            solidity: "MarketTypes.GetBalanceReturn",
+            rust: "fil_actor_market::GetBalanceReturn",
+            cbor_fields: vec![("balance", Ipld::Bytes(vec![0, 1])), ("locked", Ipld::Bytes(vec![0, 2]))],
+            sample: Ipld::List(vec![Ipld::Bytes(vec![0, 1]), Ipld::Bytes(vec![0, 2])]),
+        },
+        // Declared in another order, with a sample that cannot tell the fields apart
+        Conformance {
+            solidity: "MarketTypes.GetDealActivationReturn",
+            rust: "fil_actor_market::GetDealActivationReturn",
+            cbor_fields: vec![("activated", Ipld::Integer(100)), ("terminated", Ipld::Integer(100))],
+            sample: Ipld::List(vec![Ipld::Integer(100), Ipld::Integer(100)]),
+        },
+        // Serialized with quota and used_quota swapped
+        Conformance {
+            solidity: "MinerTypes.BeneficiaryTerm",
+            rust: "fil_actor_miner::BeneficiaryTerm",
+            cbor_fields: vec![
+                ("quota", Ipld::Bytes(vec![0, 1])),
+                ("used_quota", Ipld::Bytes(vec![0, 2])),
+                ("expiration", Ipld::Integer(300)),
+            ],
+            sample: Ipld::List(vec![
+                Ipld::Bytes(vec![0, 2]),
+                Ipld::Bytes(vec![0, 1]),
+                Ipld::Integer(300),
+            ]),
+        },
+    ];
+
+    let drift = |path: &str, problem: &str| Drift {
+        path: path.to_string(),
+        problem: problem.to_string(),
+    };
+    assert_eq!(
+        schema::check(&solidity, &conformances),
+        vec![
+            drift("MarketTypes.GetDealTermReturn.length", "not in the CBOR field order"),
+            drift("MarketTypes.GetDealTermReturn.active", "not in the CBOR field order"),
+            drift("MarketTypes.GetDealTermReturn.duration", "no such Solidity field"),
+            drift("MarketTypes.GetBalanceReturn.locked", "expected integer, CBOR has bytes"),
+            drift(
+                "MarketTypes.GetDealActivationReturn.terminated",
+                "same sample value as activated, swaps would go unnoticed"
+            ),
+            drift(
+                "MarketTypes.GetDealActivationReturn.terminated",
+                "declared where the CBOR tuple has activated"
+            ),
+            drift(
+                "MarketTypes.GetDealActivationReturn.activated",
+                "declared where the CBOR tuple has terminated"
+            ),
+            drift("MinerTypes.BeneficiaryTerm.quota", "serialized at position 1, expected 0"),
+            drift("MinerTypes.BeneficiaryTerm.used_quota", "serialized at position 0, expected 1"),
+            drift("MarketTypes.Unmapped", "not compared with any builtin-actors type"),
+        ]
+    );
+}
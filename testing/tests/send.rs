@@ -1,202 +1,94 @@
-use fil_actor_eam::Return;
-use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::EAM_ACTOR_ADDR;
-use fvm::executor::{ApplyKind, Executor};
-use fvm_integration_tests::dummy::DummyExterns;
-use fvm_integration_tests::tester::Account;
-use fvm_ipld_encoding::strict_bytes;
-use fvm_ipld_encoding::RawBytes;
 use fvm_shared::address::Address;
 use fvm_shared::econ::TokenAmount;
-use fvm_shared::message::Message;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-use alloy_primitives::{fixed_bytes, address, Address as Alloy_Address, U256};
-use alloy_sol_types::{SolCall, SolType, sol_data};
+use fvm_ipld_encoding::RawBytes;
+use alloy_primitives::U256;
 
-use testing::{setup, api_contracts};
+use testing::bindings;
+use testing::env::TestEnv;
 use testing::GasResult;
 use testing::parse_gas;
 
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/SendApiTest.bin";
-
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+const CONTRACT_NAME: &str = "SendApiTest";
 
 #[test]
 fn send_tests() {
     println!("Testing solidity API");
 
     let mut gas_result: GasResult = vec![];
-    let (mut tester, _manifest) = setup::setup_tester();
+    let mut env = TestEnv::new();
 
-    // As the governor address for datacap is 200, we create many many address in order to initialize the ID 200 with some tokens
-    // and make it a valid address to use.
-    let sender: [Account; 300] = tester.create_accounts().unwrap();
+    // Account 101, the target of the sends
+    let [receiver] = env.create_accounts();
 
     println!(
-        "{}",
-        format!(
-            "Sender address id [{}] and bytes [{}]",
-            &sender[0].0,
-            hex::encode(&sender[0].1.to_bytes())
-        )
-    );
-    println!(
-        "{}",
-        format!(
-            "Sender address id [{}] and bytes [{}]",
-            &sender[1].0,
-            hex::encode(&sender[1].1.to_bytes())
-        )
-    );
-    println!(
-        "{}",
-        format!(
-            "Sender address id [{}] and bytes [{}]",
-            &sender[2].0,
-            hex::encode(&sender[2].1.to_bytes())
-        )
+        "Sender address id [{}] and bytes [{}]",
+        env.sender.0,
+        hex::encode(env.sender.1.to_bytes())
     );
     println!(
-        "{}",
-        format!(
-            "Sender address id [{}] and bytes [{}]",
-            &sender[3].0,
-            hex::encode(&sender[3].1.to_bytes())
-        )
+        "Receiver address id [{}] and bytes [{}]",
+        receiver.0,
+        hex::encode(receiver.1.to_bytes())
     );
 
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
     // First we deploy the contract in order to actually have an actor running on the embryo address
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender[0].1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
-    println!(
-        "Contract address ID type on decimal [{}]",
-        exec_return.actor_id
-    );
+    println!("Contract address ID type on decimal [{}]", contract.actor_id);
     println!(
         "Contract address ID type on hex [{}]",
-        hex::encode(Address::new_id(exec_return.actor_id).to_bytes())
+        hex::encode(contract.address().to_bytes())
     );
-    match exec_return.robust_address {
-        Some(addr) => println!("Contract address robust type [{}]", addr),
-        None => (),
+    if let Some(addr) = contract.robust_address {
+        println!("Contract address robust type [{}]", addr);
     }
-
     println!(
         "Contract address eth address type [{}]",
-        hex::encode(exec_return.eth_address.0)
+        hex::encode(contract.eth_address)
     );
 
-    let contract_actor_id = exec_return.actor_id;
-
     // Send some tokens to the smart contract
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: 0,
-        value: TokenAmount::from_atto(100),
-        sequence: 1,
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let sender = env.sender.1;
+    let res = env.call(
+        sender,
+        contract.address(),
+        0,
+        RawBytes::default(),
+        TokenAmount::from_atto(100),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `send (actor id)`");
 
-    let abi_encoded_call = api_contracts::send_test::send_0Call{
-        target: 0x65_u64,
+    let call = bindings::SendApiTest::send_0Call{
+        target: receiver.0,
         amount: U256::from(0xa)
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender[0].1,
-            to: Address::new_id(contract_actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 2,
-            params: RawBytes::new(hex::decode(
-                // "58446F7EE35E0000000000000000000000000000000000000000000000000000000000000065000000000000000000000000000000000000000000000000000000000000000A"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    };
+
+    let res = env.invoke(&contract, &call);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("send (actor id)".into(), gas_used));
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-
     println!("Calling `send (address)`");
 
-    let abi_encoded_call = api_contracts::send_test::send_1Call{
-        target: api_contracts::send_test::FilAddress{
-            data: vec![0_u8, 0x65]
-        },
+    let call = bindings::SendApiTest::send_1Call{
+        target: Address::new_id(receiver.0).into(),
         amount: U256::from(0xa)
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-        from: sender[0].1,
-        to: Address::new_id(contract_actor_id),
-        gas_limit: 1000000000,
-        method_num: EvmMethods::InvokeContract as u64,
-        sequence: 3,
-        params: RawBytes::new(hex::decode(
-            // "58A40E0E687C0000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000A000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000020065000000000000000000000000000000000000000000000000000000000000"
-            cbor_encoded.as_str()
-        ).unwrap()),
-        ..Message::default()
     };
 
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    let res = env.invoke(&contract, &call);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("send (address)".into(), gas_used));
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    let table = testing::create_gas_table(gas_result);
+    let table = testing::create_gas_table(gas_result.clone());
     testing::save_gas_table(&table, "send");
+    testing::report::save_gas_results(&gas_result, "send");
 
     table.printstd();
+
+    testing::gas::check_gas_baseline(&gas_result, "send");
 }
@@ -0,0 +1,575 @@
+use cid::Cid;
+use fvm_ipld_encoding::BytesDe;
+use fvm_shared::address::Address;
+use fvm_shared::clock::ChainEpoch;
+use fvm_shared::crypto::signature::Signature;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::sector::RegisteredPoStProof;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use serde::de::DeserializeOwned;
+use testing::bindings;
+use testing::bindings::SerializeParamsTest as contract_api;
+use testing::fuzz::{self, sol_bigint, ContractFuzzer};
+
+const CONTRACT_NAME: &str = "SerializeParamsTest";
+
+/// `CommonTypes.BigInt` as its raw magnitude and sign, so that leading zeros and negative zeros
+/// reach the serializer too.
+type Amount = (Vec<u8>, bool);
+
+fn amount() -> impl Strategy<Value = Amount> {
+    (fuzz::bytes(32), any::<bool>())
+}
+
+fn sol_amount((val, neg): &Amount) -> bindings::BigInt {
+    bindings::BigInt {
+        val: val.clone(),
+        neg: *neg,
+    }
+}
+
+fn amount_eq((val, neg): &Amount, amount: &TokenAmount) -> bool {
+    sol_bigint(val, *neg) == *amount.atto()
+}
+
+fn decode<T: DeserializeOwned>(cbor: &[u8]) -> Result<T, TestCaseError> {
+    fvm_ipld_encoding::from_slice(cbor)
+        .map_err(|err| TestCaseError::fail(format!("{} does not decode: {}", hex::encode(cbor), err)))
+}
+
+fn label() -> impl Strategy<Value = fil_actor_market::Label> {
+    prop_oneof![
+        "\\PC{0,64}".prop_map(fil_actor_market::Label::String),
+        fuzz::bytes(64).prop_map(fil_actor_market::Label::Bytes),
+    ]
+}
+
+fn signature() -> impl Strategy<Value = Signature> {
+    prop_oneof![
+        vec(any::<u8>(), 65).prop_map(Signature::new_secp256k1),
+        vec(any::<u8>(), 96).prop_map(Signature::new_bls),
+    ]
+}
+
+/// Signatures are handed to the serializer with their type byte, as `Signature` encodes them.
+fn sol_signature(signature: &Signature) -> Vec<u8> {
+    let mut bytes = vec![signature.signature_type() as u8];
+    bytes.extend(signature.bytes());
+    bytes
+}
+
+#[derive(Debug)]
+struct Proposal {
+    piece_cid: Cid,
+    piece_size: u64,
+    verified_deal: bool,
+    client: Address,
+    provider: Address,
+    label: fil_actor_market::Label,
+    start_epoch: ChainEpoch,
+    end_epoch: ChainEpoch,
+    storage_price_per_epoch: Amount,
+    provider_collateral: Amount,
+    client_collateral: Amount,
+}
+
+impl Proposal {
+    fn to_sol(&self) -> bindings::DealProposal {
+        let (data, is_string) = match &self.label {
+            fil_actor_market::Label::String(label) => (label.as_bytes().to_vec(), true),
+            fil_actor_market::Label::Bytes(label) => (label.clone(), false),
+        };
+        bindings::DealProposal {
+            // writeCid adds the multibase prefix of the tag 42 byte string
+            piece_cid: bindings::Cid {
+                data: self.piece_cid.to_bytes(),
+            },
+            piece_size: self.piece_size,
+            verified_deal: self.verified_deal,
+            client: self.client.into(),
+            provider: self.provider.into(),
+            label: bindings::DealLabel {
+                data,
+                isString: is_string,
+            },
+            start_epoch: self.start_epoch,
+            end_epoch: self.end_epoch,
+            storage_price_per_epoch: sol_amount(&self.storage_price_per_epoch),
+            provider_collateral: sol_amount(&self.provider_collateral),
+            client_collateral: sol_amount(&self.client_collateral),
+        }
+    }
+
+    fn check(&self, proposal: &fil_actor_market::DealProposal) -> Result<(), TestCaseError> {
+        prop_assert_eq!(proposal.piece_cid, self.piece_cid);
+        prop_assert_eq!(proposal.piece_size.0, self.piece_size);
+        prop_assert_eq!(proposal.verified_deal, self.verified_deal);
+        prop_assert_eq!(proposal.client, self.client);
+        prop_assert_eq!(proposal.provider, self.provider);
+        prop_assert_eq!(&proposal.label, &self.label);
+        prop_assert_eq!(proposal.start_epoch, self.start_epoch);
+        prop_assert_eq!(proposal.end_epoch, self.end_epoch);
+        prop_assert!(amount_eq(&self.storage_price_per_epoch, &proposal.storage_price_per_epoch));
+        prop_assert!(amount_eq(&self.provider_collateral, &proposal.provider_collateral));
+        prop_assert!(amount_eq(&self.client_collateral, &proposal.client_collateral));
+        Ok(())
+    }
+}
+
+fn deal_proposal() -> impl Strategy<Value = Proposal> {
+    (
+        (fuzz::cid(), any::<u64>(), any::<bool>(), fuzz::address(), fuzz::address()),
+        (label(), fuzz::chain_epoch(), fuzz::chain_epoch()),
+        (amount(), amount(), amount()),
+    )
+        .prop_map(
+            |(
+                (piece_cid, piece_size, verified_deal, client, provider),
+                (label, start_epoch, end_epoch),
+                (storage_price_per_epoch, provider_collateral, client_collateral),
+            )| Proposal {
+                piece_cid,
+                piece_size,
+                verified_deal,
+                client,
+                provider,
+                label,
+                start_epoch,
+                end_epoch,
+                storage_price_per_epoch,
+                provider_collateral,
+                client_collateral,
+            },
+        )
+}
+
+#[test]
+fn serialize_fuzz_market_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "serializeWithdrawBalanceParams",
+        (fuzz::address(), amount()),
+        |caller, (provider_or_client, token_amount)| {
+            let sol = bindings::WithdrawBalanceParams {
+                provider_or_client: provider_or_client.into(),
+                tokenAmount: sol_amount(&token_amount),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeWithdrawBalanceParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_market::WithdrawBalanceParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.provider_or_client, provider_or_client);
+            prop_assert!(amount_eq(&token_amount, &params.amount));
+            Ok(())
+        },
+    );
+
+    fuzzer.run("serializeDealProposal", deal_proposal(), |caller, proposal| {
+        let cbor = caller
+            .call(&contract_api::serializeDealProposalCall {
+                params: proposal.to_sol(),
+            })?
+            ._0;
+
+        proposal.check(&decode(&cbor)?)
+    });
+
+    fuzzer.run(
+        "serializePublishStorageDealsParams",
+        vec((deal_proposal(), signature()), 0..4),
+        |caller, deals| {
+            let sol = bindings::PublishStorageDealsParams {
+                deals: deals
+                    .iter()
+                    .map(|(proposal, signature)| bindings::ClientDealProposal {
+                        proposal: proposal.to_sol(),
+                        client_signature: sol_signature(signature),
+                    })
+                    .collect(),
+            };
+            let cbor = caller
+                .call(&contract_api::serializePublishStorageDealsParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_market::PublishStorageDealsParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.deals.len(), deals.len());
+            for (deal, (proposal, signature)) in params.deals.iter().zip(&deals) {
+                proposal.check(&deal.proposal)?;
+                prop_assert_eq!(&deal.client_signature, signature);
+            }
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn serialize_fuzz_miner_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "serializeChangeBeneficiaryParams",
+        (fuzz::address(), amount(), fuzz::chain_epoch()),
+        |caller, (new_beneficiary, new_quota, new_expiration)| {
+            let sol = bindings::ChangeBeneficiaryParams {
+                new_beneficiary: new_beneficiary.into(),
+                new_quota: sol_amount(&new_quota),
+                new_expiration,
+            };
+            let cbor = caller
+                .call(&contract_api::serializeChangeBeneficiaryParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_miner::ChangeBeneficiaryParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.new_beneficiary, new_beneficiary);
+            prop_assert!(amount_eq(&new_quota, &params.new_quota));
+            prop_assert_eq!(params.new_expiration, new_expiration);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeChangeWorkerAddressParams",
+        (fuzz::address(), vec(fuzz::address(), 0..4)),
+        |caller, (new_worker, new_control_addresses)| {
+            let sol = bindings::ChangeWorkerAddressParams {
+                new_worker: new_worker.into(),
+                new_control_addresses: new_control_addresses
+                    .iter()
+                    .map(bindings::FilAddress::from)
+                    .collect(),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeChangeWorkerAddressParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_miner::ChangeWorkerAddressParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.new_worker, new_worker);
+            prop_assert_eq!(params.new_control_addresses, new_control_addresses);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeChangeMultiaddrsParams",
+        vec(fuzz::bytes(64), 0..8),
+        |caller, multi_addrs| {
+            let sol = multi_addrs
+                .iter()
+                .map(|addr| bindings::FilAddress { data: addr.clone() })
+                .collect();
+            let cbor = caller
+                .call(&contract_api::serializeChangeMultiaddrsParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_miner::ChangeMultiaddrsParams = decode(&cbor)?;
+
+            let decoded: Vec<Vec<u8>> = params.new_multi_addrs.into_iter().map(|addr| addr.0).collect();
+            prop_assert_eq!(decoded, multi_addrs);
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn serialize_fuzz_power_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    // PowerTypes.RegisteredPoStProof stops at the V1 proofs, its trailing `Invalid` would
+    // encode as the first V1P1 proof.
+    fuzzer.run(
+        "serializeCreateMinerParams",
+        (
+            fuzz::address(),
+            fuzz::address(),
+            0_u8..10,
+            fuzz::bytes(64),
+            vec(fuzz::bytes(64), 0..4),
+        ),
+        |caller, (owner, worker, proof_type, peer, multiaddrs)| {
+            let sol = bindings::CreateMinerParams {
+                owner: owner.into(),
+                worker: worker.into(),
+                peer: bindings::FilAddress { data: peer.clone() },
+                multiaddrs: multiaddrs
+                    .iter()
+                    .map(|addr| bindings::FilAddress { data: addr.clone() })
+                    .collect(),
+                window_post_proof_type: proof_type,
+            };
+            let cbor = caller
+                .call(&contract_api::serializeCreateMinerParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_power::CreateMinerParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.owner, owner);
+            prop_assert_eq!(params.worker, worker);
+            prop_assert_eq!(params.window_post_proof_type, RegisteredPoStProof::from(proof_type as i64));
+            prop_assert_eq!(params.peer, peer);
+            prop_assert_eq!(
+                params.multiaddrs.into_iter().map(|addr: BytesDe| addr.0).collect::<Vec<_>>(),
+                multiaddrs
+            );
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn serialize_fuzz_verifreg_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "serializeGetClaimsParams",
+        (any::<u64>(), vec(any::<u64>(), 0..8)),
+        |caller, (provider, claim_ids)| {
+            let sol = bindings::GetClaimsParams {
+                provider,
+                claim_ids: claim_ids.clone(),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeGetClaimsParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_verifreg::GetClaimsParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.provider, provider);
+            prop_assert_eq!(params.claim_ids, claim_ids);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeAddVerifiedClientParams",
+        (fuzz::address(), amount()),
+        |caller, (addr, allowance)| {
+            let sol = bindings::AddVerifiedClientParams {
+                addr: addr.into(),
+                allowance: sol_amount(&allowance),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeAddVerifiedClientParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_verifreg::AddVerifiedClientParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.address, addr);
+            prop_assert!(amount_eq(&allowance, &params.allowance));
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeRemoveExpiredAllocationsParams",
+        (any::<u64>(), vec(any::<u64>(), 0..8)),
+        |caller, (client, allocation_ids)| {
+            let sol = bindings::RemoveExpiredAllocationsParams {
+                client,
+                allocation_ids: allocation_ids.clone(),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeRemoveExpiredAllocationsParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_verifreg::RemoveExpiredAllocationsParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.client, client);
+            prop_assert_eq!(params.allocation_ids, allocation_ids);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeExtendClaimTermsParams",
+        vec((any::<u64>(), any::<u64>(), fuzz::chain_epoch()), 0..4),
+        |caller, terms| {
+            let sol = terms
+                .iter()
+                .map(|&(provider, claim_id, term_max)| bindings::ClaimTerm {
+                    provider,
+                    claim_id,
+                    term_max,
+                })
+                .collect();
+            let cbor = caller
+                .call(&contract_api::serializeExtendClaimTermsParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_verifreg::ExtendClaimTermsParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.terms.len(), terms.len());
+            for (term, &(provider, claim_id, term_max)) in params.terms.iter().zip(&terms) {
+                prop_assert_eq!(term.provider, provider);
+                prop_assert_eq!(term.claim_id, claim_id);
+                prop_assert_eq!(term.term_max, term_max);
+            }
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeRemoveExpiredClaimsParams",
+        (any::<u64>(), vec(any::<u64>(), 0..8)),
+        |caller, (provider, claim_ids)| {
+            let sol = bindings::RemoveExpiredClaimsParams {
+                provider,
+                claim_ids: claim_ids.clone(),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeRemoveExpiredClaimsParamsCall { params: sol })?
+                ._0;
+            let params: fil_actor_verifreg::RemoveExpiredClaimsParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.provider, provider);
+            prop_assert_eq!(params.claim_ids, claim_ids);
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn serialize_fuzz_datacap_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "serializeGetAllowanceParams",
+        (fuzz::address(), fuzz::address()),
+        |caller, (owner, operator)| {
+            let sol = bindings::GetAllowanceParams {
+                owner: owner.into(),
+                operator: operator.into(),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeGetAllowanceParamsCall { params: sol })?
+                ._0;
+            let params: frc46_token::token::types::GetAllowanceParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.owner, owner);
+            prop_assert_eq!(params.operator, operator);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeTransferParams",
+        (fuzz::address(), amount(), fuzz::bytes(64)),
+        |caller, (to, amount, operator_data)| {
+            let sol = bindings::TransferParams {
+                operator_data: operator_data.clone(),
+                to: to.into(),
+                amount: sol_amount(&amount),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeTransferParamsCall { params: sol })?
+                ._0;
+            let params: frc46_token::token::types::TransferParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.to, to);
+            prop_assert!(amount_eq(&amount, &params.amount));
+            prop_assert_eq!(params.operator_data.bytes(), &operator_data[..]);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeTransferFromParams",
+        (fuzz::address(), fuzz::address(), amount(), fuzz::bytes(64)),
+        |caller, (from, to, amount, operator_data)| {
+            let sol = bindings::TransferFromParams {
+                operator_data: operator_data.clone(),
+                from: from.into(),
+                to: to.into(),
+                amount: sol_amount(&amount),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeTransferFromParamsCall { params: sol })?
+                ._0;
+            let params: frc46_token::token::types::TransferFromParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.from, from);
+            prop_assert_eq!(params.to, to);
+            prop_assert!(amount_eq(&amount, &params.amount));
+            prop_assert_eq!(params.operator_data.bytes(), &operator_data[..]);
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeIncreaseAllowanceParams",
+        (fuzz::address(), amount()),
+        |caller, (operator, increase)| {
+            let sol = bindings::IncreaseAllowanceParams {
+                operator: operator.into(),
+                increase: sol_amount(&increase),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeIncreaseAllowanceParamsCall { params: sol })?
+                ._0;
+            let params: frc46_token::token::types::IncreaseAllowanceParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.operator, operator);
+            prop_assert!(amount_eq(&increase, &params.increase));
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeDecreaseAllowanceParams",
+        (fuzz::address(), amount()),
+        |caller, (operator, decrease)| {
+            let sol = bindings::DecreaseAllowanceParams {
+                operator: operator.into(),
+                decrease: sol_amount(&decrease),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeDecreaseAllowanceParamsCall { params: sol })?
+                ._0;
+            let params: frc46_token::token::types::DecreaseAllowanceParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.operator, operator);
+            prop_assert!(amount_eq(&decrease, &params.decrease));
+            Ok(())
+        },
+    );
+
+    fuzzer.run(
+        "serializeBurnFromParams",
+        (fuzz::address(), amount()),
+        |caller, (owner, amount)| {
+            let sol = bindings::BurnFromParams {
+                owner: owner.into(),
+                amount: sol_amount(&amount),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeBurnFromParamsCall { params: sol })?
+                ._0;
+            let params: frc46_token::token::types::BurnFromParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.owner, owner);
+            prop_assert!(amount_eq(&amount, &params.amount));
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn serialize_fuzz_common_tests() {
+    let fuzzer = ContractFuzzer::new(CONTRACT_NAME);
+
+    fuzzer.run(
+        "serializeUniversalReceiverParams",
+        (any::<u32>(), fuzz::bytes(64)),
+        |caller, (type_, payload)| {
+            let sol = bindings::UniversalReceiverParams {
+                type_,
+                payload: payload.clone(),
+            };
+            let cbor = caller
+                .call(&contract_api::serializeUniversalReceiverParamsCall { params: sol })?
+                ._0;
+            let params: fvm_actor_utils::receiver::UniversalReceiverParams = decode(&cbor)?;
+
+            prop_assert_eq!(params.type_, type_);
+            prop_assert_eq!(params.payload.bytes(), &payload[..]);
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,64 @@
+use fvm::gas::{Gas, GasCharge};
+use fvm::trace::ExecutionEvent;
+use fvm_shared::address::Address;
+use fvm_shared::bigint::Zero;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fil_actors_runtime::runtime::builtins;
+use testing::trace::{self, ActorInfo};
+
+fn call(from: u64, to: u64, method: u64) -> ExecutionEvent {
+    ExecutionEvent::Call {
+        from,
+        to: Address::new_id(to),
+        method,
+        params: None,
+        value: TokenAmount::default(),
+        gas_limit: 1000000000,
+        read_only: false,
+    }
+}
+
+fn charge(gas: i64) -> ExecutionEvent {
+    ExecutionEvent::GasCharge(GasCharge::new("OnMethodInvocation", Gas::new(gas), Gas::zero()))
+}
+
+#[test]
+fn trace_render_tests() {
+    let get_deal_client = frc42_dispatch::method_hash!("GetDealClient");
+    let exec_trace = vec![
+        charge(100),
+        call(100, 1001, fil_actor_evm::Method::InvokeContract as u64),
+        charge(10),
+        call(1001, 5, get_deal_client),
+        charge(5),
+        ExecutionEvent::CallReturn(ExitCode::USR_NOT_FOUND, None),
+        ExecutionEvent::CallReturn(ExitCode::new(33), None),
+    ];
+
+    let frames = trace::call_tree(&exec_trace);
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].gas, Gas::new(15));
+    assert_eq!(frames[0].children[0].exit_code, Some(ExitCode::USR_NOT_FOUND));
+
+    let rendered = trace::render(&frames, |address| {
+        let actor_id = address.id().ok();
+        let actor_type = match actor_id {
+            Some(100) => Some(builtins::Type::Account as u32),
+            Some(1001) => Some(builtins::Type::EVM as u32),
+            Some(5) => Some(builtins::Type::Market as u32),
+            _ => None,
+        };
+
+        ActorInfo {
+            name: trace::actor_name(actor_id, actor_type),
+            actor_type,
+        }
+    });
+
+    assert_eq!(
+        rendered,
+        "Account #100 -> EVM contract #1001 InvokeContract (0 B) exit 33, gas 15\n\
+         └─ EVM contract #1001 -> StorageMarket GetDealClient (0 B) exit 17, gas 5\n"
+    );
+}
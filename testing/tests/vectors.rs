@@ -0,0 +1,37 @@
+use libipld_core::ipld::Ipld;
+use std::collections::BTreeSet;
+use testing::vectors::{self, VECTORS_VERSION};
+
+#[test]
+fn vectors_fixture_tests() {
+    // The checked-in fixture is what the builtin-actors types encode today
+    if let Err(err) = vectors::check() {
+        panic!("{:#}", err);
+    }
+
+    let file = vectors::load(&vectors::default_path()).unwrap();
+    assert_eq!(file.version, VECTORS_VERSION);
+
+    let names: BTreeSet<&str> = file.vectors.iter().map(|vector| vector.name.as_str()).collect();
+    assert_eq!(names.len(), file.vectors.len(), "vector names are not unique");
+
+    // Every vector is canonical DAG-CBOR
+    for vector in &file.vectors {
+        let ipld: Ipld = fvm_ipld_encoding::from_slice(&vector.bytes()).unwrap();
+        assert_eq!(fvm_ipld_encoding::to_vec(&ipld).unwrap(), vector.bytes(), "{}", vector.name);
+    }
+
+    // Hex the Solidity tests used to hardcode
+    assert_eq!(file.get("GetVestingFundsReturn/default").unwrap().cbor, "8181820040");
+}
+
+#[test]
+fn vectors_version_tests() {
+    let path = std::env::temp_dir().join(format!("cbor_vectors_{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"version": 0, "builtin_actors": "v11.0.0", "vectors": []}"#).unwrap();
+
+    let err = vectors::load(&path).unwrap_err();
+    assert!(err.to_string().contains("expected 1"), "{}", err);
+
+    std::fs::remove_file(&path).unwrap();
+}
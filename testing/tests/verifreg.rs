@@ -1,32 +1,24 @@
-use fil_actor_eam::Return;
 use fil_actor_evm::Method as EvmMethods;
-use fil_actors_runtime::{
-    runtime::builtins, DATACAP_TOKEN_ACTOR_ADDR, EAM_ACTOR_ADDR, SYSTEM_ACTOR_ADDR,
-    VERIFIED_REGISTRY_ACTOR_ADDR,
-};
-use fvm::executor::{ApplyKind, Executor};
-use fvm_integration_tests::dummy::DummyExterns;
+use fil_actors_runtime::VERIFIED_REGISTRY_ACTOR_ADDR;
 use fvm_integration_tests::tester::Account;
+use fvm_ipld_encoding::CborStore;
 use fvm_ipld_encoding::RawBytes;
-use fvm_ipld_encoding::{strict_bytes, tuple::*};
+use fvm_ipld_encoding::tuple::*;
 use fvm_shared::address::Address;
 use fvm_shared::bigint::bigint_ser;
-use fvm_shared::message::Message;
+use fvm_shared::econ::TokenAmount;
 use fvm_shared::sector::StoragePower;
-use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
-use alloy_primitives::{fixed_bytes, address, Address as Alloy_Address, U256};
-use alloy_sol_types::{SolCall, SolType, sol_data};
 
-use testing::{setup, api_contracts};
-use testing::helpers;
+use testing::bindings;
+use testing::env::TestEnv;
+use testing::genesis::{self, GenesisConfig, VERIFREG_ROOT_KEY_ACTOR_ID};
 use testing::GasResult;
 use testing::parse_gas;
 
-const WASM_COMPILED_PATH: &str = "../build/v0.8/tests/VerifRegApiTest.bin";
+const CONTRACT_NAME: &str = "VerifRegApiTest";
 
-#[derive(SerdeSerialize, SerdeDeserialize)]
-#[serde(transparent)]
-pub struct CreateExternalParams(#[serde(with = "strict_bytes")] pub Vec<u8>);
+// Root key of the verifreg actor, an account created by the test
+const VERIFREG_ROOT_KEY_ID: u64 = 199;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
 pub struct VerifierParams {
@@ -40,190 +32,64 @@ fn verifreg_tests() {
     println!("Testing solidity API");
 
     let mut gas_result: GasResult = vec![];
-    let (mut tester, manifest) = setup::setup_tester();
-
-    let accounts: [Account; 2] = tester.create_accounts().unwrap();
-    let (sender, _verified_client) = (accounts[0], accounts[1]);
-    // register address so we can use senderID 199 which is the governor address of the verifreg
-    // actor
-    let _accounts: [Account; 200] = tester.create_accounts().unwrap();
-
-    // Set verifreg actor
-    let state_tree = tester.state_tree.as_mut().unwrap();
-    helpers::set_verifiedregistry_actor(
-        state_tree,
-        *manifest
-            .code_by_id(builtins::Type::VerifiedRegistry as u32)
-            .unwrap(),
-    )
-    .unwrap();
-    helpers::set_datacap_actor(
-        state_tree,
-        *manifest.code_by_id(builtins::Type::DataCap as u32).unwrap(),
-    )
-    .unwrap();
-
-    // Instantiate machine
-    tester.instantiate_machine(DummyExterns).unwrap();
-
-    let executor = tester.executor.as_mut().unwrap();
-
-    // Try to call "constructor"
-    println!("Try to call constructor on storage verifreg actor");
-
-    let root_key = Address::new_id(199);
-
-    let message = Message {
-        from: SYSTEM_ACTOR_ADDR,
-        to: VERIFIED_REGISTRY_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 1,
-        params: RawBytes::serialize(root_key).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Implicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    // Try to call "constructor"
-    println!("Try to call constructor on datacap actor");
-
-    let message = Message {
-        from: SYSTEM_ACTOR_ADDR,
-        to: DATACAP_TOKEN_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 1,
-        params: RawBytes::serialize(VERIFIED_REGISTRY_ACTOR_ADDR).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Implicit, 100)
-        .unwrap();
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    let mut env = TestEnv::with_genesis(GenesisConfig {
+        verifreg_root_key: Address::new_id(VERIFREG_ROOT_KEY_ID),
+        ..GenesisConfig::default()
+    });
+    let sender = env.sender.1;
+
+    let [verified_client] = env.create_accounts();
+    // register addresses up to the root key ID so the root key is an account we can send from
+    let accounts: [Account; 98] = env.create_accounts();
+    let root_key = accounts[accounts.len() - 1].1;
+    assert_eq!(root_key, Address::new_id(VERIFREG_ROOT_KEY_ID));
 
     println!("Calling init actor (EVM)");
 
-    let evm_bin = setup::load_evm(WASM_COMPILED_PATH);
-
-    let constructor_params = CreateExternalParams(evm_bin);
-
-    let message = Message {
-        from: sender.1,
-        to: EAM_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 4,
-        sequence: 0,
-        params: RawBytes::serialize(constructor_params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap(); //use fil_actors
-
-    assert_eq!(res.msg_receipt.exit_code.value(), 0);
-
-    let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
-    let contract_actor = exec_return.actor_id;
+    let contract = env.deploy_contract(CONTRACT_NAME);
 
     let verifier_allowance = fvm_shared::sector::StoragePower::from(1_048_576u64);
     let params = VerifierParams {
-        address: Address::new_id(contract_actor),
+        address: contract.address(),
         allowance: verifier_allowance,
     };
 
     println!("Registering contract-actor as verifier");
     // by this call we register our contract actor as a verifier
-    let message = Message {
-        //from: sender.1,
-        from: Address::new_id(199),
-        to: VERIFIED_REGISTRY_ACTOR_ADDR,
-        gas_limit: 1000000000,
-        method_num: 2,
-        sequence: 0,
-        params: RawBytes::serialize(params).unwrap(),
-        ..Message::default()
-    };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
-
+    let res = env.call(
+        root_key,
+        VERIFIED_REGISTRY_ACTOR_ADDR,
+        2,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `add_verified_client`");
 
-    let abi_encoded_call = api_contracts::verifreg_test::add_verified_clientCall{
-        params: api_contracts::verifreg_test::AddVerifiedClientParams{
-            addr: api_contracts::verifreg_test::FilAddress{
-                data: _verified_client.1.to_bytes()
-            },
-            allowance: api_contracts::verifreg_test::BigInt{
-                val: fixed_bytes!("100000").to_vec(),
-                neg: false
-            }
+    let call = bindings::VerifRegApiTest::add_verified_clientCall{
+        params: bindings::AddVerifiedClientParams{
+            addr: verified_client.1.into(),
+            allowance: TokenAmount::from_atto(0x100000).into()
         }
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-    
-    dbg!(cbor_encoded.as_str());
-
-    let message = Message {
-            from: sender.1,
-            to: Address::new_id(exec_return.actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 1,
-            params: RawBytes::new(hex::decode(
-                // "5901441FEBC7BF0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000A00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000001501DCE5B7F69E73494891556A350F8CC357614916D500000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000031000000000000000000000000000000000000000000000000000000000000000"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    };
+
+    let res = env.invoke(&contract, &call);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("add_verified_client".into(), gas_used));
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `get_claims`");
 
-    let abi_encoded_call = api_contracts::verifreg_test::get_claimsCall{
-        params: api_contracts::verifreg_test::GetClaimsParams{
+    //  get_claims params [201, [0,1]]
+    let call = bindings::VerifRegApiTest::get_claimsCall{
+        params: bindings::GetClaimsParams{
             provider: 0xc9_u64,
             claim_ids: vec![0_u64, 1]
         }
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    dbg!(cbor_encoded.as_str());
-
-    let message = Message {
-            from: sender.1,
-            to: Address::new_id(exec_return.actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 2,
-            //  get_claims params [201, [0,1]]
-            params: RawBytes::new(hex::decode(
-                // "58C4DBCB98AB000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000C90000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"
-                cbor_encoded.as_bytes()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    };
+
+    let res = env.invoke(&contract, &call);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("get_claims".into(), gas_used));
     // Should not fail as actor would return an empty list of claims
@@ -238,67 +104,38 @@ fn verifreg_tests() {
     //
     //CODE:
     //
-    // let abi_encoded_call = api_contracts::verifreg_test::extend_claim_termsCall{
-    //     params: api_contracts::verifreg_test::ExtendClaimTermsParams{
-    //         terms: vec![
-    //             api_contracts::verifreg_test::ClaimTerm{
-    //                 provider: 0xc9_u64,
-    //                 claim_id: 1_u64,
-    //                 term_max: 100000_i64
-    //             }
-    //         ]
-    //     }
-    // }.abi_encode();
-    //
-    // let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    let message = Message {
-            from: sender.1,
-            to: Address::new_id(exec_return.actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 3,
-            params: RawBytes::new(hex::decode("58C4D8308B8C000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000660000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001").unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    // let call = bindings::VerifRegApiTest::extend_claim_termsCall{
+    //     claimTerms: vec![
+    //         bindings::ClaimTerm{
+    //             provider: 0xc9_u64,
+    //             claim_id: 1_u64,
+    //             term_max: 100000_i64
+    //         }
+    //     ]
+    // };
+
+    let res = env.call(
+        sender,
+        contract.address(),
+        EvmMethods::InvokeContract as u64,
+        RawBytes::new(hex::decode("58C4D8308B8C000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000660000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001").unwrap()),
+        TokenAmount::default(),
+    );
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("extend_claim_term".into(), gas_used));
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
     println!("Calling `remove_expired_allocations`");
 
-    let abi_encoded_call = api_contracts::verifreg_test::remove_expired_allocationsCall{
-        params: api_contracts::verifreg_test::RemoveExpiredAllocationsParams{
-            client: 0x65_u64,
+    // empty list which means remove all
+    let call = bindings::VerifRegApiTest::remove_expired_allocationsCall{
+        params: bindings::RemoveExpiredAllocationsParams{
+            client: verified_client.0,
             allocation_ids: vec![]
         }
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    dbg!(cbor_encoded.as_str());
-
-    let message = Message {
-            from: sender.1,
-            to: Address::new_id(exec_return.actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 4,
-            //  remove_expired_allocations params [actorId(101), []] empty list which means remove all
-            params: RawBytes::new(hex::decode(
-                // "5884DF5527250000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000006500000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000000"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    };
+
+    let res = env.invoke(&contract, &call);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push((
         "remove_expired_allocations".into(),
@@ -308,39 +145,45 @@ fn verifreg_tests() {
 
     println!("Calling `remove_expired_claims`");
 
-    let abi_encoded_call = api_contracts::verifreg_test::remove_expired_claimsCall{
-        params: api_contracts::verifreg_test::RemoveExpiredClaimsParams{
+    let call = bindings::VerifRegApiTest::remove_expired_claimsCall{
+        params: bindings::RemoveExpiredClaimsParams{
             provider: 0x66_u64,
             claim_ids: vec![0_u64, 1]
         }
-    }.abi_encode();
-
-    let cbor_encoded = api_contracts::cbor_encode(abi_encoded_call);
-
-    dbg!(cbor_encoded.as_str());
-
-    let message = Message {
-            from: sender.1,
-            to: Address::new_id(exec_return.actor_id),
-            gas_limit: 1000000000,
-            method_num: EvmMethods::InvokeContract as u64,
-            sequence: 5,
-            params: RawBytes::new(hex::decode(
-                // "58C4D8308B8C000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000660000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"
-                cbor_encoded.as_str()
-            ).unwrap()),
-            ..Message::default()
-        };
-
-    let res = executor
-        .execute_message(message, ApplyKind::Explicit, 100)
-        .unwrap();
+    };
+
+    let res = env.invoke(&contract, &call);
     let gas_used = parse_gas(res.exec_trace);
     gas_result.push(("remove_expired_claims".into(), gas_used));
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
 
-    let table = testing::create_gas_table(gas_result);
+    let table = testing::create_gas_table(gas_result.clone());
     testing::save_gas_table(&table, "verifreg");
+    testing::report::save_gas_results(&gas_result, "verifreg");
 
     table.printstd();
+
+    testing::gas::check_gas_baseline(&gas_result, "verifreg");
+}
+
+#[test]
+fn verifreg_test_genesis_root_key() {
+    // The default root key is an account at ID 80 with a key address
+    let mut env = TestEnv::new();
+    let state_tree = env.state_tree_mut();
+    let root_key = genesis::verifreg_root_key_address();
+    assert_eq!(state_tree.lookup_id(&root_key).unwrap(), Some(VERIFREG_ROOT_KEY_ACTOR_ID));
+
+    let actor = state_tree.get_actor(VERIFREG_ROOT_KEY_ACTOR_ID).unwrap().unwrap();
+    let state: fvm::account_actor::State = state_tree.store().get_cbor(&actor.state).unwrap().unwrap();
+    assert_eq!(state.address, root_key);
+
+    // Another root key leaves ID 80 to the test
+    let mut env = TestEnv::with_genesis(GenesisConfig {
+        verifreg_root_key: Address::new_id(VERIFREG_ROOT_KEY_ID),
+        ..GenesisConfig::default()
+    });
+    let state_tree = env.state_tree_mut();
+    assert!(state_tree.get_actor(VERIFREG_ROOT_KEY_ACTOR_ID).unwrap().is_none());
+    assert_eq!(state_tree.lookup_id(&root_key).unwrap(), None);
 }